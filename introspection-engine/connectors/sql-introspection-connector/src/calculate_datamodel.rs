@@ -134,10 +134,10 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
 
         for index in table.indices.iter() {
             if index.columns.len() > 1 {
-                let tpe = if index.tpe == IndexType::Unique {
-                    datamodel::dml::IndexType::Unique
-                } else {
-                    datamodel::dml::IndexType::Normal
+                let tpe = match index.tpe {
+                    IndexType::Unique => datamodel::dml::IndexType::Unique,
+                    IndexType::Normal => datamodel::dml::IndexType::Normal,
+                    IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
                 };
 
                 let index_definition: IndexDefinition = IndexDefinition {
@@ -148,10 +148,15 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 model.add_index(index_definition)
             }
             if index.columns.len() == 1 && index.tpe != IndexType::Unique {
+                let tpe = match index.tpe {
+                    IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
+                    _ => datamodel::dml::IndexType::Normal,
+                };
+
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
                     fields: index.columns.clone(),
-                    tpe: datamodel::dml::IndexType::Normal,
+                    tpe,
                 };
                 model.add_index(index_definition)
             }
@@ -349,6 +354,27 @@ fn parse_int(value: &str) -> Option<i32> {
     }
 }
 
+fn parse_big_int(value: &str) -> Option<i64> {
+    debug!("Parsing big int '{}'", value);
+    let re_num = Regex::new(r"^'?(\d+)'?$").expect("compile regex");
+    let rslt = re_num.captures(value);
+    if rslt.is_none() {
+        debug!("Couldn't parse big int");
+        return None;
+    }
+
+    let captures = rslt.expect("get captures");
+    let num_str = captures.get(1).expect("get capture").as_str();
+    let num_rslt = num_str.parse::<i64>();
+    match num_rslt {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse big int '{}'", num_str);
+            None
+        }
+    }
+}
+
 fn parse_bool(value: &str) -> Option<bool> {
     debug!("Parsing bool '{}'", value);
     value.to_lowercase().parse().ok()
@@ -382,7 +408,9 @@ fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<PrismaValu
             None => parse_bool(default).map(|b| PrismaValue::Boolean(b)),
         },
         ColumnTypeFamily::Int => parse_int(default).map(|x| PrismaValue::Int(x)),
+        ColumnTypeFamily::BigInt => parse_big_int(default).map(|x| PrismaValue::BigInt(x)),
         ColumnTypeFamily::Float => parse_float(default).map(|x| PrismaValue::Float(x)),
+        ColumnTypeFamily::Decimal => parse_float(default).map(|x| PrismaValue::Decimal(x)),
         ColumnTypeFamily::String => Some(PrismaValue::String(default.to_string())),
         _ => None,
     }
@@ -474,8 +502,12 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
                 ColumnTypeFamily::Boolean => FieldType::Base(PrismaType::Boolean),
                 ColumnTypeFamily::DateTime => FieldType::Base(PrismaType::DateTime),
                 ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
+                ColumnTypeFamily::Decimal => FieldType::Base(PrismaType::Decimal),
                 ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
+                ColumnTypeFamily::BigInt => FieldType::Base(PrismaType::BigInt),
                 ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
+                ColumnTypeFamily::Json => FieldType::Base(PrismaType::Json),
+                ColumnTypeFamily::Binary => FieldType::Base(PrismaType::Bytes),
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
                 _ => FieldType::Base(PrismaType::String),
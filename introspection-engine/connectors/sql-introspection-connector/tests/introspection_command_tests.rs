@@ -50,7 +50,9 @@ fn a_data_model_can_be_generated_from_a_schema() {
 
     let col_types = vec![
         ColumnTypeFamily::Int,
+        ColumnTypeFamily::BigInt,
         ColumnTypeFamily::Float,
+        ColumnTypeFamily::Decimal,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
         ColumnTypeFamily::DateTime,
@@ -79,8 +81,12 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Boolean => FieldType::Base(PrismaType::Boolean),
                         ColumnTypeFamily::DateTime => FieldType::Base(PrismaType::DateTime),
                         ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
+                        ColumnTypeFamily::Decimal => FieldType::Base(PrismaType::Decimal),
                         ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
+                        ColumnTypeFamily::BigInt => FieldType::Base(PrismaType::BigInt),
                         ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
+                        ColumnTypeFamily::Json => FieldType::Base(PrismaType::Json),
+                        ColumnTypeFamily::Binary => FieldType::Base(PrismaType::Bytes),
                         // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                         // variants that don't yet have corresponding PrismaType variants
                         _ => FieldType::Base(PrismaType::String),
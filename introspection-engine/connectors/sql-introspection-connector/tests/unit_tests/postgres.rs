@@ -151,6 +151,35 @@ fn introspecting_a_table_with_datetime_default_values_should_work() {
     });
 }
 
+#[test]
+fn introspecting_array_columns_should_map_them_to_embedded_scalar_lists() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
+        let _setup_schema = barrel.execute(|migration| {
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"dates\" timestamp(3)[]");
+                t.inject_custom("\"decimals\" numeric[]");
+                t.inject_custom("\"floats\" Decimal(65,30)[]");
+                t.inject_custom("\"ints\" integer[] NOT NULL");
+                t.inject_custom("\"tags\" text[]");
+            });
+        });
+
+        let dm = r#"
+            model Post {
+                dates    DateTime[] @scalarList(strategy: EMBEDDED)
+                decimals Decimal[] @scalarList(strategy: EMBEDDED)
+                floats   Float[] @scalarList(strategy: EMBEDDED)
+                id       Int @id(strategy: NONE) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+                ints     Int[] @scalarList(strategy: EMBEDDED)
+                tags     String[] @scalarList(strategy: EMBEDDED)
+            }
+        "#;
+        let result = dbg!(introspect(test_setup));
+        custom_assert(&result, dm);
+    });
+}
+
 #[test]
 fn introspecting_a_table_with_default_values_should_work() {
     test_each_backend_with_ignores(vec![SqlFamily::Sqlite, SqlFamily::Mysql], |test_setup, barrel| {
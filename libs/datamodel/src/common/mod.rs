@@ -19,22 +19,28 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaType {
     Int,
+    BigInt,
     Float,
     Decimal,
     Boolean,
     String,
     DateTime,
+    Json,
+    Bytes,
 }
 
 impl FromStrAndSpan for PrismaType {
     fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, DatamodelError> {
         match s {
             "Int" => Ok(PrismaType::Int),
+            "BigInt" => Ok(PrismaType::BigInt),
             "Float" => Ok(PrismaType::Float),
             "Decimal" => Ok(PrismaType::Decimal),
             "Boolean" => Ok(PrismaType::Boolean),
             "String" => Ok(PrismaType::String),
             "DateTime" => Ok(PrismaType::DateTime),
+            "Json" => Ok(PrismaType::Json),
+            "Bytes" => Ok(PrismaType::Bytes),
             _ => Err(DatamodelError::new_type_not_found_error(s, span)),
         }
     }
@@ -44,11 +50,14 @@ impl ToString for PrismaType {
     fn to_string(&self) -> String {
         match self {
             PrismaType::Int => String::from("Int"),
+            PrismaType::BigInt => String::from("BigInt"),
             PrismaType::Float => String::from("Float"),
             PrismaType::Decimal => String::from("Decimal"),
             PrismaType::Boolean => String::from("Boolean"),
             PrismaType::String => String::from("String"),
             PrismaType::DateTime => String::from("DateTime"),
+            PrismaType::Json => String::from("Json"),
+            PrismaType::Bytes => String::from("Bytes"),
         }
     }
 }
@@ -57,6 +66,7 @@ impl ToString for PrismaType {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaValue {
     Int(i32),
+    BigInt(i64),
     Float(f32),
     Decimal(f32),
     Boolean(bool),
@@ -70,6 +80,7 @@ impl PrismaValue {
     fn get_type(&self) -> PrismaType {
         match self {
             PrismaValue::Int(_) => PrismaType::Int,
+            PrismaValue::BigInt(_) => PrismaType::BigInt,
             PrismaValue::Float(_) => PrismaType::Float,
             PrismaValue::Decimal(_) => PrismaType::Decimal,
             PrismaValue::Boolean(_) => PrismaType::Boolean,
@@ -87,6 +98,7 @@ impl ToString for PrismaValue {
     fn to_string(&self) -> String {
         match self {
             PrismaValue::Int(val) => val.to_string(),
+            PrismaValue::BigInt(val) => val.to_string(),
             PrismaValue::Float(val) => val.to_string(),
             PrismaValue::Decimal(val) => val.to_string(),
             PrismaValue::Boolean(val) => val.to_string(),
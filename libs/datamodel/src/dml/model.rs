@@ -32,6 +32,7 @@ pub struct IndexDefinition {
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
 
 impl Model {
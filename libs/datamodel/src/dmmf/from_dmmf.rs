@@ -74,6 +74,9 @@ fn default_value_from_serde(container: &Option<serde_json::Value>, field_type: &
         (Some(value), dml::FieldType::Base(scalar_type)) => Some(match (value, scalar_type) {
             (serde_json::Value::Bool(val), PrismaType::Boolean) => dml::Value::Boolean(*val),
             (serde_json::Value::String(val), PrismaType::String) => dml::Value::String(String::from(val.as_str())),
+            (serde_json::Value::String(val), PrismaType::Json) => dml::Value::String(String::from(val.as_str())),
+            (serde_json::Value::String(val), PrismaType::Bytes) => dml::Value::String(String::from(val.as_str())),
+            (serde_json::Value::String(val), PrismaType::BigInt) => dml::Value::BigInt(val.parse().unwrap()),
             (serde_json::Value::Number(val), PrismaType::Float) => dml::Value::Float(val.as_f64().unwrap() as f32),
             (serde_json::Value::Number(val), PrismaType::Int) => dml::Value::Int(val.as_i64().unwrap() as i32),
             (serde_json::Value::Number(val), PrismaType::Decimal) => dml::Value::Decimal(val.as_f64().unwrap() as f32),
@@ -107,6 +107,7 @@ fn value_to_serde(value: &dml::Value) -> serde_json::Value {
         dml::Value::ConstantLiteral(val) => serde_json::Value::String(val.clone()),
         dml::Value::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::Value::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        dml::Value::BigInt(val) => serde_json::Value::String(val.to_string()),
         dml::Value::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::Value::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         dml::Value::Expression(name, return_type, args) => function_to_serde(&name, *return_type, &args),
@@ -35,6 +35,7 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(embedded::EmbeddedDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
 
     validator
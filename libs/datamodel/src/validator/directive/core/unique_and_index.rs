@@ -1,3 +1,4 @@
+use crate::common::PrismaType;
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
 use crate::{ast, dml, IndexDefinition, IndexType};
@@ -87,7 +88,58 @@ impl DirectiveValidator<dml::Model> for ModelLevelIndexDirectiveValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` directive.
+pub struct ModelLevelFulltextDirectiveValidator {}
+
+impl IndexDirectiveBase<dml::Model> for ModelLevelFulltextDirectiveValidator {}
+impl DirectiveValidator<dml::Model> for ModelLevelFulltextDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+
+        let non_string_fields: Vec<String> = index_def
+            .fields
+            .iter()
+            .filter(|field| match obj.find_field(&field).map(|f| &f.field_type) {
+                Some(dml::FieldType::Base(PrismaType::String)) => false,
+                _ => true,
+            })
+            .map(|field| field.to_string())
+            .collect();
+
+        if !non_string_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The fulltext index definition refers to the fields {}, but only String fields can be searched.",
+                    non_string_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.indexes.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexDirectiveBase<T>: DirectiveValidator<T> {
     fn validate_index(
         &self,
@@ -130,7 +182,11 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    match index_type {
+                        IndexType::Unique => "unique ",
+                        IndexType::Normal => "",
+                        IndexType::Fulltext => "fulltext ",
+                    },
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
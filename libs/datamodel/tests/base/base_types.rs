@@ -11,6 +11,8 @@ fn parse_scalar_types() {
         isPro Boolean
         balance Decimal
         averageGrade Float
+        views BigInt
+        avatar Bytes
     }
     "#;
 
@@ -29,6 +31,12 @@ fn parse_scalar_types() {
     user_model
         .assert_has_field("averageGrade")
         .assert_base_type(&PrismaType::Float);
+    user_model
+        .assert_has_field("views")
+        .assert_base_type(&PrismaType::BigInt);
+    user_model
+        .assert_has_field("avatar")
+        .assert_base_type(&PrismaType::Bytes);
 }
 
 #[test]
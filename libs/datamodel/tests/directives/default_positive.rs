@@ -8,6 +8,7 @@ fn should_set_default_for_all_scalar_types() {
     model Model {
         id Int @id
         int Int @default(3)
+        bigInt BigInt @default(9007199254740993)
         float Float @default(3.14)
         decimal Decimal @default(3.15)
         string String @default("String")
@@ -22,6 +23,10 @@ fn should_set_default_for_all_scalar_types() {
         .assert_has_field("int")
         .assert_base_type(&PrismaType::Int)
         .assert_default_value(PrismaValue::Int(3));
+    user_model
+        .assert_has_field("bigInt")
+        .assert_base_type(&PrismaType::BigInt)
+        .assert_default_value(PrismaValue::BigInt(9007199254740993));
     user_model
         .assert_has_field("float")
         .assert_base_type(&PrismaType::Float)
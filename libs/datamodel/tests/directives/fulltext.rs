@@ -0,0 +1,62 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn basic_fulltext_index_must_work() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        body  String
+
+        @@fulltext([title,body], name: "PostSearch")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("PostSearch".to_string()),
+        fields: vec!["title".to_string(), "body".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn must_error_when_non_string_fields_are_used() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        likes Int
+
+        @@fulltext([title,likes])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The fulltext index definition refers to the fields likes, but only String fields can be searched.",
+        "Post",
+        Span::new(93, 116),
+    ));
+}
+
+#[test]
+fn fulltext_directives_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Post {
+            id    Int    @id
+            title String
+
+            @@fulltext([title])
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("@@fulltext([title])"));
+    assert!(datamodel::parse_datamodel(&rendered).is_ok());
+}
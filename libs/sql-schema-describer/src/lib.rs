@@ -148,6 +148,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text search index: a `FULLTEXT` index on MySQL, an FTS5 virtual table on SQLite.
+    Fulltext,
 }
 
 /// An index of a table.
@@ -239,8 +241,12 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64 bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
+    /// Exact decimal types.
+    Decimal,
     /// Boolean types.
     Boolean,
     /// String types.
@@ -269,7 +275,9 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int",
+            Self::BigInt => "bigInt",
             Self::Float => "float",
+            Self::Decimal => "decimal",
             Self::Boolean => "boolean",
             Self::String => "string",
             Self::DateTime => "dateTime",
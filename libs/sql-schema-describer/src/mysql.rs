@@ -74,7 +74,8 @@ impl SqlSchemaDescriber {
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra,
+                numeric_precision numeric_precision, numeric_scale numeric_scale
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -99,7 +100,11 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(data_type.as_ref());
+                let precision_and_scale = col
+                    .get("numeric_precision")
+                    .and_then(|x| x.as_i64())
+                    .and_then(|precision| Some((precision, col.get("numeric_scale")?.as_i64()?)));
+                let tpe = get_column_type(data_type.as_ref(), precision_and_scale);
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -254,7 +259,8 @@ impl SqlSchemaDescriber {
                 index_name AS index_name,
                 non_unique AS non_unique,
                 column_name AS column_name,
-                seq_in_index AS seq_in_index
+                seq_in_index AS seq_in_index,
+                index_type AS index_type
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ? AND table_name = ?
             ORDER BY index_name, seq_in_index
@@ -278,6 +284,7 @@ impl SqlSchemaDescriber {
             let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
             let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
             let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
+            let is_fulltext = row.get("index_type").and_then(|x| x.to_string()).expect("index_type") == "FULLTEXT";
             let is_pk = index_name.to_lowercase() == "primary";
             if is_pk {
                 debug!("Column '{}' is part of the primary key", column_name);
@@ -311,9 +318,10 @@ impl SqlSchemaDescriber {
                         Index {
                             name: index_name,
                             columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
+                            tpe: match (is_unique, is_fulltext) {
+                                (_, true) => IndexType::Fulltext,
+                                (true, false) => IndexType::Unique,
+                                (false, false) => IndexType::Normal,
                             },
                         },
                     );
@@ -335,15 +343,19 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(data_type: &str) -> ColumnType {
+/// Float fields are stored as `Decimal(65,30)`, all other decimal columns hold exact decimals.
+const FLOAT_STORAGE: (i64, i64) = (65, 30);
+
+fn get_column_type(data_type: &str, precision_and_scale: Option<(i64, i64)>) -> ColumnType {
     let family = match data_type {
         "int" => ColumnTypeFamily::Int,
         "smallint" => ColumnTypeFamily::Int,
         "tinyint" => ColumnTypeFamily::Boolean,
         "mediumint" => ColumnTypeFamily::Int,
-        "bigint" => ColumnTypeFamily::Int,
-        "decimal" => ColumnTypeFamily::Float,
-        "numeric" => ColumnTypeFamily::Float,
+        "bigint" => ColumnTypeFamily::BigInt,
+        "decimal" | "numeric" if precision_and_scale == Some(FLOAT_STORAGE) => ColumnTypeFamily::Float,
+        "decimal" => ColumnTypeFamily::Decimal,
+        "numeric" => ColumnTypeFamily::Decimal,
         "float" => ColumnTypeFamily::Float,
         "double" => ColumnTypeFamily::Float,
         "date" => ColumnTypeFamily::DateTime,
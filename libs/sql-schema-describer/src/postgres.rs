@@ -74,10 +74,15 @@ impl SqlSchemaDescriber {
     }
 
     fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type
-            FROM information_schema.columns
-            WHERE table_schema = $1 AND table_name = $2
-            ORDER BY column_name";
+        // The formatted type holds the precision and scale of numeric columns, also for arrays.
+        let sql = "SELECT info.column_name, info.udt_name, info.column_default, info.is_nullable, info.is_identity,
+                info.data_type, format_type(att.atttypid, att.atttypmod) formatted_type
+            FROM information_schema.columns info
+            JOIN pg_attribute att
+                ON att.attrelid = (quote_ident(info.table_schema) || '.' || quote_ident(info.table_name))::regclass
+                AND att.attname = info.column_name
+            WHERE info.table_schema = $1 AND info.table_name = $2
+            ORDER BY info.column_name";
         let rows = self
             .conn
             .query_raw(&sql, &[schema.into(), table.into()])
@@ -111,7 +116,11 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(udt.as_ref());
+                let formatted_type = col
+                    .get("formatted_type")
+                    .and_then(|x| x.to_string())
+                    .expect("get formatted_type");
+                let tpe = get_column_type(udt.as_ref(), formatted_type.as_ref());
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -294,7 +303,7 @@ impl SqlSchemaDescriber {
             .query_raw(&sql, &[schema.into(), table_name.into()])
             .expect("querying for indices");
         let mut pk: Option<PrimaryKey> = None;
-        let indices = rows
+        let mut indices: Vec<Index> = rows
             .into_iter()
             .filter_map(|index| {
                 debug!("Got index: {:?}", index);
@@ -324,10 +333,48 @@ impl SqlSchemaDescriber {
             })
             .collect();
 
+        indices.extend(self.get_fulltext_indices(schema, table_name));
+
         debug!("Found table indices: {:?}, primary key: {:?}", indices, pk);
         (indices, pk)
     }
 
+    /// Full-text indexes are GIN indexes on the expression
+    /// `to_tsvector('english', COALESCE(col1, '') || ' ' || COALESCE(col2, ''))`, which the query above doesn't
+    /// find as they don't index columns directly. Their columns are read from the index definition.
+    fn get_fulltext_indices(&self, schema: &str, table_name: &str) -> Vec<Index> {
+        let sql = "SELECT indexInfos.relname as name, pg_get_indexdef(rawIndex.indexrelid) as definition
+            FROM pg_index rawIndex
+            JOIN pg_class indexInfos ON indexInfos.oid = rawIndex.indexrelid
+            JOIN pg_class tableInfos ON tableInfos.oid = rawIndex.indrelid
+            JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            JOIN pg_am accessMethod ON accessMethod.oid = indexInfos.relam
+            WHERE accessMethod.amname = 'gin'
+            AND schemaInfo.nspname = $1
+            AND tableInfos.relname = $2";
+        debug!("Getting full-text indices: {}", sql);
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table_name.into()])
+            .expect("querying for full-text indices");
+
+        rows.into_iter()
+            .filter_map(|index| {
+                let definition = index.get("definition").and_then(|x| x.to_string()).expect("definition");
+
+                if !definition.contains("to_tsvector(") {
+                    return None;
+                }
+
+                Some(Index {
+                    name: index.get("name").and_then(|x| x.to_string()).expect("name"),
+                    columns: fulltext_index_columns(&definition),
+                    tpe: IndexType::Fulltext,
+                })
+            })
+            .collect()
+    }
+
     fn infer_primary_key(
         &self,
         schema: &str,
@@ -434,11 +481,14 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(udt: &str) -> ColumnType {
+/// Float fields are stored as `Decimal(65,30)`, all other numeric columns hold exact decimals.
+const FLOAT_STORAGE: &str = "numeric(65,30)";
+
+fn get_column_type(udt: &str, formatted_type: &str) -> ColumnType {
     let family = match udt {
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
-        "int8" => ColumnTypeFamily::Int,
+        "int8" => ColumnTypeFamily::BigInt,
         "float4" => ColumnTypeFamily::Float,
         "float8" => ColumnTypeFamily::Float,
         "bool" => ColumnTypeFamily::Boolean,
@@ -459,7 +509,8 @@ fn get_column_type(udt: &str) -> ColumnType {
         "polygon" => ColumnTypeFamily::Geometric,
         "bpchar" => ColumnTypeFamily::String,
         "interval" => ColumnTypeFamily::DateTime,
-        "numeric" => ColumnTypeFamily::Float,
+        "numeric" if formatted_type == FLOAT_STORAGE => ColumnTypeFamily::Float,
+        "numeric" => ColumnTypeFamily::Decimal,
         "pg_lsn" => ColumnTypeFamily::LogSequenceNumber,
         "time" => ColumnTypeFamily::DateTime,
         "timetz" => ColumnTypeFamily::DateTime,
@@ -468,15 +519,25 @@ fn get_column_type(udt: &str) -> ColumnType {
         "tsquery" => ColumnTypeFamily::TextSearch,
         "tsvector" => ColumnTypeFamily::TextSearch,
         "txid_snapshot" => ColumnTypeFamily::TransactionId,
-        // Array types
+        // Array types, e.g. of embedded scalar lists
         "_bytea" => ColumnTypeFamily::Binary,
         "_bool" => ColumnTypeFamily::Boolean,
         "_date" => ColumnTypeFamily::DateTime,
+        "_timestamp" => ColumnTypeFamily::DateTime,
+        "_timestamptz" => ColumnTypeFamily::DateTime,
         "_float8" => ColumnTypeFamily::Float,
         "_float4" => ColumnTypeFamily::Float,
+        "_numeric" if formatted_type.trim_end_matches("[]") == FLOAT_STORAGE => ColumnTypeFamily::Float,
+        "_numeric" => ColumnTypeFamily::Decimal,
+        "_int2" => ColumnTypeFamily::Int,
         "_int4" => ColumnTypeFamily::Int,
+        "_int8" => ColumnTypeFamily::BigInt,
         "_text" => ColumnTypeFamily::String,
         "_varchar" => ColumnTypeFamily::String,
+        "_bpchar" => ColumnTypeFamily::String,
+        "_json" => ColumnTypeFamily::Json,
+        "_jsonb" => ColumnTypeFamily::Json,
+        "_uuid" => ColumnTypeFamily::Uuid,
         x => panic!(format!("type '{}' is not supported here yet.", x)),
     };
     ColumnType {
@@ -484,3 +545,23 @@ fn get_column_type(udt: &str) -> ColumnType {
         family: family,
     }
 }
+
+/// The columns of a full-text index, from its definition like `... USING gin (to_tsvector('english'::regconfig,
+/// ((COALESCE(title, ''::text) || ' '::text) || COALESCE(body, ''::text))))`.
+fn fulltext_index_columns(definition: &str) -> Vec<String> {
+    definition
+        .split("COALESCE(")
+        .skip(1)
+        .filter_map(|part| part.find(", ''").map(|end| &part[..end]))
+        .map(|column| {
+            // Columns of other types than text are cast, e.g. `(title)::text`.
+            let column = column.trim_start_matches('(').split(")::").next().unwrap_or(column);
+
+            if column.len() > 1 && column.starts_with('"') && column.ends_with('"') {
+                column[1..column.len() - 1].replace("\"\"", "\"")
+            } else {
+                column.to_string()
+            }
+        })
+        .collect()
+}
@@ -43,15 +43,40 @@ impl SqlSchemaDescriber {
         let sql = format!(r#"SELECT name FROM "{}".sqlite_master WHERE type='table'"#, schema);
         debug!("describing table names with query: '{}'", sql);
         let result_set = self.conn.query_raw(&sql, &[]).expect("get table names");
+        let fulltext_tables: Vec<String> = self
+            .get_fulltext_tables(schema)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
         let names = result_set
             .into_iter()
             .map(|row| row.get("name").and_then(|x| x.to_string()).unwrap())
             .filter(|n| n != "sqlite_sequence")
+            // Full-text indexes are virtual tables, which are described as indices of their content table.
+            .filter(|n| !is_fulltext_table(n, &fulltext_tables))
             .collect();
         debug!("Found table names: {:?}", names);
         names
     }
 
+    /// The FTS5 virtual tables of full-text indexes, with the name of the table they index.
+    fn get_fulltext_tables(&self, schema: &str) -> Vec<(String, String)> {
+        let sql = format!(
+            r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='table' AND sql LIKE 'CREATE VIRTUAL TABLE%USING fts5%'"#,
+            schema
+        );
+        debug!("describing full-text tables with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).expect("get full-text tables");
+        result_set
+            .into_iter()
+            .filter_map(|row| {
+                let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+                let sql = row.get("sql").and_then(|x| x.to_string()).expect("get sql");
+                fulltext_content_table(&sql).map(|content_table| (name, content_table))
+            })
+            .collect()
+    }
+
     fn get_table(&self, schema: &str, name: &str) -> Table {
         debug!("describing table '{}' in schema '{}", name, schema);
         let (columns, primary_key) = self.get_columns(schema, name);
@@ -288,8 +313,54 @@ impl SqlSchemaDescriber {
 
                 index
             })
+            .chain(self.get_fulltext_indices(schema, table))
             .collect()
     }
+
+    fn get_fulltext_indices(&self, schema: &str, table: &str) -> Vec<Index> {
+        self.get_fulltext_tables(schema)
+            .into_iter()
+            .filter(|(_, content_table)| content_table == table)
+            .map(|(name, _)| {
+                let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, name);
+                debug!("describing full-text index '{}', SQL: '{}'", name, sql);
+                let result_set = self
+                    .conn
+                    .query_raw(&sql, &[])
+                    .expect("querying for full-text index info");
+                let columns = result_set
+                    .into_iter()
+                    .map(|row| row.get("name").and_then(|x| x.to_string()).expect("get name"))
+                    .collect();
+
+                Index {
+                    name,
+                    columns,
+                    tpe: IndexType::Fulltext,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The shadow tables SQLite creates to store an FTS5 virtual table.
+const FTS5_SHADOW_TABLE_SUFFIXES: &[&str] = &["_data", "_idx", "_content", "_docsize", "_config"];
+
+fn is_fulltext_table(table_name: &str, fulltext_tables: &[String]) -> bool {
+    fulltext_tables.iter().any(|fulltext_table| {
+        table_name == fulltext_table
+            || FTS5_SHADOW_TABLE_SUFFIXES
+                .iter()
+                .any(|suffix| table_name == format!("{}{}", fulltext_table, suffix))
+    })
+}
+
+/// The table an FTS5 virtual table indexes, from the `content='...'` option of its definition.
+fn fulltext_content_table(sql: &str) -> Option<String> {
+    let start = sql.find("content='")? + "content='".len();
+    let length = sql[start..].find('\'')?;
+
+    Some(sql[start..start + length].to_string())
 }
 
 fn get_column_type(tpe: &str) -> ColumnType {
@@ -301,13 +372,17 @@ fn get_column_type(tpe: &str) -> ColumnType {
         "real" => ColumnTypeFamily::Float,
         "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
+        "json text" => ColumnTypeFamily::Json,
         s if s.contains("char") => ColumnTypeFamily::String,
-        s if s.contains("numeric") => ColumnTypeFamily::Float,
+        s if s.contains("numeric") => ColumnTypeFamily::Decimal,
+        "decimal" => ColumnTypeFamily::Decimal,
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -22,6 +22,7 @@ fn all_mysql_column_types_must_work() {
         t.add_column("bigint_col", types::custom("bigint"));
         t.add_column("decimal_col", types::custom("decimal"));
         t.add_column("numeric_col", types::custom("numeric"));
+        t.add_column("float_storage_col", types::custom("decimal(65,30)"));
         t.add_column("float_col", types::custom("float"));
         t.add_column("double_col", types::custom("double"));
         t.add_column("date_col", types::custom("date"));
@@ -115,7 +116,7 @@ fn all_mysql_column_types_must_work() {
             name: "bigint_col".to_string(),
             tpe: ColumnType {
                 raw: "bigint".to_string(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
             },
             arity: ColumnArity::Required,
             default: None,
@@ -125,7 +126,7 @@ fn all_mysql_column_types_must_work() {
             name: "decimal_col".to_string(),
             tpe: ColumnType {
                 raw: "decimal".to_string(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
             },
             arity: ColumnArity::Required,
             default: None,
@@ -133,6 +134,16 @@ fn all_mysql_column_types_must_work() {
         },
         Column {
             name: "numeric_col".to_string(),
+            tpe: ColumnType {
+                raw: "decimal".to_string(),
+                family: ColumnTypeFamily::Decimal,
+            },
+            arity: ColumnArity::Required,
+            default: None,
+            auto_increment: false,
+        },
+        Column {
+            name: "float_storage_col".to_string(),
             tpe: ColumnType {
                 raw: "decimal".to_string(),
                 family: ColumnTypeFamily::Float,
@@ -35,6 +35,7 @@ fn all_postgres_column_types_must_work() {
         t.add_column("date_time_col", types::date());
         t.add_column("double_col", types::double());
         t.add_column("float_col", types::float());
+        t.add_column("float_storage_col", types::custom("DECIMAL(65,30)"));
         t.add_column("int_col", types::integer());
         t.add_column("interval_col", types::custom("INTERVAL"));
         t.add_column("line_col", types::custom("LINE"));
@@ -199,6 +200,16 @@ fn all_postgres_column_types_must_work() {
             default: None,
             auto_increment: false,
         },
+        Column {
+            name: "float_storage_col".into(),
+            tpe: ColumnType {
+                raw: "numeric".into(),
+                family: ColumnTypeFamily::Float,
+            },
+            arity: ColumnArity::Required,
+            default: None,
+            auto_increment: false,
+        },
         Column {
             name: "int_col".into(),
             tpe: ColumnType {
@@ -243,7 +254,7 @@ fn all_postgres_column_types_must_work() {
             name: "bigint_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
             },
             arity: ColumnArity::Required,
             default: None,
@@ -253,7 +264,7 @@ fn all_postgres_column_types_must_work() {
             name: "bigserial_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
             },
             arity: ColumnArity::Required,
             default: Some(format!("nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)", SCHEMA)),
@@ -343,7 +354,7 @@ fn all_postgres_column_types_must_work() {
             name: "numeric_col".into(),
             tpe: ColumnType {
                 raw: "numeric".into(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
             },
             arity: ColumnArity::Required,
             default: None,
@@ -713,3 +724,27 @@ fn postgres_sequences_must_work() {
         },
     );
 }
+
+#[test]
+fn postgres_fulltext_indices_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TABLE \"{0}\".\"Post\" (id SERIAL PRIMARY KEY, title TEXT NOT NULL, \"Body\" TEXT);
+         CREATE INDEX \"Post.title_Body\" ON \"{0}\".\"Post\"
+         USING gin (to_tsvector('english', COALESCE(title, '') || ' ' || COALESCE(\"Body\", '')))",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("Post");
+
+    assert_eq!(
+        table.indices,
+        vec![Index {
+            name: "Post.title_Body".into(),
+            columns: vec!["title".into(), "Body".into()],
+            tpe: IndexType::Fulltext,
+        }]
+    );
+}
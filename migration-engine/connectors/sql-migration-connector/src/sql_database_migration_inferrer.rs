@@ -21,7 +21,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.introspect(&self.schema_name)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
@@ -36,8 +36,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.sql_family)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
@@ -90,7 +90,8 @@ fn infer_database_migration_steps_and_fix(
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
-        fix_stupid_sqlite(diff, &from, &to, &schema_name)?
+        let steps = fix_stupid_sqlite(diff, &from, &to, &schema_name)?;
+        sqlite_fulltext_steps(steps, &from, &schema_name)
     } else {
         let steps = delay_foreign_key_creation(diff);
         fix_id_column_type_change(&from, &to, schema_name, steps)?
@@ -304,6 +305,20 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
         },
     );
 
+    // Full-text indexes are separate tables, they are rebuilt for the new table like the other indexes.
+    result.append(
+        &mut current
+            .indices
+            .iter()
+            .filter(|index| index.tpe == IndexType::Fulltext)
+            .map(|index| {
+                SqlMigrationStep::DropIndex(DropIndex {
+                    table: current.name.clone(),
+                    name: index.name.clone(),
+                })
+            })
+            .collect(),
+    );
     result.push(SqlMigrationStep::DropTable(DropTable {
         name: current.name.clone(),
     }));
@@ -335,6 +350,88 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
     result
 }
 
+/// The triggers keeping an SQLite full-text index up to date, by the suffix of their name.
+const SQLITE_FULLTEXT_TRIGGERS: &[&str] = &["_insert", "_delete", "_update"];
+
+/// Full-text indexes on SQLite are FTS5 virtual tables indexing the content of the table, which have to be
+/// updated by triggers on the table. Adds the triggers and the initial indexing to the creation of full-text
+/// indexes, and drops the triggers and the virtual table instead of dropping an index.
+fn sqlite_fulltext_steps(steps: Vec<SqlMigrationStep>, from: &SqlSchema, schema_name: &str) -> Vec<SqlMigrationStep> {
+    let mut result = Vec::new();
+
+    for step in steps {
+        match step {
+            SqlMigrationStep::CreateIndex(ref create_index) if create_index.index.tpe == IndexType::Fulltext => {
+                let CreateIndex { table, index } = create_index;
+                let name = &index.name;
+                let columns: Vec<String> = index.columns.iter().map(|c| format!("\"{}\"", c)).collect();
+                let columns = columns.join(",");
+                let values = |record: &str| {
+                    let values: Vec<String> = index.columns.iter().map(|c| format!("{}.\"{}\"", record, c)).collect();
+                    values.join(",")
+                };
+
+                let insert = format!(
+                    "INSERT INTO \"{}\"(rowid,{}) VALUES (new.rowid,{});",
+                    name,
+                    columns,
+                    values("new")
+                );
+                let delete = format!(
+                    "INSERT INTO \"{}\"(\"{}\",rowid,{}) VALUES ('delete',old.rowid,{});",
+                    name,
+                    name,
+                    columns,
+                    values("old")
+                );
+                let trigger = |suffix: &str, event: &str, body: String| SqlMigrationStep::RawSql {
+                    raw: format!(
+                        "CREATE TRIGGER \"{}\".\"{}{}\" AFTER {} ON \"{}\" BEGIN {} END",
+                        schema_name, name, suffix, event, table, body
+                    ),
+                };
+
+                result.push(step.clone());
+                result.push(trigger("_insert", "INSERT", insert.clone()));
+                result.push(trigger("_delete", "DELETE", delete.clone()));
+                result.push(trigger("_update", "UPDATE", format!("{} {}", delete, insert)));
+                result.push(SqlMigrationStep::RawSql {
+                    raw: format!(
+                        "INSERT INTO \"{}\".\"{}\"(\"{}\") VALUES ('rebuild')",
+                        schema_name, name, name
+                    ),
+                });
+            }
+            SqlMigrationStep::DropIndex(ref drop_index) if is_fulltext_index(from, &drop_index) => {
+                for suffix in SQLITE_FULLTEXT_TRIGGERS {
+                    result.push(SqlMigrationStep::RawSql {
+                        raw: format!(
+                            "DROP TRIGGER IF EXISTS \"{}\".\"{}{}\"",
+                            schema_name, drop_index.name, suffix
+                        ),
+                    });
+                }
+
+                result.push(SqlMigrationStep::RawSql {
+                    raw: format!("DROP TABLE IF EXISTS \"{}\".\"{}\"", schema_name, drop_index.name),
+                });
+            }
+            step => result.push(step),
+        }
+    }
+
+    result
+}
+
+fn is_fulltext_index(schema: &SqlSchema, drop_index: &DropIndex) -> bool {
+    schema
+        .table(&drop_index.table)
+        .ok()
+        .and_then(|table| table.indices.iter().find(|index| index.name == drop_index.name))
+        .map(|index| index.tpe == IndexType::Fulltext)
+        .unwrap_or(false)
+}
+
 pub fn wrap_as_step<T, F>(steps: Vec<T>, mut wrap_fn: F) -> Vec<SqlMigrationStep>
 where
     F: FnMut(T) -> SqlMigrationStep,
@@ -152,11 +152,40 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                 lines.join(",\n")
             )
         }
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index })
+            if index.tpe == IndexType::Fulltext && sql_family == SqlFamily::Sqlite =>
+        {
+            // The triggers keeping the index up to date are separate steps, see `sqlite_fulltext_steps`.
+            let columns: Vec<String> = index.columns.iter().map(|c| renderer.quote(c)).collect();
+            format!(
+                "CREATE VIRTUAL TABLE {} USING fts5({}, content='{}')",
+                renderer.quote_with_schema(&schema_name, &index.name),
+                columns.join(","),
+                table
+            )
+        }
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index })
+            if index.tpe == IndexType::Fulltext && sql_family == SqlFamily::Postgres =>
+        {
+            // The query engine searches this exact expression, so that the index is used.
+            let columns: Vec<String> = index
+                .columns
+                .iter()
+                .map(|c| format!("COALESCE({}, '')", renderer.quote(c)))
+                .collect();
+            format!(
+                "CREATE INDEX {} ON {} USING gin (to_tsvector('english', {}))",
+                renderer.quote(&index.name),
+                renderer.quote_with_schema(&schema_name, &table),
+                columns.join(" || ' ' || ")
+            )
+        }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
             let Index { name, columns, tpe } = index;
             let index_type = match tpe {
                 IndexType::Unique => "UNIQUE",
                 IndexType::Normal => "",
+                IndexType::Fulltext => "FULLTEXT",
             };
             let index_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote_with_schema(&schema_name, &name),
@@ -13,7 +13,10 @@ pub fn render_default(column: &Column) -> String {
     match &column.default {
         Some(value) => {
             let default = match column.tpe.family {
-                ColumnTypeFamily::String | ColumnTypeFamily::DateTime => {
+                ColumnTypeFamily::String
+                | ColumnTypeFamily::DateTime
+                | ColumnTypeFamily::Json
+                | ColumnTypeFamily::Binary => {
                     // TODO: find a better solution for this amazing hack. the default value must not be a String
                     if value.starts_with("'") {
                         format!("DEFAULT {}", value)
@@ -11,7 +11,11 @@ impl super::SqlRenderer for MySqlRenderer {
         let column_name = self.quote(&column.name);
         let tpe_str = self.render_column_type(&column.tpe);
         let nullability_str = render_nullability(&table, &column);
-        let default_str = render_default(&column);
+        // Blob columns can't have a default value.
+        let default_str = match column.tpe.family {
+            ColumnTypeFamily::Binary => String::new(),
+            _ => render_default(&column),
+        };
         let foreign_key = table.foreign_key_for_column(&column.name);
         let references_str = self.render_references(&schema_name, foreign_key);
         let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
@@ -36,11 +40,16 @@ impl super::SqlRenderer for MySqlRenderer {
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
+            // Float fields keep their decimal storage, decimals have one digit less to tell them apart.
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
+            ColumnTypeFamily::Decimal => format!("Decimal(64,30)"),
             ColumnTypeFamily::Int => format!("int"),
+            ColumnTypeFamily::BigInt => format!("bigint"),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar(191)"),
+            ColumnTypeFamily::Json => format!("json"),
+            ColumnTypeFamily::Binary => format!("longblob"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
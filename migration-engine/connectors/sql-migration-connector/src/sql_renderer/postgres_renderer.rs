@@ -9,7 +9,11 @@ impl super::SqlRenderer for PostgresRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = match column.arity {
+            // Embedded scalar lists are stored in native arrays.
+            ColumnArity::List => format!("{}[]", self.render_column_type(&column.tpe)),
+            _ => self.render_column_type(&column.tpe),
+        };
         let nullability_str = render_nullability(&table, &column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -31,9 +35,14 @@ impl super::SqlRenderer for PostgresRenderer {
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("timestamp(3)"),
+            // Float fields keep their decimal storage, decimals are unconstrained to tell them apart.
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
+            ColumnTypeFamily::Decimal => format!("numeric"),
             ColumnTypeFamily::Int => format!("integer"),
+            ColumnTypeFamily::BigInt => format!("bigint"),
             ColumnTypeFamily::String => format!("text"),
+            ColumnTypeFamily::Json => format!("jsonb"),
+            ColumnTypeFamily::Binary => format!("bytea"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -31,8 +31,14 @@ impl super::SqlRenderer for SqliteRenderer {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
+            // Numeric affinity compares decimals as numbers, but can only store them as integers and floats.
+            ColumnTypeFamily::Decimal => format!("DECIMAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
+            ColumnTypeFamily::BigInt => format!("BIGINT"),
             ColumnTypeFamily::String => format!("TEXT"),
+            // A plain `JSON` type would have numeric affinity, converting documents like `1` to numbers.
+            ColumnTypeFamily::Json => format!("JSON TEXT"),
+            ColumnTypeFamily::Binary => format!("BLOB"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
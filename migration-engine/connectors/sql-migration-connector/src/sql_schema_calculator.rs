@@ -1,4 +1,4 @@
-use crate::SqlResult;
+use crate::{SqlError, SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
@@ -8,15 +8,18 @@ use sql_schema_describer as sql;
 
 pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<sql::SqlSchema> {
-        let calculator = SqlSchemaCalculator { data_model };
+    pub fn calculate(data_model: &Datamodel, sql_family: SqlFamily) -> SqlResult<sql::SqlSchema> {
+        let calculator = SqlSchemaCalculator { data_model, sql_family };
         calculator.calculate_internal()
     }
 
     fn calculate_internal(&self) -> SqlResult<sql::SqlSchema> {
+        self.validate_embedded_lists()?;
+
         let mut tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables()?;
         let mut model_tables = self.add_inline_relations_to_model_tables(model_tables_without_inline_relations)?;
@@ -49,12 +52,18 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let columns = model
                     .fields()
                     .flat_map(|f| match (&f.field_type, &f.arity) {
-                        (FieldType::Base(_), arity) | (FieldType::Enum(_), arity) if arity != &FieldArity::List => {
+                        (FieldType::Base(_), arity) | (FieldType::Enum(_), arity)
+                            if arity != &FieldArity::List || f.is_embedded_list() =>
+                        {
                             Some(sql::Column {
                                 name: f.db_name(),
                                 tpe: column_type(f),
                                 arity: column_arity(&f),
-                                default: f.migration_value_new(&self.data_model),
+                                default: if f.is_embedded_list() {
+                                    None
+                                } else {
+                                    f.migration_value_new(&self.data_model)
+                                },
                                 auto_increment: {
                                     if column_type(f).family == sql::ColumnTypeFamily::Int {
                                         f.is_id()
@@ -103,10 +112,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                         // The model index definition uses the model field names, but the SQL Index
                         // wants the column names.
                         columns: referenced_fields.iter().map(|field| field.db_name()).collect(),
-                        tpe: if index_definition.tpe == IndexType::Unique {
-                            sql::IndexType::Unique
-                        } else {
-                            sql::IndexType::Normal
+                        tpe: match index_definition.tpe {
+                            IndexType::Unique => sql::IndexType::Unique,
+                            IndexType::Normal => sql::IndexType::Normal,
+                            IndexType::Fulltext => sql::IndexType::Fulltext,
                         },
                     }
                 });
@@ -127,13 +136,31 @@ impl<'a> SqlSchemaCalculator<'a> {
             .collect()
     }
 
+    /// Embedded scalar lists are stored in native array columns, which only PostgreSQL has.
+    fn validate_embedded_lists(&self) -> SqlResult<()> {
+        if self.sql_family == SqlFamily::Postgres {
+            return Ok(());
+        }
+
+        for model in self.data_model.models() {
+            if let Some(field) = model.fields().find(|f| f.is_embedded_list()) {
+                return Err(SqlError::Generic(format!(
+                    "The scalar list field `{}` of model `{}` uses `@scalarList(strategy: EMBEDDED)`, which is only supported on PostgreSQL.",
+                    field.name, model.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn calculate_scalar_list_tables(&self) -> SqlResult<Vec<sql::Table>> {
         let mut result = Vec::new();
 
         for model in self.data_model.models() {
             let list_fields: Vec<&Field> = model
                 .fields()
-                .filter(|f| f.arity == FieldArity::List && is_scalar(f))
+                .filter(|f| f.arity == FieldArity::List && is_scalar(f) && !f.is_embedded_list())
                 .collect();
             for field in list_fields {
                 let id_field = model.id_field()?;
@@ -322,6 +349,8 @@ pub trait FieldExtensions {
     fn migration_value(&self, datamodel: &Datamodel) -> Value;
 
     fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String>;
+
+    fn is_embedded_list(&self) -> bool;
 }
 
 impl FieldExtensions for Field {
@@ -333,6 +362,10 @@ impl FieldExtensions for Field {
         self.arity == FieldArity::List
     }
 
+    fn is_embedded_list(&self) -> bool {
+        self.is_list() && self.scalar_list_strategy == Some(ScalarListStrategy::Embedded)
+    }
+
     fn is_required(&self) -> bool {
         self.arity == FieldArity::Required
     }
@@ -364,6 +397,7 @@ impl FieldExtensions for Field {
                 }
             }
             Value::Int(x) => format!("{}", x),
+            Value::BigInt(x) => format!("{}", x),
             Value::Float(x) => format!("{}", x),
             Value::Decimal(x) => format!("{}", x),
             Value::String(x) => format!("{}", x),
@@ -390,9 +424,12 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Val
     match field_type {
         FieldType::Base(PrismaType::Boolean) => Value::Boolean(false),
         FieldType::Base(PrismaType::Int) => Value::Int(0),
+        FieldType::Base(PrismaType::BigInt) => Value::BigInt(0),
         FieldType::Base(PrismaType::Float) => Value::Float(0.0),
         FieldType::Base(PrismaType::String) => Value::String("".to_string()),
         FieldType::Base(PrismaType::Decimal) => Value::Decimal(0.0),
+        FieldType::Base(PrismaType::Json) => Value::String("{}".to_string()),
+        FieldType::Base(PrismaType::Bytes) => Value::String("".to_string()),
         FieldType::Base(PrismaType::DateTime) => {
             let naive = NaiveDateTime::from_timestamp(0, 0);
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
@@ -442,10 +479,13 @@ fn column_arity(field: &Field) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime),
-        ScalarType::Decimal => unimplemented!(),
+        ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json),
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Decimal),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary),
     }
 }
@@ -14,6 +14,9 @@ fn adding_a_scalar_field_must_work() {
                 id String @id @default(cuid())
                 int Int
                 float Float
+                decimal Decimal
+                bigInt BigInt
+                bytes Bytes
                 boolean Boolean
                 string String
                 dateTime DateTime
@@ -31,6 +34,9 @@ fn adding_a_scalar_field_must_work() {
 
         assert_eq!(table.column_bang("int").tpe.family, ColumnTypeFamily::Int);
         assert_eq!(table.column_bang("float").tpe.family, ColumnTypeFamily::Float);
+        assert_eq!(table.column_bang("decimal").tpe.family, ColumnTypeFamily::Decimal);
+        assert_eq!(table.column_bang("bigInt").tpe.family, ColumnTypeFamily::BigInt);
+        assert_eq!(table.column_bang("bytes").tpe.family, ColumnTypeFamily::Binary);
         assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
         assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
         assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
@@ -1300,6 +1306,32 @@ fn adding_a_scalar_list_for_a_modelwith_id_type_int_must_work() {
     });
 }
 
+#[test]
+fn adding_an_embedded_scalar_list_must_create_an_array_column() {
+    test_only_connector(SqlFamily::Postgres, |test_setup, api| {
+        let dm1 = r#"
+            model A {
+                id Int @id
+                strings String[] @scalarList(strategy: EMBEDDED)
+                ints Int[] @scalarList(strategy: EMBEDDED)
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let table = result.table_bang("A");
+
+        let strings_column = table.column_bang("strings");
+        assert_eq!(strings_column.tpe.family, ColumnTypeFamily::String);
+        assert_eq!(strings_column.arity, ColumnArity::List);
+
+        let ints_column = table.column_bang("ints");
+        assert_eq!(ints_column.tpe.family, ColumnTypeFamily::Int);
+        assert_eq!(ints_column.arity, ColumnArity::List);
+
+        assert!(!result.has_table("A_strings"));
+        assert!(!result.has_table("A_ints"));
+    });
+}
+
 #[test_each_connector(ignore = "mysql")]
 fn updating_a_model_with_a_scalar_list_to_a_different_id_type_must_work(api: &TestApi) {
     let dm = r#"
@@ -21,7 +21,10 @@ impl<'a> DatamodelConverter<'a> {
     fn new(datamodel: &dml::Datamodel) -> DatamodelConverter {
         DatamodelConverter {
             datamodel,
-            relations: Self::calculate_relations(datamodel),
+            relations: Self::calculate_relations(datamodel)
+                .into_iter()
+                .filter(|r| r.model_a.id_fields.is_empty() && r.model_b.id_fields.is_empty())
+                .collect(),
         }
     }
 
@@ -52,6 +55,31 @@ impl<'a> DatamodelConverter<'a> {
                 is_embedded: model.is_embedded,
                 fields: self.convert_fields(model),
                 manifestation: model.database_name.clone(),
+                id_field_names: model.id_fields.clone(),
+                indexes: self.convert_indexes(model),
+            })
+            .collect()
+    }
+
+    /// Indexes spanning relation fields are skipped, they can't be used to find records yet.
+    fn convert_indexes(&self, model: &dml::Model) -> Vec<IndexTemplate> {
+        let is_scalar = |name: &String| match model.find_field(name) {
+            Some(field) => field.type_identifier() != TypeIdentifier::Relation,
+            None => false,
+        };
+
+        model
+            .indexes
+            .iter()
+            .filter(|index| index.fields.iter().all(is_scalar))
+            .map(|index| IndexTemplate {
+                name: index.name.clone(),
+                fields: index.fields.clone(),
+                typ: match index.tpe {
+                    dml::IndexType::Unique => IndexType::Unique,
+                    dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
+                },
             })
             .collect()
     }
@@ -59,6 +87,7 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
+            .filter(|field| !Self::relates_compound_id(self.datamodel, model, field))
             .map(|field| match field.type_identifier() {
                 TypeIdentifier::Relation => {
                     let relation = self
@@ -231,6 +260,23 @@ impl<'a> DatamodelConverter<'a> {
         }
         result.into_iter().unique_by(|rel| rel.name()).collect()
     }
+
+    /// Relations link records by a single id column. Relation fields of models with a compound id, or pointing
+    /// to one, are left out of the internal data model together with their relations, the models themselves
+    /// can still be used. The relations are still part of `calculate_relations` for the migration engine.
+    fn relates_compound_id(datamodel: &dml::Datamodel, model: &dml::Model, field: &dml::Field) -> bool {
+        match &field.field_type {
+            dml::FieldType::Relation(info) => {
+                let related_has_compound_id = datamodel
+                    .find_model(&info.to)
+                    .map(|related_model| !related_model.id_fields.is_empty())
+                    .unwrap_or(false);
+
+                !model.id_fields.is_empty() || related_has_compound_id
+            }
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -331,9 +377,12 @@ impl DatamodelFieldExtensions for dml::Field {
             dml::FieldType::Base(scalar) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
+                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                dml::ScalarType::Json => TypeIdentifier::Json,
                 dml::ScalarType::String => match self.default_value {
                     Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
                         TypeIdentifier::GraphQLID
@@ -433,13 +482,19 @@ impl DatamodelFieldExtensions for dml::Field {
     }
 
     fn default_value(&self) -> Option<PrismaValue> {
+        let is_bytes = self.field_type == dml::FieldType::Base(dml::ScalarType::Bytes);
+
         self.default_value.as_ref().and_then(|v| match v {
             datamodel::common::PrismaValue::Boolean(x) => Some(PrismaValue::Boolean(*x)),
             datamodel::common::PrismaValue::Int(x) => Some(PrismaValue::Int(i64::from(*x))),
+            datamodel::common::PrismaValue::BigInt(x) => Some(PrismaValue::BigInt(*x)),
             datamodel::common::PrismaValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
+            // Bytes defaults are given as base64 string.
+            datamodel::common::PrismaValue::String(x) if is_bytes => base64::decode(x).ok().map(PrismaValue::Bytes),
             datamodel::common::PrismaValue::String(x) => Some(PrismaValue::String(x.clone())),
             datamodel::common::PrismaValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
-            datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
+            // Parsed from the text of the value, a float conversion would add digits to defaults like `3.15`.
+            datamodel::common::PrismaValue::Decimal(x) => x.to_string().parse().ok().map(PrismaValue::Decimal),
             datamodel::common::PrismaValue::ConstantLiteral(x) => {
                 Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
             }
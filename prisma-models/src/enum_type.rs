@@ -1,9 +1,8 @@
-use super::{InternalEnum, OrderBy, ScalarField, SortOrder};
+use super::{InternalEnum, NullOrdering, OrderBy, OrderByField, SortOrder};
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct EnumType {
@@ -31,17 +30,21 @@ impl EnumValue {
     pub fn as_string(&self) -> String {
         match &self.value {
             EnumValueWrapper::String(s) => s.clone(),
-            EnumValueWrapper::OrderBy(ob) => format!("{}_{}", ob.field.name, ob.sort_order.abbreviated()),
+            EnumValueWrapper::OrderBy(ob) => ob.name(),
         }
     }
 
-    pub fn order_by<T>(name: T, field: Arc<ScalarField>, sort_order: SortOrder) -> Self
+    pub fn order_by<T>(name: T, field: OrderByField, sort_order: SortOrder, null_ordering: Option<NullOrdering>) -> Self
     where
         T: Into<String>,
     {
         EnumValue {
             name: name.into(),
-            value: EnumValueWrapper::OrderBy(OrderBy { field, sort_order }),
+            value: EnumValueWrapper::OrderBy(OrderBy {
+                field,
+                sort_order,
+                null_ordering,
+            }),
         }
     }
 
@@ -56,6 +59,15 @@ impl EnumValue {
     }
 }
 
+impl From<OrderBy> for EnumValue {
+    fn from(order_by: OrderBy) -> Self {
+        EnumValue {
+            name: order_by.name(),
+            value: EnumValueWrapper::OrderBy(order_by),
+        }
+    }
+}
+
 impl From<String> for EnumValue {
     fn from(s: String) -> Self {
         EnumValue::string(s.clone(), s)
@@ -32,6 +32,7 @@ pub struct FieldManifestation {
 pub enum TypeIdentifier {
     String,
     Float,
+    Decimal,
     Boolean,
     Enum,
     Json,
@@ -39,6 +40,8 @@ pub enum TypeIdentifier {
     GraphQLID,
     UUID,
     Int,
+    BigInt,
+    Bytes,
     Relation,
 }
 
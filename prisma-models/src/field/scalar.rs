@@ -171,6 +171,16 @@ impl ScalarField {
         }
     }
 
+    /// Embedded scalar lists are stored in a column of the model table, as a native array, instead of a separate
+    /// scalar list table.
+    pub fn is_embedded_list(&self) -> bool {
+        self.is_list
+            && self.behaviour
+                == Some(FieldBehaviour::ScalarList {
+                    strategy: ScalarListStrategy::Embedded,
+                })
+    }
+
     pub fn scalar_list_table(&self) -> ScalarListTable {
         ScalarListTable::new(self)
     }
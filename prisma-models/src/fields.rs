@@ -8,7 +8,7 @@ use std::{
 #[derive(Debug)]
 pub struct Fields {
     pub all: Vec<Field>,
-    id: OnceCell<Weak<ScalarField>>,
+    id: OnceCell<Option<Weak<ScalarField>>>,
     scalar: OnceCell<Vec<Weak<ScalarField>>>,
     relation: OnceCell<Vec<Weak<RelationField>>>,
     model: ModelWeakRef,
@@ -29,19 +29,17 @@ impl Fields {
         }
     }
 
-    pub fn id(&self) -> Arc<ScalarField> {
+    /// The `@id` field of the model, `None` for models with a compound id. Use `Model::id_fields` for both.
+    pub fn id(&self) -> Option<Arc<ScalarField>> {
         self.id
             .get_or_init(|| {
-                self.all
-                    .iter()
-                    .fold(None, |acc, field| match field {
-                        Field::Scalar(sf) if sf.is_id() => Some(Arc::downgrade(sf)),
-                        _ => acc,
-                    })
-                    .expect("No id field defined!")
+                self.all.iter().fold(None, |acc, field| match field {
+                    Field::Scalar(sf) if sf.is_id() => Some(Arc::downgrade(sf)),
+                    _ => acc,
+                })
             })
-            .upgrade()
-            .unwrap()
+            .as_ref()
+            .map(|id| id.upgrade().unwrap())
     }
 
     pub fn created_at(&self) -> &Option<Arc<ScalarField>> {
@@ -74,6 +72,14 @@ impl Fields {
         self.scalar().into_iter().filter(|sf| sf.is_list).collect()
     }
 
+    /// Scalar lists stored in their scalar list tables.
+    pub fn scalar_list_non_embedded(&self) -> Vec<Arc<ScalarField>> {
+        self.scalar()
+            .into_iter()
+            .filter(|sf| sf.is_list && !sf.is_embedded_list())
+            .collect()
+    }
+
     fn scalar_weak(&self) -> &[Weak<ScalarField>] {
         self.scalar
             .get_or_init(|| self.all.iter().fold(Vec::new(), Self::scalar_filter))
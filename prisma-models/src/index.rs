@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexTemplate {
+    pub name: Option<String>,
+    pub fields: Vec<String>,
+    pub typ: IndexType,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum IndexType {
+    Unique,
+    Normal,
+    Fulltext,
+}
+
+/// An index spanning one or more fields of a model, defined with `@@unique`, `@@index` or `@@fulltext`.
+#[derive(Debug)]
+pub struct Index {
+    pub name: Option<String>,
+    pub fields: Vec<ScalarFieldWeak>,
+    pub typ: IndexType,
+}
+
+impl IndexTemplate {
+    pub fn build(self, fields: &Fields) -> Index {
+        let fields = self
+            .fields
+            .iter()
+            .map(|name| Arc::downgrade(&fields.find_from_scalar(name).unwrap()))
+            .collect();
+
+        Index {
+            name: self.name,
+            fields,
+            typ: self.typ,
+        }
+    }
+}
+
+impl Index {
+    pub fn fields(&self) -> Vec<ScalarFieldRef> {
+        self.fields.iter().map(|field| field.upgrade().unwrap()).collect()
+    }
+
+    pub fn is_unique(&self) -> bool {
+        self.typ == IndexType::Unique
+    }
+
+    pub fn is_fulltext(&self) -> bool {
+        self.typ == IndexType::Fulltext
+    }
+}
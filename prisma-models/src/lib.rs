@@ -11,6 +11,7 @@ mod enum_type;
 mod error;
 mod field;
 mod fields;
+mod index;
 mod internal_data_model;
 mod model;
 mod order_by;
@@ -29,6 +30,7 @@ pub use enum_type::*;
 pub use error::*;
 pub use field::*;
 pub use fields::*;
+pub use index::*;
 pub use internal_data_model::*;
 pub use model::*;
 pub use order_by::*;
@@ -14,6 +14,12 @@ pub struct ModelTemplate {
     pub is_embedded: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
+
+    #[serde(default)]
+    pub id_field_names: Vec<String>,
+
+    #[serde(default)]
+    pub indexes: Vec<IndexTemplate>,
 }
 
 #[derive(DebugStub)]
@@ -21,8 +27,10 @@ pub struct Model {
     pub name: String,
     pub is_embedded: bool,
     manifestation: Option<String>,
+    id_field_names: Vec<String>,
 
     fields: OnceCell<Fields>,
+    indexes: OnceCell<Vec<Index>>,
 
     #[debug_stub = "#InternalDataModelWeakRef#"]
     pub internal_data_model: InternalDataModelWeakRef,
@@ -34,7 +42,9 @@ impl ModelTemplate {
             name: self.name,
             is_embedded: self.is_embedded,
             fields: OnceCell::new(),
+            indexes: OnceCell::new(),
             manifestation: self.manifestation,
+            id_field_names: self.id_field_names,
             internal_data_model,
         });
 
@@ -46,8 +56,11 @@ impl ModelTemplate {
             Arc::downgrade(&model),
         );
 
+        let indexes = self.indexes.into_iter().map(|i| i.build(&fields)).collect();
+
         // The model is created here and fields WILL BE UNSET before now!
         model.fields.set(fields).unwrap();
+        model.indexes.set(indexes).unwrap();
 
         model
     }
@@ -61,7 +74,7 @@ impl PartialEq for Model {
 
 impl Model {
     pub fn generate_id(&self) -> GraphqlId {
-        match self.fields().id().type_identifier {
+        match self.fields().id().map(|id| id.type_identifier) {
             // This will panic when:
             //
             // - System time goes backwards
@@ -69,10 +82,11 @@ impl Model {
             // - Time cannot be converted to a string.
             //
             // Panic is a better choice than bubbling this up
-            TypeIdentifier::GraphQLID => GraphqlId::String(cuid::cuid().unwrap()),
-            TypeIdentifier::UUID => GraphqlId::UUID(Uuid::new_v4()),
-            TypeIdentifier::Int => panic!("Cannot generate integer ids."),
-            t => panic!("You shouldn't even use ids of type {:?}", t),
+            Some(TypeIdentifier::GraphQLID) => GraphqlId::String(cuid::cuid().unwrap()),
+            Some(TypeIdentifier::UUID) => GraphqlId::UUID(Uuid::new_v4()),
+            Some(TypeIdentifier::Int) => panic!("Cannot generate integer ids."),
+            Some(t) => panic!("You shouldn't even use ids of type {:?}", t),
+            None => panic!("Cannot generate compound ids."),
         }
     }
 
@@ -87,6 +101,58 @@ impl Model {
             .unwrap()
     }
 
+    pub fn indexes(&self) -> &Vec<Index> {
+        self.indexes
+            .get()
+            .ok_or_else(|| String::from("Model indexes must be set!"))
+            .unwrap()
+    }
+
+    /// The fields of a compound id defined with `@@id`. Empty if the id is a single field.
+    pub fn compound_id_fields(&self) -> Vec<ScalarFieldRef> {
+        self.id_field_names
+            .iter()
+            .map(|name| self.fields().find_from_scalar(name).unwrap())
+            .collect()
+    }
+
+    /// The fields identifying a record: the `@id` field, or all fields of a compound id.
+    pub fn id_fields(&self) -> Vec<ScalarFieldRef> {
+        match self.compound_id_fields() {
+            ref fields if fields.is_empty() => self.fields().id().into_iter().collect(),
+            fields => fields,
+        }
+    }
+
+    pub fn id_field_names(&self) -> Vec<String> {
+        self.id_fields().into_iter().map(|field| field.name.clone()).collect()
+    }
+
+    pub fn has_compound_id(&self) -> bool {
+        !self.id_field_names.is_empty()
+    }
+
+    /// All combinations of more than one field that identify a record: the compound id and the compound uniques.
+    pub fn compound_uniques(&self) -> Vec<Vec<ScalarFieldRef>> {
+        let compound_id = Some(self.compound_id_fields()).filter(|fields| !fields.is_empty());
+        let compound_uniques = self
+            .indexes()
+            .iter()
+            .filter(|index| index.is_unique() && index.fields.len() > 1)
+            .map(|index| index.fields());
+
+        compound_id.into_iter().chain(compound_uniques).collect()
+    }
+
+    /// The full-text index a field is searched with. If the field is part of several full-text indexes,
+    /// the one with the fewest fields is used.
+    pub fn fulltext_index(&self, field: &ScalarField) -> Option<&Index> {
+        self.indexes()
+            .iter()
+            .filter(|index| index.is_fulltext() && index.fields().iter().any(|f| f.name == field.name))
+            .min_by_key(|index| index.fields.len())
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.internal_data_model().is_legacy()
     }
@@ -105,7 +171,16 @@ impl Model {
             .expect("InternalDataModel does not exist anymore. Parent internal_data_model is deleted without deleting the child internal_data_model.")
     }
 
+    /// The column of the `@id` field, which relations link records by. Relations of models with a compound id
+    /// are left out of the internal data model, see `DatamodelConverter`.
     pub fn id_column(&self) -> Column<'static> {
-        self.fields().id().as_column()
+        self.fields()
+            .id()
+            .expect("Only models with a single id field have relations.")
+            .as_column()
+    }
+
+    pub fn id_columns(&self) -> Vec<Column<'static>> {
+        self.id_fields().iter().map(|field| field.as_column()).collect()
     }
 }
@@ -1,5 +1,4 @@
-use crate::{ModelRef, ScalarField};
-use std::sync::Arc;
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
@@ -19,10 +18,110 @@ impl SortOrder {
             SortOrder::Descending => "DESC",
         }
     }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// Explicit position of null values in an ordering.
+/// If not given, the database default applies, which differs between databases.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NullOrdering {
+    First,
+    Last,
+}
+
+impl NullOrdering {
+    /// "NULLS_FIRST" / "NULLS_LAST"
+    pub fn abbreviated(self) -> &'static str {
+        match self {
+            NullOrdering::First => "NULLS_FIRST",
+            NullOrdering::Last => "NULLS_LAST",
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            NullOrdering::First => NullOrdering::Last,
+            NullOrdering::Last => NullOrdering::First,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct OrderBy {
-    pub field: Arc<ScalarField>,
+    pub field: OrderByField,
     pub sort_order: SortOrder,
+    pub null_ordering: Option<NullOrdering>,
+}
+
+/// The value a model is ordered by.
+#[derive(Clone, Debug)]
+pub enum OrderByField {
+    /// A scalar field of the model itself.
+    Scalar(ScalarFieldRef),
+
+    /// A scalar field of the record related via a to-one relation field of the model, e.g. `author.name`.
+    RelatedScalar(RelationFieldRef, ScalarFieldRef),
+
+    /// The number of records related via a to-many relation field of the model, e.g. `comments.count`.
+    RelationCount(RelationFieldRef),
+
+    /// The relevance of a record for the full-text search query on a scalar field of the model.
+    Relevance(ScalarFieldRef, String),
+}
+
+impl OrderByField {
+    /// The scalar field of the model itself, if the ordering is on one.
+    pub fn as_scalar(&self) -> Option<&ScalarFieldRef> {
+        match self {
+            OrderByField::Scalar(sf) => Some(sf),
+            _ => None,
+        }
+    }
+
+    /// Name of the ordered value in the query schema, e.g. `name`, `author_name`, `comments_COUNT` or
+    /// `title_RELEVANCE`.
+    pub fn name(&self) -> String {
+        match self {
+            OrderByField::Scalar(sf) => sf.name.clone(),
+            OrderByField::RelatedScalar(rf, sf) => format!("{}_{}", rf.name, sf.name),
+            OrderByField::RelationCount(rf) => format!("{}_COUNT", rf.name),
+            OrderByField::Relevance(sf, _) => format!("{}_RELEVANCE", sf.name),
+        }
+    }
+
+    /// Whether or not the ordered value can be null.
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            OrderByField::Scalar(sf) => !sf.is_required,
+            OrderByField::RelatedScalar(rf, sf) => !rf.is_required || !sf.is_required,
+            OrderByField::RelationCount(_) | OrderByField::Relevance(_, _) => false,
+        }
+    }
+}
+
+impl From<ScalarFieldRef> for OrderByField {
+    fn from(sf: ScalarFieldRef) -> Self {
+        OrderByField::Scalar(sf)
+    }
+}
+
+impl OrderBy {
+    /// Name of the ordering as used in the query schema, e.g. `name_ASC` or `author_name_DESC_NULLS_LAST`.
+    pub fn name(&self) -> String {
+        match self.null_ordering {
+            Some(nulls) => format!(
+                "{}_{}_{}",
+                self.field.name(),
+                self.sort_order.abbreviated(),
+                nulls.abbreviated()
+            ),
+            None => format!("{}_{}", self.field.name(), self.sort_order.abbreviated()),
+        }
+    }
 }
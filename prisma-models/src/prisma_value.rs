@@ -1,4 +1,5 @@
 use crate::{DomainError, DomainResult, EnumValue};
+use bigdecimal::BigDecimal;
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -15,6 +16,27 @@ pub enum GraphqlId {
     String(String),
     Int(usize),
     UUID(Uuid),
+
+    /// The id of a record identified by more than one field, in the order of `Model::id_fields`.
+    Compound(Vec<GraphqlId>),
+}
+
+impl GraphqlId {
+    /// The id of a record from the values of its id fields. More than one value make a compound id.
+    pub fn from_parts(mut ids: Vec<GraphqlId>) -> GraphqlId {
+        match ids.len() {
+            1 => ids.pop().unwrap(),
+            _ => GraphqlId::Compound(ids),
+        }
+    }
+
+    /// The id values of the individual id fields: the id itself, or the parts of a compound id.
+    pub fn into_parts(self) -> Vec<GraphqlId> {
+        match self {
+            GraphqlId::Compound(ids) => ids,
+            id => vec![id],
+        }
+    }
 }
 
 #[cfg(feature = "sql")]
@@ -37,6 +59,10 @@ pub enum PrismaValue {
     #[serde(rename = "float")]
     Float(f64),
 
+    /// An exact decimal number, e.g. an amount of money.
+    #[serde(rename = "decimal")]
+    Decimal(BigDecimal),
+
     #[serde(rename = "bool")]
     Boolean(bool),
 
@@ -52,6 +78,13 @@ pub enum PrismaValue {
     #[serde(rename = "int")]
     Int(i64),
 
+    /// A 64 bit integer of a BigInt field, which JSON clients can't represent as number.
+    #[serde(rename = "bigint")]
+    BigInt(i64),
+
+    #[serde(rename = "bytes")]
+    Bytes(Vec<u8>),
+
     #[serde(rename = "null")]
     Null,
 
@@ -79,17 +112,21 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.as_string().fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
             PrismaValue::Int(x) => x.fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => base64::encode(x).fmt(f),
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::GraphqlId(x) => match x {
                 GraphqlId::String(x) => x.fmt(f),
                 GraphqlId::Int(x) => x.fmt(f),
                 GraphqlId::UUID(x) => x.fmt(f),
+                GraphqlId::Compound(x) => format!("{:?}", x).fmt(f),
             },
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
@@ -123,6 +160,12 @@ impl From<f32> for PrismaValue {
     }
 }
 
+impl From<BigDecimal> for PrismaValue {
+    fn from(d: BigDecimal) -> Self {
+        PrismaValue::Decimal(d)
+    }
+}
+
 impl From<bool> for PrismaValue {
     fn from(b: bool) -> Self {
         PrismaValue::Boolean(b)
@@ -141,6 +184,12 @@ impl From<i64> for PrismaValue {
     }
 }
 
+impl From<Vec<u8>> for PrismaValue {
+    fn from(b: Vec<u8>) -> Self {
+        PrismaValue::Bytes(b)
+    }
+}
+
 impl From<usize> for PrismaValue {
     fn from(u: usize) -> Self {
         PrismaValue::Int(u as i64)
@@ -190,6 +239,7 @@ impl TryFrom<PrismaValue> for GraphqlId {
         match value {
             PrismaValue::GraphqlId(id) => Ok(id),
             PrismaValue::Int(i) => Ok(GraphqlId::from(i)),
+            PrismaValue::BigInt(i) => Ok(GraphqlId::from(i)),
             PrismaValue::String(s) => Ok(GraphqlId::from(s)),
             PrismaValue::Uuid(u) => Ok(GraphqlId::from(u)),
             _ => Err(DomainError::ConversionFailure("PrismaValue", "GraphqlId")),
@@ -204,6 +254,7 @@ impl TryFrom<&PrismaValue> for GraphqlId {
         match value {
             PrismaValue::GraphqlId(id) => Ok(id.clone()),
             PrismaValue::Int(i) => Ok(GraphqlId::from(*i)),
+            PrismaValue::BigInt(i) => Ok(GraphqlId::from(*i)),
             PrismaValue::String(s) => Ok(GraphqlId::from(s.clone())),
             PrismaValue::Uuid(u) => Ok(GraphqlId::from(*u)),
             _ => Err(DomainError::ConversionFailure("PrismaValue", "GraphqlId")),
@@ -217,6 +268,7 @@ impl TryFrom<PrismaValue> for i64 {
     fn try_from(value: PrismaValue) -> DomainResult<i64> {
         match value {
             PrismaValue::Int(i) => Ok(i),
+            PrismaValue::BigInt(i) => Ok(i),
             _ => Err(DomainError::ConversionFailure("PrismaValue", "i64")),
         }
     }
@@ -229,6 +281,7 @@ impl<'a> From<GraphqlId> for DatabaseValue<'a> {
             GraphqlId::String(s) => s.into(),
             GraphqlId::Int(i) => (i as i64).into(),
             GraphqlId::UUID(u) => u.to_string().into(),
+            GraphqlId::Compound(ids) => ids.into(),
         }
     }
 }
@@ -246,11 +299,14 @@ impl<'a> From<PrismaValue> for DatabaseValue<'a> {
         match pv {
             PrismaValue::String(s) => s.into(),
             PrismaValue::Float(f) => (f as f64).into(),
+            PrismaValue::Decimal(d) => decimal_to_float(&d).into(),
             PrismaValue::Boolean(b) => b.into(),
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => e.as_string().into(),
-            PrismaValue::Json(j) => j.to_string().into(),
+            PrismaValue::Json(j) => DatabaseValue::Parameterized(ParameterizedValue::Json(j)),
             PrismaValue::Int(i) => (i as i64).into(),
+            PrismaValue::BigInt(i) => i.into(),
+            PrismaValue::Bytes(b) => base64::encode(&b).into(),
             PrismaValue::Null => DatabaseValue::Parameterized(ParameterizedValue::Null),
             PrismaValue::Uuid(u) => u.to_string().into(),
             PrismaValue::GraphqlId(id) => id.into(),
@@ -260,6 +316,41 @@ impl<'a> From<PrismaValue> for DatabaseValue<'a> {
     }
 }
 
+/// Not a `From` implementation, as quaint converts everything that converts into a `ParameterizedValue` into a
+/// `DatabaseValue`, which would conflict with the conversion of lists and compound ids into rows above.
+#[cfg(feature = "sql")]
+impl PrismaValue {
+    /// The value as a single bind parameter, lists and compound ids are bound as arrays.
+    pub fn into_parameter(self) -> ParameterizedValue<'static> {
+        match self {
+            PrismaValue::String(s) => ParameterizedValue::Text(s.into()),
+            PrismaValue::Float(f) => ParameterizedValue::Real(f),
+            PrismaValue::Decimal(d) => ParameterizedValue::Real(decimal_to_float(&d)),
+            PrismaValue::Boolean(b) => ParameterizedValue::Boolean(b),
+            PrismaValue::DateTime(d) => ParameterizedValue::DateTime(d),
+            PrismaValue::Enum(e) => ParameterizedValue::Text(e.as_string().into()),
+            PrismaValue::Json(j) => ParameterizedValue::Json(j),
+            PrismaValue::Int(i) => ParameterizedValue::Integer(i),
+            PrismaValue::BigInt(i) => ParameterizedValue::Integer(i),
+            PrismaValue::Bytes(b) => ParameterizedValue::Text(base64::encode(&b).into()),
+            PrismaValue::Null => ParameterizedValue::Null,
+            PrismaValue::Uuid(u) => ParameterizedValue::Text(u.to_string().into()),
+            PrismaValue::GraphqlId(GraphqlId::String(s)) => ParameterizedValue::Text(s.into()),
+            PrismaValue::GraphqlId(GraphqlId::Int(i)) => ParameterizedValue::Integer(i as i64),
+            PrismaValue::GraphqlId(GraphqlId::UUID(u)) => ParameterizedValue::Text(u.to_string().into()),
+            PrismaValue::GraphqlId(GraphqlId::Compound(ids)) => ParameterizedValue::Array(
+                ids.into_iter()
+                    .map(|id| PrismaValue::GraphqlId(id).into_parameter())
+                    .collect(),
+            ),
+            PrismaValue::List(Some(l)) => {
+                ParameterizedValue::Array(l.into_iter().map(PrismaValue::into_parameter).collect())
+            }
+            PrismaValue::List(None) => ParameterizedValue::Null,
+        }
+    }
+}
+
 #[cfg(feature = "sql")]
 impl<'a> From<ParameterizedValue<'a>> for PrismaValue {
     fn from(pv: ParameterizedValue<'a>) -> Self {
@@ -281,6 +372,15 @@ impl<'a> From<ParameterizedValue<'a>> for PrismaValue {
     }
 }
 
+/// Quaint has no numeric parameter type, decimals are bound as the closest float and read back from the
+/// float or string the database returns. Values with more than 15 significant digits are therefore rounded.
+#[cfg(feature = "sql")]
+fn decimal_to_float(d: &BigDecimal) -> f64 {
+    d.to_string()
+        .parse()
+        .unwrap_or_else(|_| bigdecimal::ToPrimitive::to_f64(d).unwrap_or(std::f64::NAN))
+}
+
 impl From<&str> for GraphqlId {
     fn from(s: &str) -> Self {
         GraphqlId::from(s.to_string())
@@ -21,8 +21,8 @@ impl SingleRecord {
         Self { record, field_names }
     }
 
-    pub fn collect_id(&self, id_field: &str) -> DomainResult<GraphqlId> {
-        self.record.collect_id(&self.field_names, id_field)
+    pub fn collect_id(&self, id_fields: &[String]) -> DomainResult<GraphqlId> {
+        self.record.collect_id(&self.field_names, id_fields)
     }
 
     pub fn get_field_value(&self, field: &str) -> DomainResult<&PrismaValue> {
@@ -37,10 +37,10 @@ pub struct ManyRecords {
 }
 
 impl ManyRecords {
-    pub fn collect_ids(&self, id_field: &str) -> DomainResult<Vec<GraphqlId>> {
+    pub fn collect_ids(&self, id_fields: &[String]) -> DomainResult<Vec<GraphqlId>> {
         self.records
             .iter()
-            .map(|record| record.collect_id(&self.field_names, id_field).map(|i| i.clone()))
+            .map(|record| record.collect_id(&self.field_names, id_fields))
             .collect()
     }
 
@@ -79,9 +79,18 @@ impl Record {
         }
     }
 
-    pub fn collect_id(&self, field_names: &[String], id_field: &str) -> DomainResult<GraphqlId> {
-        self.get_field_value(field_names, id_field)
-            .and_then(GraphqlId::try_from)
+    /// Collects the id of the record from the values of the given id fields.
+    /// Records with more than one id field have a compound id.
+    pub fn collect_id(&self, field_names: &[String], id_fields: &[String]) -> DomainResult<GraphqlId> {
+        let ids = id_fields
+            .iter()
+            .map(|id_field| {
+                self.get_field_value(field_names, id_field)
+                    .and_then(GraphqlId::try_from)
+            })
+            .collect::<DomainResult<Vec<GraphqlId>>>()?;
+
+        Ok(GraphqlId::from_parts(ids))
     }
 
     pub fn get_field_value(&self, field_names: &[String], field: &str) -> DomainResult<&PrismaValue> {
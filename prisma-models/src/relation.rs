@@ -222,13 +222,13 @@ impl Relation {
                 let model_b = self.model_b();
 
                 if self.is_self_relation() && self.field_a().is_hidden {
-                    model_a.fields().id().as_column()
+                    model_a.id_column()
                 } else if self.is_self_relation() && self.field_b().is_hidden {
-                    model_b.fields().id().as_column()
+                    model_b.id_column()
                 } else if self.is_self_relation() {
                     m.referencing_column(self.relation_table())
                 } else if m.in_table_of_model_name == model_a.name && !self.is_self_relation() {
-                    model_a.fields().id().as_column()
+                    model_a.id_column()
                 } else {
                     m.referencing_column(self.relation_table())
                 }
@@ -249,9 +249,9 @@ impl Relation {
                 if self.is_self_relation() && (self.field_a().is_hidden || self.field_b().is_hidden) {
                     m.referencing_column(self.relation_table())
                 } else if self.is_self_relation() {
-                    model_b.fields().id().as_column()
+                    model_b.id_column()
                 } else if m.in_table_of_model_name == model_b.name && !self.is_self_relation() {
-                    model_b.fields().id().as_column()
+                    model_b.id_column()
                 } else {
                     m.referencing_column(self.relation_table())
                 }
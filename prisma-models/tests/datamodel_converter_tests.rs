@@ -49,6 +49,9 @@ fn models_with_only_scalar_fields() {
                 id Int @id
                 int Int
                 float Float
+                decimal Decimal
+                bigInt BigInt
+                bytes Bytes
                 boolean Boolean
                 dateTime DateTime
                 stringOpt String?
@@ -74,6 +77,18 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("float")
         .assert_type_identifier(TypeIdentifier::Float)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("decimal")
+        .assert_type_identifier(TypeIdentifier::Decimal)
+        .assert_no_behaviour();
+    model
+        .assert_scalar_field("bigInt")
+        .assert_type_identifier(TypeIdentifier::BigInt)
+        .assert_no_behaviour();
+    model
+        .assert_scalar_field("bytes")
+        .assert_type_identifier(TypeIdentifier::Bytes)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("boolean")
         .assert_type_identifier(TypeIdentifier::Boolean)
@@ -153,6 +168,116 @@ fn unique_works() {
         .assert_unique();
 }
 
+#[test]
+fn compound_unique_works() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id String @id @default(cuid())
+                a String
+                b Int
+                c String
+
+                @@unique([a, b])
+                @@index([c])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+    let compound_uniques: Vec<Vec<String>> = model
+        .compound_uniques()
+        .into_iter()
+        .map(|fields| fields.into_iter().map(|f| f.name.clone()).collect())
+        .collect();
+
+    assert_eq!(model.indexes().len(), 2);
+    assert_eq!(compound_uniques, vec![vec!["a".to_string(), "b".to_string()]]);
+}
+
+#[test]
+fn compound_id_works() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                a String
+                b Int
+                c String
+
+                @@id([a, b])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+
+    assert!(model.has_compound_id());
+    assert_eq!(model.id_field_names(), vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(model.compound_uniques().len(), 1);
+}
+
+#[test]
+fn relations_of_compound_ids_are_left_out() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id String @id
+                name String
+                memberships Membership[]
+            }
+
+            model Membership {
+                group String
+                userId String
+                user User
+                role String
+
+                @@id([group, userId])
+            }
+        "#,
+    );
+
+    let user = datamodel.assert_model("User");
+    let membership = datamodel.assert_model("Membership");
+
+    assert!(user.fields().find_from_relation_fields("memberships").is_err());
+    assert!(membership.fields().find_from_relation_fields("user").is_err());
+    assert!(datamodel.find_relation("MembershipToUser").is_err());
+    user.assert_scalar_field("name");
+    membership.assert_scalar_field("role");
+}
+
+#[test]
+fn fulltext_indexes_work() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id String @id @default(cuid())
+                title String
+                body String
+                tags String
+
+                @@fulltext([title, body])
+                @@fulltext([title])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Post");
+    let index_fields = |field: &str| -> Option<Vec<String>> {
+        model
+            .fulltext_index(&model.assert_scalar_field(field))
+            .map(|index| index.fields().into_iter().map(|f| f.name.clone()).collect())
+    };
+
+    assert_eq!(index_fields("title"), Some(vec!["title".to_string()]));
+    assert_eq!(
+        index_fields("body"),
+        Some(vec!["title".to_string(), "body".to_string()])
+    );
+    assert_eq!(index_fields("tags"), None);
+}
+
 #[test]
 fn uuid_fields_must_work() {
     let datamodel = convert(
@@ -0,0 +1,97 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class AggregationFunctionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id        String   @id @default(cuid())
+      |  name      String
+      |  quantity  Int
+      |  price     Float
+      |  createdAt DateTime
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Aggregating an empty set" should "return a count of 0 and null values" in {
+    val result = server.query(
+      """{
+        |  aggregateItem {
+        |    count
+        |    sum { quantity price }
+        |    avg { quantity price }
+        |    min { quantity createdAt }
+        |    max { quantity createdAt }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal(
+      """{"data":{"aggregateItem":{"count":0,"sum":{"quantity":null,"price":null},"avg":{"quantity":null,"price":null},"min":{"quantity":null,"createdAt":null},"max":{"quantity":null,"createdAt":null}}}}""".parseJson)
+  }
+
+  "Aggregating records" should "compute sum, avg, min and max" in {
+    createItem("a", 1, 1.5, "2019-01-01T00:00:00Z")
+    createItem("b", 2, 2.5, "2019-06-01T00:00:00Z")
+    createItem("c", 6, 5.0, "2019-12-01T00:00:00Z")
+
+    val result = server.query(
+      """{
+        |  aggregateItem {
+        |    count
+        |    sum { quantity price }
+        |    avg { quantity price }
+        |    min { quantity createdAt }
+        |    max { quantity createdAt }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal(
+      """{"data":{"aggregateItem":{"count":3,"sum":{"quantity":9,"price":9.0},"avg":{"quantity":3.0,"price":3.0},"min":{"quantity":1,"createdAt":"2019-01-01T00:00:00.000Z"},"max":{"quantity":6,"createdAt":"2019-12-01T00:00:00.000Z"}}}}""".parseJson)
+  }
+
+  "Aggregating records" should "obey filters and pagination" in {
+    createItem("a", 1, 1.5, "2019-01-01T00:00:00Z")
+    createItem("b", 2, 2.5, "2019-06-01T00:00:00Z")
+    createItem("c", 6, 5.0, "2019-12-01T00:00:00Z")
+    createItem("d", 10, 7.0, "2020-01-01T00:00:00Z")
+
+    val result = server.query(
+      """{
+        |  aggregateItem(where: { quantity_gt: 1 }, orderBy: quantity_ASC, first: 2) {
+        |    count
+        |    sum { quantity }
+        |    max { price }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal("""{"data":{"aggregateItem":{"count":2,"sum":{"quantity":8},"max":{"price":5.0}}}}""".parseJson)
+  }
+
+  def createItem(name: String, quantity: Int, price: Double, createdAt: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { name: "$name", quantity: $quantity, price: $price, createdAt: "$createdAt" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,103 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CompoundUniqueSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model User {
+      |  id        String  @id @default(cuid())
+      |  firstName String
+      |  lastName  String
+      |  age       Int?
+      |  posts     Post[]
+      |
+      |  @@unique([firstName, lastName])
+      |}
+      |
+      |model Post {
+      |  id     String  @id @default(cuid())
+      |  title  String
+      |  author User?
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  val ada = """firstName_lastName: { firstName: "Ada", lastName: "Lovelace" }"""
+
+  def createUsers(): Unit = {
+    server.query("""mutation { createUser(data: { firstName: "Ada", lastName: "Lovelace", age: 36 }) { id } }""", project)
+    server.query("""mutation { createUser(data: { firstName: "Ada", lastName: "Byron", age: 42 }) { id } }""", project)
+  }
+
+  "A compound unique" should "find a single record" in {
+    createUsers()
+
+    val result = server.query(s"""{ user(where: { $ada }) { firstName lastName age } }""", project)
+    result.toString should be("""{"data":{"user":{"firstName":"Ada","lastName":"Lovelace","age":36}}}""")
+  }
+
+  "A compound unique" should "return null if no record matches all fields" in {
+    createUsers()
+
+    val result = server.query("""{ user(where: { firstName_lastName: { firstName: "Ada", lastName: "King" } }) { age } }""", project)
+    result.toString should be("""{"data":{"user":null}}""")
+  }
+
+  "A compound unique" should "select the record to update" in {
+    createUsers()
+
+    val result = server.query(s"""mutation { updateUser(where: { $ada }, data: { age: 37 }) { lastName age } }""", project)
+    result.toString should be("""{"data":{"updateUser":{"lastName":"Lovelace","age":37}}}""")
+
+    val byron = server.query("""{ user(where: { firstName_lastName: { firstName: "Ada", lastName: "Byron" } }) { age } }""", project)
+    byron.pathAsLong("data.user.age") should equal(42)
+  }
+
+  "A compound unique" should "select the record to delete" in {
+    createUsers()
+
+    server.query(s"""mutation { deleteUser(where: { $ada }) { lastName } }""", project)
+
+    val result = server.query("""{ users { lastName } }""", project)
+    result.toString should be("""{"data":{"users":[{"lastName":"Byron"}]}}""")
+  }
+
+  "A compound unique" should "select the record to upsert" in {
+    val upsert =
+      s"""mutation {
+        |  upsertUser(
+        |    where: { $ada }
+        |    create: { firstName: "Ada", lastName: "Lovelace", age: 1 }
+        |    update: { age: 2 }
+        |  ) { age }
+        |}
+      """.stripMargin
+
+    server.query(upsert, project).pathAsLong("data.upsertUser.age") should equal(1)
+    server.query(upsert, project).pathAsLong("data.upsertUser.age") should equal(2)
+  }
+
+  "A compound unique" should "select the record to connect" in {
+    createUsers()
+
+    val result = server.query(
+      s"""mutation {
+        |  createPost(data: { title: "Notes", author: { connect: { $ada } } }) {
+        |    author { lastName }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsString("data.createPost.author.lastName") should equal("Lovelace")
+  }
+}
@@ -0,0 +1,112 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DistinctSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model User {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  name   String
+                                                   |  age    Int
+                                                   |  posts  Post[]
+                                                   |}
+                                                   |
+                                                   |model Post {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  title  String
+                                                   |  author User
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    createUser(1, "a", 30, """[{ unique: 1, title: "x" }, { unique: 2, title: "x" }, { unique: 3, title: "y" }]""")
+    createUser(2, "b", 20, "[]")
+    createUser(3, "a", 20, "[]")
+    createUser(4, "b", 40, "[]")
+    createUser(5, "c", 10, "[]")
+    createUser(6, "a", 30, "[]")
+  }
+
+  "Distinct" should "return the first record of each distinct value in order" in {
+    userUniques("""(distinct: [name], orderBy: unique_ASC)""") should be(Vector(1, 2, 5))
+    userUniques("""(distinct: [name], orderBy: unique_DESC)""") should be(Vector(6, 5, 4))
+    // Users 2 and 3 have the same age, their order is decided by the id.
+    userUniques("""(distinct: [name], orderBy: age_ASC)""").sorted should be(Vector(2, 3, 5))
+  }
+
+  "Distinct" should "consider all of the given fields" in {
+    userUniques("""(distinct: [name, age], orderBy: unique_ASC)""") should be(Vector(1, 2, 3, 4, 5))
+  }
+
+  "Distinct" should "apply to the records matching the filter" in {
+    userUniques("""(distinct: [name], where: { age_gt: 20 }, orderBy: unique_ASC)""") should be(Vector(1, 4))
+  }
+
+  "Distinct" should "paginate the distinct records" in {
+    userUniques("""(distinct: [name], orderBy: unique_ASC, first: 2)""") should be(Vector(1, 2))
+    userUniques("""(distinct: [name], orderBy: unique_ASC, skip: 1, first: 1)""") should be(Vector(2))
+    userUniques("""(distinct: [name], orderBy: unique_ASC, last: 2)""") should be(Vector(2, 5))
+  }
+
+  "Distinct" should "paginate past records with duplicate values" in {
+    userUniques("""(distinct: [name], orderBy: age_DESC, first: 1)""") should be(Vector(4))
+    userUniques("""(distinct: [name], orderBy: age_DESC, skip: 2, first: 5)""") should be(Vector(5))
+  }
+
+  "An empty distinct list" should "not make the records distinct" in {
+    userUniques("""(distinct: [], orderBy: unique_ASC)""") should be(Vector(1, 2, 3, 4, 5, 6))
+    userUniques("""(distinct: [], orderBy: unique_ASC, first: 2)""") should be(Vector(1, 2))
+  }
+
+  "Distinct" should "work with cursors" in {
+    val cursor = server
+      .query("""{ user(where: { unique: 1 }) { id } }""", project)
+      .pathAsString("data.user.id")
+
+    userUniques(s"""(distinct: [name], orderBy: unique_ASC, after: "$cursor")""") should be(Vector(2, 3, 5))
+  }
+
+  "Distinct" should "work on related records" in {
+    val result = server.query(
+      """{ user(where: { unique: 1 }) { posts(distinct: [title], orderBy: unique_ASC) { unique } } }""",
+      project
+    )
+
+    result.pathAsSeq("data.user.posts").map(_.pathAsLong("unique")).toVector should be(Vector(1, 3))
+
+    val paginated = server.query(
+      """{ user(where: { unique: 1 }) { posts(distinct: [title], orderBy: unique_DESC, first: 1) { unique } } }""",
+      project
+    )
+
+    paginated.pathAsSeq("data.user.posts").map(_.pathAsLong("unique")).toVector should be(Vector(3))
+  }
+
+  def userUniques(args: String) =
+    server
+      .query(s"{ users $args{ unique } }", project)
+      .pathAsSeq("data.users")
+      .map(_.pathAsLong("unique"))
+      .toVector
+
+  def createUser(unique: Int, name: String, age: Int, posts: String) = {
+    server.query(
+      s"""mutation {
+         |  createUser(data: { unique: $unique, name: "$name", age: $age, posts: { create: $posts } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
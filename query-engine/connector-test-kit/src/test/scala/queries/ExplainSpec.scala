@@ -0,0 +1,78 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json._
+import util._
+
+class ExplainSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String  @id @default(cuid())
+      |  title  String  @unique
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  val readTodoes = Json.obj("query" -> """{ todoes { title } }""")
+
+  "Explaining a mutation" should "report its statements without persisting its writes" in {
+    server.withEngine(project) { post =>
+      val result = post("/explain", Json.obj("query" -> """mutation { createTodo(data: { title: "a" }) { title } }"""))
+      val operation = result.pathAsSeq("operations").head
+
+      operation.pathAsString("key") should be("createTodo")
+      operation.pathAsSeq("queries").map(_.pathAsString("query")).exists(_.toUpperCase.startsWith("INSERT")) should be(true)
+
+      post("/", readTodoes).toString should be("""{"data":{"todoes":[]}}""")
+    }
+  }
+
+  "A failing operation" should "not affect the explanations of the following operations" in {
+    server.withEngine(project) { post =>
+      post("/", Json.obj("query" -> """mutation { createTodo(data: { title: "a" }) { title } }"""))
+
+      val result = post(
+        "/explain",
+        Json.obj(
+          "query" ->
+            """mutation {
+              |  duplicate: createTodo(data: { title: "a" }) { title }
+              |  other: createTodo(data: { title: "b" }) { title }
+              |}""".stripMargin,
+          "explainDatabase" -> true
+        )
+      )
+
+      val operations = result.pathAsSeq("operations")
+
+      operations.head.pathAsString("error") should include("Unique constraint")
+      (operations(1) \ "error").toOption.flatMap(_.asOpt[String]) should be(None)
+
+      post("/", readTodoes).toString should be("""{"data":{"todoes":[{"title":"a"}]}}""")
+    }
+  }
+
+  "Explaining a query with the database" should "return a plan for its statements" in {
+    server.withEngine(project) { post =>
+      val result = post(
+        "/explain",
+        Json.obj("query" -> """{ todoes(where: { title: "a" }) { title } }""", "explainDatabase" -> true)
+      )
+
+      val queries = result.pathAsSeq("operations").head.pathAsSeq("queries")
+
+      queries should not be empty
+      queries.foreach { query =>
+        (query \ "planError").toOption should be(None)
+        (query \ "plan").toOption should not be None
+      }
+    }
+  }
+}
@@ -0,0 +1,200 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class FragmentsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String    @id @default(cuid())
+      |  title  String    @unique
+      |  done   Boolean
+      |  tags   Tag[]
+      |}
+      |
+      |model Tag {
+      |  id    String @id @default(cuid())
+      |  name  String
+      |  todo  Todo
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Named fragments" should "be expanded and merged with the other selections" in {
+    createTodo()
+
+    val result = server.query(
+      """{
+        |  todoes {
+        |    title
+        |    ...todoFields
+        |    tags(orderBy: name_ASC) { name }
+        |  }
+        |}
+        |
+        |fragment todoFields on Todo {
+        |  title
+        |  done
+        |  tags(orderBy: name_ASC) { id }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsSeq("data.todoes").head.toString should be(
+      s"""{"title":"a","done":true,"tags":[{"id":"${tagId("x")}","name":"x"},{"id":"${tagId("y")}","name":"y"}]}""")
+  }
+
+  "Inline fragments" should "be expanded, with and without a type condition" in {
+    createTodo()
+
+    val result = server.query(
+      """{
+        |  todoes {
+        |    ... on Todo { title }
+        |    ... { done }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"todoes":[{"title":"a","done":true}]}}""")
+  }
+
+  "Merged fields" should "accept the same arguments in any order" in {
+    createTodo()
+
+    val result = server.query(
+      """{
+        |  todoes {
+        |    tags(first: 1, orderBy: name_DESC) { name }
+        |    ...todoTags
+        |  }
+        |}
+        |
+        |fragment todoTags on Todo {
+        |  tags(orderBy: name_DESC, first: 1) { id }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be(s"""{"data":{"todoes":[{"tags":[{"name":"y","id":"${tagId("y")}"}]}]}}""")
+  }
+
+  "Merged fields with different arguments" should "fail" in {
+    server.queryThatMustFail(
+      """{
+        |  todoes {
+        |    tags(first: 1) { name }
+        |    ...todoTags
+        |  }
+        |}
+        |
+        |fragment todoTags on Todo {
+        |  tags(first: 2) { id }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Conflicting selections for 'tags'"
+    )
+  }
+
+  "Root fields" should "not be merged, every root field is an operation of its own" in {
+    server.query(
+      """mutation {
+        |  first: createTodo(data: { title: "a", done: true }) { title }
+        |  ...moreTodos
+        |}
+        |
+        |fragment moreTodos on Mutation {
+        |  second: createTodo(data: { title: "b", done: false }) { title }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    val result = server.query("""{ todoes(orderBy: title_ASC) { title } }""", project)
+    result.toString should be("""{"data":{"todoes":[{"title":"a"},{"title":"b"}]}}""")
+  }
+
+  "A fragment with a type condition on another type" should "fail" in {
+    server.queryThatMustFail(
+      """{
+        |  todoes {
+        |    ...tagFields
+        |  }
+        |}
+        |
+        |fragment tagFields on Tag {
+        |  name
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Type condition 'Tag' does not match."
+    )
+  }
+
+  "Fragment cycles" should "fail" in {
+    server.queryThatMustFail(
+      """{
+        |  todoes {
+        |    ...first
+        |  }
+        |}
+        |
+        |fragment first on Todo {
+        |  title
+        |  ...second
+        |}
+        |
+        |fragment second on Todo {
+        |  done
+        |  ...first
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Fragment cycle detected: first -> second -> first."
+    )
+  }
+
+  "Unknown fragments" should "fail" in {
+    server.queryThatMustFail(
+      """{
+        |  todoes {
+        |    ...missing
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Unknown fragment 'missing'"
+    )
+  }
+
+  def createTodo() = {
+    server.query(
+      """mutation {
+        |  createTodo(data: { title: "a", done: true, tags: { create: [{ name: "x" }, { name: "y" }] } }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+  }
+
+  def tagId(name: String) =
+    server.query(s"""{ tags(where: { name: "$name" }) { id } }""", project).pathAsString("data.tags.[0].id")
+}
@@ -0,0 +1,167 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupBySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id       String  @id @default(cuid())
+      |  category String
+      |  color    String?
+      |  quantity Int
+      |  price    Float
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Grouping an empty set" should "return no groups" in {
+    val result = server.query(
+      """{
+        |  groupByItem(by: [category]) {
+        |    category
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal("""{"data":{"groupByItem":[]}}""".parseJson)
+  }
+
+  "Grouping records" should "compute aggregations per group" in {
+    createItem("a", "red", 1, 1.5)
+    createItem("a", "blue", 2, 2.5)
+    createItem("b", "red", 6, 5.0)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [category]) {
+        |    category
+        |    count
+        |    sum { quantity price }
+        |    avg { quantity }
+        |    min { price }
+        |    max { price }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal(
+      """{"data":{"groupByItem":[{"category":"a","count":2,"sum":{"quantity":3,"price":4.0},"avg":{"quantity":1.5},"min":{"price":1.5},"max":{"price":2.5}},{"category":"b","count":1,"sum":{"quantity":6,"price":5.0},"avg":{"quantity":6.0},"min":{"price":5.0},"max":{"price":5.0}}]}}""".parseJson)
+  }
+
+  "Grouping records by multiple fields" should "obey filters and ordering" in {
+    createItem("a", "red", 1, 1.5)
+    createItem("a", "red", 2, 2.5)
+    createItem("a", "blue", 3, 2.5)
+    createItem("b", "red", 6, 5.0)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [category, color], where: { quantity_lt: 6 }, orderBy: [category_ASC, color_DESC]) {
+        |    category
+        |    color
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal(
+      """{"data":{"groupByItem":[{"category":"a","color":"red","count":2},{"category":"a","color":"blue","count":1}]}}""".parseJson)
+  }
+
+  "Grouping records" should "discard groups not matching the having filter" in {
+    createItem("a", "red", 1, 1.5)
+    createItem("a", "blue", 2, 2.5)
+    createItem("b", "red", 6, 5.0)
+    createItem("c", "red", 10, 1.0)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [category], having: { OR: [{ count_gt: 1 }, { sum_quantity_gte: 10 }], max_price_lt: 5.0 }) {
+        |    category
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal("""{"data":{"groupByItem":[{"category":"a","count":2},{"category":"c","count":1}]}}""".parseJson)
+  }
+
+  "Grouping records by an optional field" should "put the records without a value into one group" in {
+    createItem("a", "red", 1, 1.5)
+    server.query("""mutation { createItem(data: { category: "a", quantity: 2, price: 2.5 }) { id } }""", project)
+    server.query("""mutation { createItem(data: { category: "b", quantity: 3, price: 3.5 }) { id } }""", project)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [color], having: { sum_price_gt: 1.5 }) {
+        |    color
+        |    count
+        |    sum { quantity }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal("""{"data":{"groupByItem":[{"color":null,"count":2,"sum":{"quantity":5}}]}}""".parseJson)
+  }
+
+  "Selecting a field that is not grouped" should "fail" in {
+    server.queryThatMustFail(
+      """{
+        |  groupByItem(by: [category]) {
+        |    color
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Only grouped fields can be selected, 'color' is not contained in 'by'."
+    )
+  }
+
+  "Groups" should "be paginated with skip and first" in {
+    createItem("a", "red", 1, 1.5)
+    createItem("b", "red", 2, 2.5)
+    createItem("c", "red", 3, 3.5)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [category], orderBy: category_DESC, skip: 1, first: 1) {
+        |    category
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result should equal("""{"data":{"groupByItem":[{"category":"b"}]}}""".parseJson)
+  }
+
+  def createItem(category: String, color: String, quantity: Int, price: Double) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { category: "$category", color: "$color", quantity: $quantity, price: $price }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
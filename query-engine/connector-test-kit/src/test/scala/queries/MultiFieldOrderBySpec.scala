@@ -0,0 +1,85 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class MultiFieldOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Member {
+                                                   |  id       String  @id @default(cuid())
+                                                   |  unique   Int     @unique
+                                                   |  group    Int
+                                                   |  nickname String?
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createMember(1, 1, Some("b"))
+    createMember(2, 2, None)
+    createMember(3, 1, None)
+    createMember(4, 2, Some("a"))
+    createMember(5, 1, Some("a"))
+  }
+
+  "Multiple orderings" should "be applied in the given order" in {
+    memberUniques("(orderBy: [group_ASC, unique_DESC])") should be(Vector(5, 3, 1, 4, 2))
+    memberUniques("(orderBy: [group_DESC, unique_ASC])") should be(Vector(2, 4, 1, 3, 5))
+  }
+
+  "Multiple orderings" should "be reversed for last" in {
+    memberUniques("(orderBy: [group_ASC, unique_DESC], last: 2)") should be(Vector(4, 2))
+  }
+
+  "Nulls first and last" should "position nulls independently of the sort order" in {
+    memberUniques("(orderBy: [nickname_ASC_NULLS_FIRST, unique_ASC])") should be(Vector(2, 3, 4, 5, 1))
+    memberUniques("(orderBy: [nickname_ASC_NULLS_LAST, unique_ASC])") should be(Vector(4, 5, 1, 2, 3))
+    memberUniques("(orderBy: [nickname_DESC_NULLS_FIRST, unique_ASC])") should be(Vector(2, 3, 1, 4, 5))
+    memberUniques("(orderBy: [nickname_DESC_NULLS_LAST, unique_ASC])") should be(Vector(1, 4, 5, 2, 3))
+  }
+
+  "Nulls first and last" should "be kept for last" in {
+    memberUniques("(orderBy: [nickname_ASC_NULLS_FIRST, unique_ASC], last: 2)") should be(Vector(5, 1))
+    memberUniques("(orderBy: [nickname_ASC_NULLS_LAST, unique_ASC], last: 3)") should be(Vector(1, 2, 3))
+  }
+
+  "A cursor" should "paginate over multiple orderings" in {
+    memberUniques(s"""(orderBy: [group_ASC, unique_DESC], after: "${memberId(1)}")""") should be(Vector(4, 2))
+    memberUniques(s"""(orderBy: [group_ASC, unique_DESC], before: "${memberId(4)}")""") should be(Vector(5, 3, 1))
+  }
+
+  "A cursor" should "paginate over null orderings, from and to null values" in {
+    val ordering = "orderBy: [nickname_ASC_NULLS_LAST, unique_ASC]"
+
+    memberUniques(s"""($ordering, after: "${memberId(5)}")""") should be(Vector(1, 2, 3))
+    memberUniques(s"""($ordering, after: "${memberId(2)}")""") should be(Vector(3))
+    memberUniques(s"""($ordering, before: "${memberId(2)}")""") should be(Vector(4, 5, 1))
+    memberUniques(s"""($ordering, before: "${memberId(1)}", last: 1)""") should be(Vector(5))
+
+    val nullsFirst = "orderBy: [nickname_DESC_NULLS_FIRST, unique_ASC]"
+
+    memberUniques(s"""($nullsFirst, after: "${memberId(3)}")""") should be(Vector(1, 4, 5))
+    memberUniques(s"""($nullsFirst, before: "${memberId(1)}")""") should be(Vector(2, 3))
+  }
+
+  def memberUniques(args: String) =
+    server.query(s"{ members $args{ unique } }", project).pathAsSeq("data.members").map(_.pathAsLong("unique")).toVector
+
+  def memberId(unique: Int) =
+    server.query(s"{ member(where: { unique: $unique }){ id } }", project).pathAsString("data.member.id")
+
+  def createMember(unique: Int, group: Int, nickname: Option[String]) = {
+    val nicknameArg = nickname.map(n => s""", nickname: "$n"""").getOrElse("")
+
+    server.query(
+      s"""mutation {
+         |  createMember(data: { unique: $unique, group: $group$nicknameArg }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
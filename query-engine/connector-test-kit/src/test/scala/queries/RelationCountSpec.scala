@@ -0,0 +1,80 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class RelationCountSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model User {
+                                                   |  id     String @id @default(cuid())
+                                                   |  name   String @unique
+                                                   |  posts  Post[]
+                                                   |  groups Group[]
+                                                   |}
+                                                   |
+                                                   |model Post {
+                                                   |  id        String  @id @default(cuid())
+                                                   |  title     String
+                                                   |  published Boolean
+                                                   |  author    User
+                                                   |}
+                                                   |
+                                                   |model Group {
+                                                   |  id      String @id @default(cuid())
+                                                   |  name    String @unique
+                                                   |  members User[]
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    server.query(
+      """mutation {
+        |  createUser(data: {
+        |    name: "alice",
+        |    posts: { create: [{ title: "a", published: true }, { title: "b", published: false }, { title: "c", published: true }] },
+        |    groups: { create: [{ name: "admins" }, { name: "editors" }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    server.query(
+      """mutation {
+        |  createUser(data: { name: "bob", posts: { create: [{ title: "d", published: false }] } }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    server.query("""mutation { createUser(data: { name: "carol" }) { id } }""", project)
+  }
+
+  "Selecting _count" should "return the number of related records of each record" in {
+    val result = server.query("""{ users(orderBy: name_ASC) { name _count { posts groups } } }""", project)
+
+    result.toString should be(
+      """{"data":{"users":[{"name":"alice","_count":{"posts":3,"groups":2}},{"name":"bob","_count":{"posts":1,"groups":0}},{"name":"carol","_count":{"posts":0,"groups":0}}]}}""")
+  }
+
+  "Selecting _count" should "only count the related records matching the filter" in {
+    val result = server.query("""{ users(orderBy: name_ASC) { name _count { posts(where: { published: true }) } } }""", project)
+
+    result.toString should be(
+      """{"data":{"users":[{"name":"alice","_count":{"posts":2}},{"name":"bob","_count":{"posts":0}},{"name":"carol","_count":{"posts":0}}]}}""")
+  }
+
+  "Selecting _count" should "work on a single record and on nested records" in {
+    val single = server.query("""{ user(where: { name: "alice" }) { _count { groups } } }""", project)
+    single.pathAsJsValue("data.user").toString() should be("""{"_count":{"groups":2}}""")
+
+    val nested = server.query("""{ groups(orderBy: name_ASC) { name members { name _count { posts } } } }""", project)
+    nested.toString should be(
+      """{"data":{"groups":[{"name":"admins","members":[{"name":"alice","_count":{"posts":3}}]},{"name":"editors","members":[{"name":"alice","_count":{"posts":3}}]}]}}""")
+  }
+}
@@ -0,0 +1,104 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json._
+import util._
+
+class VariablesSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Item {
+      |  id     String  @id @default(cuid())
+      |  name   String  @unique
+      |  count  Int
+      |  color  Color
+      |}
+      |
+      |enum Color {
+      |  RED
+      |  BLUE
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  val createItem =
+    """mutation($name: String!, $count: Int = 1, $color: Color = RED) {
+      |  createItem(data: { name: $name, count: $count, color: $color }) {
+      |    name
+      |    count
+      |    color
+      |  }
+      |}
+    """.stripMargin
+
+  def request(query: String, variables: JsObject) = Json.obj("query" -> query, "variables" -> variables)
+
+  "Variables" should "be used for the arguments referencing them" in {
+    server.withEngine(project) { post =>
+      val result = post("/", request(createItem, Json.obj("name" -> "a", "count" -> 5, "color" -> "BLUE")))
+      result.toString should be("""{"data":{"createItem":{"name":"a","count":5,"color":"BLUE"}}}""")
+
+      val filtered = post(
+        "/",
+        request("""query($where: ItemWhereInput) { items(where: $where) { name } }""",
+                Json.obj("where" -> Json.obj("count_gt" -> 1, "color" -> "BLUE")))
+      )
+      filtered.toString should be("""{"data":{"items":[{"name":"a"}]}}""")
+    }
+  }
+
+  "Variables" should "fall back to their default values" in {
+    server.withEngine(project) { post =>
+      val result = post("/", request(createItem, Json.obj("name" -> "a")))
+      result.toString should be("""{"data":{"createItem":{"name":"a","count":1,"color":"RED"}}}""")
+    }
+  }
+
+  "A missing required variable" should "fail" in {
+    server.withEngine(project) { post =>
+      val result = post("/", request(createItem, Json.obj()))
+      result.toString should include("Variable '$name' of required type 'String!' was not provided.")
+    }
+  }
+
+  "A variable with a value of the wrong type" should "fail" in {
+    server.withEngine(project) { post =>
+      val wrongInt = post("/", request(createItem, Json.obj("name" -> "a", "count" -> "five")))
+      wrongInt.toString should include("Variable '$count' of type 'Int' got an invalid value")
+
+      val wrongEnum = post("/", request(createItem, Json.obj("name" -> "a", "color" -> "GREEN")))
+      wrongEnum.toString should include("Value 'GREEN' is not a member of enum 'Color'.")
+
+      val nullName = post("/", request(createItem, Json.obj("name" -> JsNull)))
+      nullName.toString should include("Expected a non-null value, got null.")
+
+      post("/", request("{ items { name } }", Json.obj())).toString should be("""{"data":{"items":[]}}""")
+    }
+  }
+
+  "A default value of the wrong type" should "fail" in {
+    server.withEngine(project) { post =>
+      val query =
+        """mutation($name: String!, $count: Int = "one") {
+          |  createItem(data: { name: $name, count: $count, color: RED }) { name }
+          |}
+        """.stripMargin
+
+      val result = post("/", request(query, Json.obj("name" -> "a")))
+      result.toString should include("Variable '$count' of type 'Int' has an invalid default value")
+    }
+  }
+
+  "A variable that is not defined by the operation" should "fail" in {
+    server.withEngine(project) { post =>
+      val result = post("/", request("""{ item(where: { name: $name }) { name } }""", Json.obj("name" -> "a")))
+      result.toString should include("Variable '$name' is not defined by the operation.")
+    }
+  }
+}
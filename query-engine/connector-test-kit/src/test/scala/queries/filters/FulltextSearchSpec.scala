@@ -0,0 +1,65 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.FullTextSearchCapability
+import util._
+
+class FulltextSearchSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(FullTextSearchCapability)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Post {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  title  String
+                                                   |  body   String
+                                                   |
+                                                   |  @@fulltext([title, body])
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createPost(1, "Cats", "Cats are independent animals")
+    createPost(2, "Dogs", "Dogs are loyal animals, dogs love walks")
+    createPost(3, "Plants", "Plants need water")
+  }
+
+  "The search filter" should "find records matching the query in any field of the index" in {
+    postUniques("""(where: { title_search: "animals" })""") should be(Vector(1, 2))
+    postUniques("""(where: { title_search: "water" })""") should be(Vector(3))
+    postUniques("""(where: { title_search: "birds" })""") should be(Vector.empty)
+  }
+
+  "The search filter" should "work together with other filters" in {
+    postUniques("""(where: { title_search: "animals", unique_gt: 1 })""") should be(Vector(2))
+  }
+
+  "Ordering by relevance" should "return the most relevant records first" in {
+    postUniques("""(where: { title_search: "dogs" }, orderBy: title_RELEVANCE_DESC)""") should be(Vector(2))
+    postUniques("""(where: { title_search: "animals" }, orderBy: [title_RELEVANCE_DESC, unique_ASC])""").length should be(2)
+  }
+
+  "Ordering by relevance" should "fail without a search filter on the field" in {
+    server.queryThatMustFail(
+      """{ posts(orderBy: title_RELEVANCE_DESC){ unique } }""",
+      project,
+      errorCode = 0,
+      errorContains = "Ordering by the relevance of 'title' requires a 'title_search' filter."
+    )
+  }
+
+  def postUniques(args: String) =
+    server.query(s"{ posts $args{ unique } }", project).pathAsSeq("data.posts").map(_.pathAsLong("unique")).toVector
+
+  def createPost(unique: Int, title: String, body: String) = {
+    server.query(
+      s"""mutation {
+         |  createPost(data: { unique: $unique, title: "$title", body: "$body" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
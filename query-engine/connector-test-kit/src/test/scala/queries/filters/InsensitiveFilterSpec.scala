@@ -0,0 +1,59 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.InsensitiveFiltersCapability
+import util._
+
+class InsensitiveFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(InsensitiveFiltersCapability)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model User {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  name   String
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createUser(1, "Anna Berg")
+    createUser(2, "ANNA BERG")
+    createUser(3, "anna berg")
+    createUser(4, "Bert Anders")
+    createUser(5, "Émile Zola")
+  }
+
+  "Equality filters" should "ignore the case in insensitive mode" in {
+    userUniques("""(where: { name: "anna berg", name_mode: insensitive })""") should be(Vector(1, 2, 3))
+    userUniques("""(where: { name_not: "anna berg", name_mode: insensitive })""") should be(Vector(4, 5))
+    userUniques("""(where: { name_in: ["ANNA berg"], name_mode: insensitive })""") should be(Vector(1, 2, 3))
+  }
+
+  "Pattern filters" should "ignore the case in insensitive mode" in {
+    userUniques("""(where: { name_contains: "ANDER", name_mode: insensitive })""") should be(Vector(4))
+    userUniques("""(where: { name_starts_with: "anna", name_mode: insensitive })""") should be(Vector(1, 2, 3))
+    userUniques("""(where: { name_ends_with: "Berg", name_mode: insensitive })""") should be(Vector(1, 2, 3))
+    userUniques("""(where: { name_not_contains: "ANNA", name_mode: insensitive })""") should be(Vector(4, 5))
+  }
+
+  "Insensitive filters" should "match values with non-ASCII characters in their own spelling" in {
+    userUniques("""(where: { name: "Émile zola", name_mode: insensitive })""") should be(Vector(5))
+    userUniques("""(where: { name_starts_with: "ÉMILE", name_mode: insensitive })""") should be(Vector(5))
+    userUniques("""(where: { name_in: ["Émile ZOLA"], name_mode: insensitive })""") should be(Vector(5))
+  }
+
+  def userUniques(filter: String) =
+    server.query(s"{ users $filter{ unique } }", project).pathAsSeq("data.users").map(_.pathAsLong("unique")).toVector
+
+  def createUser(unique: Int, name: String) = {
+    server.query(
+      s"""mutation {
+         |  createUser(data: { unique: $unique, name: "$name" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,54 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JsonFiltersCapability
+import util._
+
+class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JsonFiltersCapability)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Item {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  meta   Json?
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createItem(1, """{\"status\": \"active\", \"tags\": [\"a\", \"b\"], \"size\": {\"width\": 10}}""")
+    createItem(2, """{\"status\": \"inactive\", \"tags\": [\"b\"], \"size\": {\"width\": 20}}""")
+    createItem(3, """{\"tags\": []}""")
+  }
+
+  "A Json path filter" should "compare the value at the path" in {
+    itemUniques("""(where: { meta: "\"active\"", meta_path: ["status"] })""") should be(Vector(1))
+    itemUniques("""(where: { meta: "20", meta_path: ["size", "width"] })""") should be(Vector(2))
+    itemUniques("""(where: { meta: "\"b\"", meta_path: ["tags", "0"] })""") should be(Vector(2))
+  }
+
+  "A Json path filter" should "exclude records with a different value at the path" in {
+    itemUniques("""(where: { meta_not: "\"active\"", meta_path: ["status"] })""") should be(Vector(2))
+  }
+
+  "The array_contains filter" should "find arrays at the path containing the value" in {
+    itemUniques("""(where: { meta_array_contains: "\"b\"", meta_path: ["tags"] })""") should be(Vector(1, 2))
+    itemUniques("""(where: { meta_array_contains: "\"a\"", meta_path: ["tags"] })""") should be(Vector(1))
+    itemUniques("""(where: { meta_array_contains: "\"c\"", meta_path: ["tags"] })""") should be(Vector.empty)
+  }
+
+  def itemUniques(args: String) =
+    server.query(s"{ items $args{ unique } }", project).pathAsSeq("data.items").map(_.pathAsLong("unique")).toVector
+
+  def createItem(unique: Int, meta: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { unique: $unique, meta: "$meta" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
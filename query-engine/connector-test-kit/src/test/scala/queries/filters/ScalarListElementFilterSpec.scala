@@ -0,0 +1,64 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListFiltersCapability
+import util.ConnectorTag.PostgresConnectorTag
+import util._
+
+class ScalarListElementFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForConnectors   = Set(PostgresConnectorTag)
+  override def runOnlyForCapabilities = Set(ScalarListFiltersCapability)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Item {
+                                                   |  id     String   @id @default(cuid())
+                                                   |  unique Int      @unique
+                                                   |  tags   String[] @scalarList(strategy: EMBEDDED)
+                                                   |  scores Int[]    @scalarList(strategy: EMBEDDED)
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createItem(1, """["a", "b"]""", "[1, 2]")
+    createItem(2, """["b"]""", "[3]")
+    createItem(3, "[]", "[]")
+  }
+
+  "The has filter" should "find lists containing the value" in {
+    itemUniques("""(where: { tags_has: "b" })""") should be(Vector(1, 2))
+    itemUniques("""(where: { scores_has: 1 })""") should be(Vector(1))
+    itemUniques("""(where: { tags_has: "c" })""") should be(Vector.empty)
+  }
+
+  "The has_every filter" should "find lists containing all of the values" in {
+    itemUniques("""(where: { tags_has_every: ["a", "b"] })""") should be(Vector(1))
+    itemUniques("""(where: { tags_has_every: ["b"] })""") should be(Vector(1, 2))
+    itemUniques("""(where: { tags_has_every: ["a", "c"] })""") should be(Vector.empty)
+  }
+
+  "The has_some filter" should "find lists containing at least one of the values" in {
+    itemUniques("""(where: { tags_has_some: ["a", "c"] })""") should be(Vector(1))
+    itemUniques("""(where: { scores_has_some: [2, 3] })""") should be(Vector(1, 2))
+    itemUniques("""(where: { tags_has_some: ["c"] })""") should be(Vector.empty)
+  }
+
+  def itemUniques(args: String) =
+    server
+      .query(s"{ items $args{ unique } }", project)
+      .pathAsSeq("data.items")
+      .map(_.pathAsLong("unique"))
+      .toVector
+      .sorted
+
+  def createItem(unique: Int, tags: String, scores: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { unique: $unique, tags: { set: $tags }, scores: { set: $scores } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,73 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.PostgresConnectorTag
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForConnectors = Set(PostgresConnectorTag)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Item {
+                                                   |  id     String   @id @default(cuid())
+                                                   |  unique Int      @unique
+                                                   |  tags   String[] @scalarList(strategy: EMBEDDED)
+                                                   |  scores Int[]    @scalarList(strategy: EMBEDDED)
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+    createItem(1, """["a", "b"]""", "[1, 2]")
+    createItem(2, """["b"]""", "[3]")
+    createItem(3, "[]", "[]")
+  }
+
+  "Embedded scalar lists" should "be read with the record" in {
+    val result = server.query("""{ item(where: { unique: 1 }) { tags scores } }""", project)
+
+    result.pathAsJsValue("data.item").toString() should be("""{"tags":["a","b"],"scores":[1,2]}""")
+  }
+
+  "Embedded scalar lists" should "be replaced by an update" in {
+    server.query(
+      """mutation {
+        |  updateItem(where: { unique: 2 }, data: { tags: { set: ["c", "d"] } }) {
+        |    unique
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    val result = server.query("""{ item(where: { unique: 2 }) { tags } }""", project)
+    result.pathAsJsValue("data.item.tags").toString() should be("""["c","d"]""")
+  }
+
+  "The is_empty filter" should "find empty or non-empty lists" in {
+    itemUniques("""(where: { tags_is_empty: true })""") should be(Vector(3))
+    itemUniques("""(where: { tags_is_empty: false })""") should be(Vector(1, 2))
+  }
+
+  def itemUniques(args: String) =
+    server
+      .query(s"{ items $args{ unique } }", project)
+      .pathAsSeq("data.items")
+      .map(_.pathAsLong("unique"))
+      .toVector
+      .sorted
+
+  def createItem(unique: Int, tags: String, scores: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { unique: $unique, tags: { set: $tags }, scores: { set: $scores } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,120 @@
+package queries.nonEmbedded
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class RelationOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id       String    @id @default(cuid())
+      |  name     String    @unique
+      |  posts    Post[]
+      |}
+      |
+      |model Post {
+      |  id       String    @id @default(cuid())
+      |  title    String    @unique
+      |  author   User?
+      |  comments Comment[]
+      |}
+      |
+      |model Comment {
+      |  id       String    @id @default(cuid())
+      |  text     String    @unique
+      |  post     Post
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createData()
+  }
+
+  "Ordering by a field of a to-one relation" should "work" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: [author_name_ASC_NULLS_LAST, title_DESC]) {
+        |    title
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"title":"3"},{"title":"2"},{"title":"1"},{"title":"4"}]}}""")
+  }
+
+  "Ordering by a field of a to-one relation" should "work with cursors" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: [author_name_DESC_NULLS_FIRST, title_ASC], after: "post2") {
+        |    title
+        |  }
+        |}
+      """.replace("post2", postId("2")),
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"title":"3"}]}}""")
+  }
+
+  "Ordering by the count of a to-many relation" should "work" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: [comments_COUNT_DESC, title_ASC]) {
+        |    title
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"title":"3"},{"title":"1"},{"title":"2"},{"title":"4"}]}}""")
+  }
+
+  "Ordering by the count of a to-many relation" should "work for nested related records with pagination" in {
+    val result = server.query(
+      """
+        |{
+        |  users(orderBy: name_ASC) {
+        |    name
+        |    posts(orderBy: comments_COUNT_ASC, last: 1) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"users":[{"name":"A","posts":[{"title":"3"}]},{"name":"B","posts":[{"title":"1"}]}]}}""")
+  }
+
+  private def postId(title: String): String = {
+    server
+      .query(s"""{ post(where: { title: "$title" }) { id } }""", project)
+      .pathAsString("data.post.id")
+  }
+
+  private def createData(): Unit = {
+    server.query(
+      """
+        |mutation {
+        |  a: createUser(data: { name: "B", posts: { create: [{ title: "1", comments: { create: [{ text: "a" }] } }] } }){ id }
+        |  b: createUser(data: { name: "A", posts: { create: [{ title: "2" }, { title: "3", comments: { create: [{ text: "b" }, { text: "c" }] } }] } }){ id }
+        |  c: createPost(data: { title: "4" }){ id }
+        |}
+      """,
+      project
+    )
+  }
+}
@@ -26,6 +26,10 @@ object ConnectorCapability extends Enumeratum[ConnectorCapability] {
   object MongoJoinRelationLinksCapability    extends ConnectorCapability // does not allow NOT/OR and only _some on manyrelations
   object RelationLinkListCapability          extends ConnectorCapability // relation links can be stored inline in a node in a list
   object RelationLinkTableCapability         extends ConnectorCapability // relation links are stored in a table
+  object InsensitiveFiltersCapability        extends ConnectorCapability // string filters can ignore the case
+  object JsonFiltersCapability               extends ConnectorCapability // filters can compare values inside of Json fields
+  object ScalarListFiltersCapability         extends ConnectorCapability // embedded scalar lists can be filtered by their elements
+  object FullTextSearchCapability            extends ConnectorCapability // fields of full-text indexes can be searched
 
   sealed trait IdCapability   extends ConnectorCapability
   object IntIdCapability      extends IdCapability
@@ -55,9 +59,9 @@ object ConnectorCapabilities {
   val empty: ConnectorCapabilities                                     = ConnectorCapabilities(Set.empty[ConnectorCapability])
   def apply(capabilities: ConnectorCapability*): ConnectorCapabilities = ConnectorCapabilities(Set(capabilities: _*))
 
-  lazy val sqlite: ConnectorCapabilities = ConnectorCapabilities(sqlShared)
+  lazy val sqlite: ConnectorCapabilities = ConnectorCapabilities(sqlShared + FullTextSearchCapability)
   lazy val postgres: ConnectorCapabilities = ConnectorCapabilities(sqlShared)
-  lazy val mysql: ConnectorCapabilities = ConnectorCapabilities(sqlShared)
+  lazy val mysql: ConnectorCapabilities = ConnectorCapabilities(sqlShared + InsensitiveFiltersCapability)
 
   private lazy val sqlShared: Set[ConnectorCapability] = {
     Set(
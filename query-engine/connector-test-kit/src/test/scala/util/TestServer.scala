@@ -22,15 +22,17 @@ case class TestServer() extends PlayJsonExtensions {
   def query(
       query: String,
       project: Project,
-      dataContains: String = ""
+      dataContains: String = "",
+      transactional: Boolean = false
   ): JsValue = {
-    awaitInfinitely { queryAsync(query, project, dataContains) }
+    awaitInfinitely { queryAsync(query, project, dataContains, transactional) }
   }
 
-  def queryAsync(query: String, project: Project, dataContains: String = ""): Future[JsValue] = {
+  def queryAsync(query: String, project: Project, dataContains: String = "", transactional: Boolean = false): Future[JsValue] = {
     val result = querySchemaAsync(
       query = query.stripMargin,
       project = project,
+      transactional = transactional,
     )
 
     result.map { r =>
@@ -44,12 +46,14 @@ case class TestServer() extends PlayJsonExtensions {
       project: Project,
       errorCode: Int,
       errorCount: Int = 1,
-      errorContains: String = ""
+      errorContains: String = "",
+      transactional: Boolean = false
   ): JsValue = {
     val result = awaitInfinitely {
       querySchemaAsync(
         query = query,
         project = project,
+        transactional = transactional,
       )
     }
 
@@ -58,9 +62,29 @@ case class TestServer() extends PlayJsonExtensions {
     result
   }
 
+  /**
+    * Sends several requests to the same query engine process, e.g. to keep an interactive transaction open across requests.
+    * The given function receives a function posting a JSON body to a path of the engine.
+    */
+  def withEngine[T](project: Project)(f: ((String, JsValue) => JsValue) => T): T = {
+    val (port, queryEngineProcess) = startQueryEngine(project)
+
+    try {
+      f((path, body) => {
+        println(s"Request to $path: $body")
+        val result = postToPrismaProcess(path, body.toString(), port).jsonBody.get
+        println(s"Result: $result")
+        result
+      })
+    } finally {
+      queryEngineProcess.destroyForcibly().waitFor()
+    }
+  }
+
   private def querySchemaAsync(
       query: String,
-      project: Project
+      project: Project,
+      transactional: Boolean
   ): Future[JsValue] = {
     val (port, queryEngineProcess) = startQueryEngine(project)
 
@@ -68,7 +92,7 @@ case class TestServer() extends PlayJsonExtensions {
     println(s"Query: $query")
 
     Future {
-      queryPrismaProcess(query, port)
+      queryPrismaProcess(query, port, transactional)
     }.map(r => r.jsonBody.get)
       .transform { r =>
         println(s"Query result: $r")
@@ -107,16 +131,19 @@ case class TestServer() extends PlayJsonExtensions {
     (port, process)
   }
 
-  private def queryPrismaProcess(query: String, port: Int): QueryEngineResponse = {
-    val url = new URL(s"http://127.0.0.1:$port")
+  private def queryPrismaProcess(query: String, port: Int, transactional: Boolean): QueryEngineResponse = {
+    val body = Json.obj("query" -> query, "variables" -> Json.obj(), "transactional" -> transactional).toString()
+    postToPrismaProcess("/", body, port)
+  }
+
+  private def postToPrismaProcess(path: String, body: String, port: Int): QueryEngineResponse = {
+    val url = new URL(s"http://127.0.0.1:$port$path")
     val con = url.openConnection().asInstanceOf[HttpURLConnection]
 
     con.setDoOutput(true)
     con.setRequestMethod("POST")
     con.setRequestProperty("Content-Type", "application/json")
 
-    val body = Json.obj("query" -> query, "variables" -> Json.obj()).toString()
-
     con.setRequestProperty("Content-Length", Integer.toString(body.length))
     con.getOutputStream.write(body.getBytes(StandardCharsets.UTF_8))
 
@@ -0,0 +1,76 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BigIntSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """|model Item {
+       | id    String @id @default(cuid())
+       | name  String @unique
+       | views BigInt
+       |}"""
+  }
+
+  val bigIntIdProject = ProjectDsl.fromString {
+    """|model Account {
+       | id      BigInt @id
+       | name    String
+       | entries Entry[]
+       |}
+       |
+       |model Entry {
+       | id      String  @id @default(cuid())
+       | title   String
+       | account Account
+       |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    database.setup(bigIntIdProject)
+  }
+
+  "A BigInt larger than the safe JavaScript integers" should "be returned exactly as string" in {
+    server.query(s"""mutation {createItem(data: {name: "First", views: "9007199254740993"}){name}}""", project)
+    val res = server.query(s"""query {item(where:{name: "First"}){name, views}}""", project)
+    res.toString should be("""{"data":{"item":{"name":"First","views":"9007199254740993"}}}""")
+  }
+
+  "A BigInt given as number" should "be returned as string" in {
+    server.query(s"""mutation {createItem(data: {name: "Second", views: 42}){name}}""", project)
+    val res = server.query(s"""query {item(where:{name: "Second"}){name, views}}""", project)
+    res.toString should be("""{"data":{"item":{"name":"Second","views":"42"}}}""")
+  }
+
+  "BigInt filters" should "compare exactly" in {
+    server.query(s"""mutation {createItem(data: {name: "Third", views: "9007199254740995"}){name}}""", project)
+    val res = server.query(s"""query {items(where:{views_gt: "9007199254740994"}){name}}""", project)
+    res.toString should be("""{"data":{"items":[{"name":"Third"}]}}""")
+  }
+
+  "A BigInt id" should "be usable to create, find, update and delete records" in {
+    server.query(
+      s"""mutation {createAccount(data: {id: "9007199254740993", name: "A", entries: {create: [{title: "x"}]}}){id}}""",
+      bigIntIdProject
+    )
+
+    val read = server.query(s"""query {account(where:{id: "9007199254740993"}){id, name, entries {title}}}""", bigIntIdProject)
+    read.toString should be("""{"data":{"account":{"id":"9007199254740993","name":"A","entries":[{"title":"x"}]}}}""")
+
+    val entries = server.query(s"""query {entries{title, account {id}}}""", bigIntIdProject)
+    entries.toString should be("""{"data":{"entries":[{"title":"x","account":{"id":"9007199254740993"}}]}}""")
+
+    val updated = server.query(
+      s"""mutation {updateAccount(where:{id: "9007199254740993"}, data: {name: "B"}){id, name}}""",
+      bigIntIdProject
+    )
+    updated.toString should be("""{"data":{"updateAccount":{"id":"9007199254740993","name":"B"}}}""")
+
+    server.query(s"""mutation {deleteManyEntries{count}}""", bigIntIdProject)
+    val deleted = server.query(s"""mutation {deleteAccount(where:{id: "9007199254740993"}){id}}""", bigIntIdProject)
+    deleted.toString should be("""{"data":{"deleteAccount":{"id":"9007199254740993"}}}""")
+  }
+}
@@ -0,0 +1,42 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BytesSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """|model Item {
+       | id     String @id @default(cuid())
+       | name   String @unique
+       | avatar Bytes?
+       |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  // Bytes are bound as base64 text, which Postgres doesn't convert to bytea.
+  "Bytes" should "be written and returned as base64" taggedAs IgnorePostgres in {
+    server.query(s"""mutation {createItem(data: {name: "First", avatar: "AQID/w=="}){name}}""", project)
+    val res = server.query(s"""query {item(where:{name: "First"}){name, avatar}}""", project)
+    res.toString should be("""{"data":{"item":{"name":"First","avatar":"AQID/w=="}}}""")
+  }
+
+  "Bytes" should "be filterable by equality" taggedAs IgnorePostgres in {
+    server.query(s"""mutation {createItem(data: {name: "Second", avatar: "BAUG"}){name}}""", project)
+    val res = server.query(s"""query {items(where:{avatar: "BAUG"}){name}}""", project)
+    res.toString should be("""{"data":{"items":[{"name":"Second"}]}}""")
+  }
+
+  "Invalid base64" should "be rejected" in {
+    server.queryThatMustFail(
+      s"""mutation {createItem(data: {name: "Third", avatar: "not base64!"}){name}}""",
+      project,
+      errorCode = 0,
+      errorContains = "Invalid base64 Bytes"
+    )
+  }
+}
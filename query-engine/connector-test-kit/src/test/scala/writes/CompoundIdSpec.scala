@@ -0,0 +1,160 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CompoundIdSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Membership {
+      |  groupId String
+      |  userId  String
+      |  role    String
+      |
+      |  @@id([groupId, userId])
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  val ada = """groupId_userId: { groupId: "admins", userId: "ada" }"""
+
+  def createMemberships(): Unit = {
+    server.query("""mutation { createMembership(data: { groupId: "admins", userId: "ada", role: "owner" }) { role } }""", project)
+    server.query("""mutation { createMembership(data: { groupId: "admins", userId: "bob", role: "member" }) { role } }""", project)
+    server.query("""mutation { createMembership(data: { groupId: "users", userId: "ada", role: "member" }) { role } }""", project)
+  }
+
+  "A model with a compound id" should "be created and returned" in {
+    val result = server.query(
+      """mutation { createMembership(data: { groupId: "admins", userId: "ada", role: "owner" }) { groupId userId role } }""",
+      project
+    )
+
+    result.toString should be("""{"data":{"createMembership":{"groupId":"admins","userId":"ada","role":"owner"}}}""")
+  }
+
+  "A model with a compound id" should "fail to create a record with an existing id" in {
+    createMemberships()
+
+    server.queryThatMustFail(
+      """mutation { createMembership(data: { groupId: "admins", userId: "ada", role: "member" }) { role } }""",
+      project,
+      errorCode = 3010
+    )
+  }
+
+  "A model with a compound id" should "find a single record by its id" in {
+    createMemberships()
+
+    val result = server.query(s"""{ membership(where: { $ada }) { role } }""", project)
+    result.toString should be("""{"data":{"membership":{"role":"owner"}}}""")
+  }
+
+  "A model with a compound id" should "be ordered by all id fields by default" in {
+    createMemberships()
+
+    val result = server.query("""{ memberships { groupId userId } }""", project)
+    result.toString should be(
+      """{"data":{"memberships":[{"groupId":"admins","userId":"ada"},{"groupId":"admins","userId":"bob"},{"groupId":"users","userId":"ada"}]}}""")
+  }
+
+  "A model with a compound id" should "be paginated with cursors of all id fields" in {
+    createMemberships()
+
+    val after = server.query("""{ memberships(after: { groupId: "admins", userId: "ada" }) { groupId userId } }""", project)
+    after.toString should be("""{"data":{"memberships":[{"groupId":"admins","userId":"bob"},{"groupId":"users","userId":"ada"}]}}""")
+
+    val before = server.query("""{ memberships(before: { groupId: "users", userId: "ada" }) { groupId userId } }""", project)
+    before.toString should be("""{"data":{"memberships":[{"groupId":"admins","userId":"ada"},{"groupId":"admins","userId":"bob"}]}}""")
+
+    val ordered = server.query(
+      """{ memberships(orderBy: userId_DESC, after: { groupId: "admins", userId: "bob" }) { groupId userId } }""",
+      project
+    )
+    ordered.toString should be("""{"data":{"memberships":[{"groupId":"admins","userId":"ada"},{"groupId":"users","userId":"ada"}]}}""")
+  }
+
+  "A model with a compound id" should "be usable without its relation fields" in {
+    val relatedProject = ProjectDsl.fromString {
+      """model User {
+        |  id          String       @id @default(cuid())
+        |  name        String
+        |  memberships Membership[]
+        |}
+        |
+        |model Membership {
+        |  groupId String
+        |  userId  String
+        |  user    User?
+        |
+        |  @@id([groupId, userId])
+        |}
+      """.stripMargin
+    }
+    database.setup(relatedProject)
+
+    server.query("""mutation { createMembership(data: { groupId: "admins", userId: "ada" }) { groupId } }""", relatedProject)
+    server.query("""mutation { createUser(data: { name: "Ada" }) { name } }""", relatedProject)
+
+    val result = server.query("""{ memberships { groupId userId } users { name } }""", relatedProject)
+    result.toString should be("""{"data":{"memberships":[{"groupId":"admins","userId":"ada"}],"users":[{"name":"Ada"}]}}""")
+
+    server.queryThatMustFail(
+      """{ users { memberships { groupId } } }""",
+      relatedProject,
+      errorCode = 0,
+      errorContains = "Field does not exist on enclosing type."
+    )
+  }
+
+  "A model with a compound id" should "update a single record by its id" in {
+    createMemberships()
+
+    val result = server.query(s"""mutation { updateMembership(where: { $ada }, data: { role: "member" }) { role } }""", project)
+    result.toString should be("""{"data":{"updateMembership":{"role":"member"}}}""")
+
+    val owners = server.query("""{ memberships(where: { role: "owner" }) { userId } }""", project)
+    owners.toString should be("""{"data":{"memberships":[]}}""")
+  }
+
+  "A model with a compound id" should "update many records" in {
+    createMemberships()
+
+    val result = server.query("""mutation { updateManyMemberships(where: { userId: "ada" }, data: { role: "admin" }) { count } }""", project)
+    result.pathAsLong("data.updateManyMemberships.count") should equal(2)
+
+    val bob = server.query("""{ membership(where: { groupId_userId: { groupId: "admins", userId: "bob" } }) { role } }""", project)
+    bob.pathAsString("data.membership.role") should equal("member")
+  }
+
+  "A model with a compound id" should "upsert a record by its id" in {
+    val upsert =
+      s"""mutation {
+        |  upsertMembership(
+        |    where: { $ada }
+        |    create: { groupId: "admins", userId: "ada", role: "owner" }
+        |    update: { role: "member" }
+        |  ) { role }
+        |}
+      """.stripMargin
+
+    server.query(upsert, project).pathAsString("data.upsertMembership.role") should equal("owner")
+    server.query(upsert, project).pathAsString("data.upsertMembership.role") should equal("member")
+  }
+
+  "A model with a compound id" should "delete a single record by its id" in {
+    createMemberships()
+
+    val result = server.query(s"""mutation { deleteMembership(where: { $ada }) { role } }""", project)
+    result.toString should be("""{"data":{"deleteMembership":{"role":"owner"}}}""")
+
+    val remaining = server.query("""{ memberships { groupId userId } }""", project)
+    remaining.toString should be("""{"data":{"memberships":[{"groupId":"admins","userId":"bob"},{"groupId":"users","userId":"ada"}]}}""")
+  }
+}
@@ -0,0 +1,58 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class CreateManyScalarListSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = ProjectDsl.fromString {
+    s"""model Item {
+      |  id    String   @id @default(cuid())
+      |  name  String   @unique
+      |  tags  String[] $scalarListDirective
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "The create many Mutation" should "create records with scalar list values" in {
+    val result = server.query(
+      """mutation {
+        |  createManyItems(data: [{ name: "a", tags: { set: ["x", "y"] } }, { name: "b" }]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+    result.pathAsLong("data.createManyItems.count") should equal(2)
+
+    val items = server.query("""{ items(orderBy: name_ASC) { name tags } }""", project)
+    items.pathAsJsValue("data.items").toString should be("""[{"name":"a","tags":["x","y"]},{"name":"b","tags":[]}]""")
+  }
+
+  "The create many Mutation" should "reject skipping duplicates for records with scalar list values" in {
+    server.queryThatMustFail(
+      """mutation {
+        |  createManyItems(data: [{ name: "a", tags: { set: ["x"] } }, { name: "b" }], skipDuplicates: true) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Duplicates can't be skipped when creating records with scalar list values."
+    )
+
+    val count = server.query("""{ aggregateItem { count } }""", project)
+    count.pathAsLong("data.aggregateItem.count") should equal(0)
+  }
+}
@@ -0,0 +1,87 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Item {
+      |  id    String  @id @default(cuid())
+      |  name  String  @unique
+      |  opt   String?
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "The create many Mutation" should "create all given records and return their count" in {
+    val result = server.query(
+      """mutation {
+        |  createManyItems(data: [{ name: "a" }, { name: "b", opt: "test" }, { id: "custom", name: "c" }]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+    result.pathAsLong("data.createManyItems.count") should equal(3)
+
+    val items = server.query("""{ items(orderBy: name_ASC) { name opt } }""", project)
+    mustBeEqual(
+      items.pathAsJsValue("data.items").toString,
+      """[{"name":"a","opt":null},{"name":"b","opt":"test"},{"name":"c","opt":null}]"""
+    )
+  }
+
+  "The create many Mutation" should "split large inputs into several inserts" in {
+    // 22000 records with an id, name and opt value each exceed the maximum number of bind parameters of a single
+    // statement on all databases (999 on SQLite, 32767 on Postgres and 65535 on MySQL).
+    val data = (1 to 22000).map(i => s"""{ name: "item$i", opt: "$i" }""").mkString("[", ", ", "]")
+
+    val result = server.query(s"""mutation { createManyItems(data: $data) { count } }""", project)
+    result.pathAsLong("data.createManyItems.count") should equal(22000)
+
+    val count = server.query("""{ aggregateItem { count } }""", project)
+    count.pathAsLong("data.aggregateItem.count") should equal(22000)
+  }
+
+  "The create many Mutation" should "fail on duplicates by default" in {
+    server.query("""mutation { createItem(data: { name: "a" }) { id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation {
+        |  createManyItems(data: [{ name: "a" }, { name: "b" }]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 3010,
+      errorContains = "A unique constraint would be violated on Item. Details: Field name = name"
+    )
+  }
+
+  "The create many Mutation" should "skip duplicates if requested" in {
+    server.query("""mutation { createItem(data: { name: "a" }) { id } }""", project)
+
+    val result = server.query(
+      """mutation {
+        |  createManyItems(data: [{ name: "a" }, { name: "b" }], skipDuplicates: true) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+    result.pathAsLong("data.createManyItems.count") should equal(1)
+
+    val count = server.query("""{ aggregateItem { count } }""", project)
+    count.pathAsLong("data.aggregateItem.count") should equal(2)
+  }
+}
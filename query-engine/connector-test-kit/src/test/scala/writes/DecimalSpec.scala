@@ -0,0 +1,39 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DecimalSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """|model Item {
+       | id    String  @id @default(cuid())
+       | name  String  @unique
+       | price Decimal
+       |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  // Decimals are bound as floats, they are exact up to 15 significant digits.
+  "A Decimal with 15 significant digits" should "be returned exactly" in {
+    server.query(s"""mutation {createItem(data: {name: "First", price: "1234567890.12345"}){name}}""", project)
+    val res = server.query(s"""query {item(where:{name: "First"}){name, price}}""", project)
+    res.toString should be("""{"data":{"item":{"name":"First","price":"1234567890.12345"}}}""")
+  }
+
+  "A Decimal given as number" should "be returned as string" in {
+    server.query(s"""mutation {createItem(data: {name: "Second", price: 1.5}){name}}""", project)
+    val res = server.query(s"""query {item(where:{name: "Second"}){name, price}}""", project)
+    res.toString should be("""{"data":{"item":{"name":"Second","price":"1.5"}}}""")
+  }
+
+  "Decimal filters" should "compare decimals" in {
+    server.query(s"""mutation {createItem(data: {name: "Third", price: "0.1"}){name}}""", project)
+    val res = server.query(s"""query {items(where:{price_gt: "0.0999", price_lt: "0.1001"}){name}}""", project)
+    res.toString should be("""{"data":{"items":[{"name":"Third"}]}}""")
+  }
+}
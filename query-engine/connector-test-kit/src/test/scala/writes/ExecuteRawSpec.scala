@@ -1,220 +1,90 @@
-//package writes
-//
-//import org.scalatest.{Matchers, WordSpecLike}
-//import play.api.libs.json.{JsString, JsValue}
-//import sangria.util.StringUtil
-//import util.ConnectorCapability.{JoinRelationLinksCapability, RawAccessCapability}
-//import util._
-//
-//class ExecuteRawSpec extends WordSpecLike with Matchers with ApiSpecBase {
-//
-//  override def runOnlyForCapabilities: Set[ConnectorCapability] = Set(JoinRelationLinksCapability, RawAccessCapability)
-//
-//  val project = ProjectDsl.fromString {
-//    """
-//      |model Todo {
-//      |  id String @id @default(cuid())
-//      |  title: String
-//      |}
-//    """.stripMargin
-//  }
-//
-//  val schemaName = project.dbName
-//  val model      = project.schema.getModelByName_!("Todo")
-//
-//  override protected def beforeAll(): Unit = {
-//    super.beforeAll()
-//    database.setup(project)
-//  }
-//
-//  override protected def beforeEach(): Unit = {
-//    super.beforeEach()
-//    database.truncateProjectTables(project)
-//  }
-//
-//  lazy val slickDatabase = testDependencies.databaseMutactionExecutor match {
-//    case m: JdbcDatabaseMutactionExecutor   => m.slickDatabase
-//    case m: NativeDatabaseMutactionExecutor => m.slickDatabaseArg
-//  }
-//
-//  lazy val isMySQL     = connectorTag == ConnectorTag.MySqlConnectorTag
-//  lazy val isPostgres  = connectorTag == ConnectorTag.PostgresConnectorTag
-//  lazy val isSQLite    = connectorTag == ConnectorTag.SQLiteConnectorTag
-//
-//  lazy val dialect = connectorTag match {
-//    case ConnectorTag.MySqlConnectorTag => SQLDialect.MYSQL_5_7
-//    case ConnectorTag.PostgresConnectorTag => SQLDialect.POSTGRES
-//    case ConnectorTag.SQLiteConnectorTag => SQLDialect.SQLITE
-//    case ConnectorTag.MongoConnectorTag => sys.error("No raw queries for Mongo")
-//  }
-//
-//  lazy val sql         = DSL.using(dialect, new Settings().withRenderFormatted(true))
-//  lazy val modelTable  = table(name(schemaName, model.dbName))
-//  lazy val idColumn    = model.idField_!.dbName
-//  lazy val titleColumn = model.getScalarFieldByName_!("title").dbName
-//
-//  "the simplest query Select 1 should work" in {
-//    val result = server.query(
-//      """mutation {
-//        |  executeRaw(
-//        |    database: default
-//        |    query: "Select 1"
-//        |  )
-//        |}
-//          """.stripMargin,
-//      project
-//    )
-//
-//    val columnName = if (isPostgres) "?column?" else "1"
-//    result.pathAsJsValue("data.executeRaw") should equal(s"""[{"$columnName":1}]""".parseJson)
-//  }
-//
-//  "querying model tables should work" in {
-//    val id1 = createTodo("title1")
-//    val id2 = createTodo(null)
-//
-//    val result = executeRaw(sql.select().from(modelTable))
-//
-//    result.pathAsJsValue("data.executeRaw") should equal(
-//      s"""[{"$idColumn":"$id1","$titleColumn":"title1"},{"$idColumn":"$id2","$titleColumn":null}]""".parseJson)
-//  }
-//
-//  "inserting into a model table should work" in {
-//    val insertResult = executeRaw(sql.insertInto(modelTable).columns(field(idColumn), field(titleColumn)).values("id1", "title1").values("id2", "title2"))
-//    insertResult.pathAsJsValue("data.executeRaw") should equal("2".parseJson)
-//
-//    val readResult = executeRaw(sql.select().from(modelTable))
-//    readResult.pathAsJsValue("data.executeRaw") should equal(
-//      s"""[{"$idColumn":"id1","$titleColumn":"title1"},{"$idColumn":"id2","$titleColumn":"title2"}]""".parseJson)
-//  }
-//
-//  "querying model tables with alias should work" in {
-//    val id1 = createTodo("title1")
-//    val id2 = createTodo(null)
-//
-//    val result = executeRaw(sql.select(field(titleColumn).as("aliasedTitle")).from(modelTable))
-//
-//    result.pathAsJsValue("data.executeRaw") should equal(s"""[{"aliasedTitle":"title1"},{"aliasedTitle":null}]""".parseJson)
-//  }
-//
-//  "querying the same column name twice but aliasing it should work" in {
-//    val id1 = createTodo("title1")
-//    val id2 = createTodo(null)
-//
-//    val result = executeRaw(sql.select(field(titleColumn).as("ALIASEDTITLE"), field(titleColumn)).from(modelTable))
-//
-//    result.pathAsJsValue("data.executeRaw") should equal(
-//      s"""[{"ALIASEDTITLE":"title1","$titleColumn":"title1"},{"ALIASEDTITLE":null,"$titleColumn":null}]""".parseJson)
-//  }
-//
-//  "postgres arrays should work" in {
-//    if (isPostgres) {
-//      val query =
-//        """
-//          |SELECT
-//          |    array_agg(columnInfos.attname) as postgres_array
-//          |FROM
-//          |    pg_attribute columnInfos;
-//        """.stripMargin
-//
-//      val result = server.query(
-//        s"""mutation {
-//           |  executeRaw(
-//           |    query: "${StringUtil.escapeString(query)}"
-//           |  )
-//           |}
-//        """.stripMargin,
-//        project
-//      )
-//
-//      val postgresArray = result.pathAsJsArray("data.executeRaw").value.head.pathAsJsArray("postgres_array").value
-//      postgresArray should not(be(empty))
-//      val allAreStrings = postgresArray.forall {
-//        case _: JsString => true
-//        case _           => false
-//      }
-//      allAreStrings should be(true)
-//    }
-//  }
-//
-//  "syntactic errors should bubble through to the user" taggedAs (IgnoreSQLite, IgnoreMySql, IgnorePostgres) in {
-//    val (errorCode, errorContains) = () match {
-//      case _ if isPostgres => (0, "syntax error at end of input")
-//      case _ if isMySQL    => (1064, "check the manual that corresponds to your MySQL server version for the right syntax to use near")
-//      case _ if isSQLite   => (1, "incomplete input")
-//    }
-//    server.queryThatMustFail(
-//      s"""mutation {
-//         |  executeRaw(
-//         |    query: "Select * from "
-//         |  )
-//         |}
-//      """.stripMargin,
-//      project,
-//      errorCode = errorCode,
-//      errorContains = errorContains
-//    )
-//  }
-//
-//  "other errors should also bubble through to the user" taggedAs (IgnoreSQLite, IgnoreMySql, IgnorePostgres) in {
-//    val id = createTodo("title")
-//    val (errorCode, errorContains) = () match {
-//      case _ if isPostgres => (0, "duplicate key value violates unique constraint")
-//      case _ if isMySQL    => (1062, "Duplicate entry")
-//      case _ if isSQLite   => (19, "Abort due to constraint violation (UNIQUE constraint failed: Todo.id)")
-//    }
-//
-//    executeRawThatMustFail(
-//      sql.insertInto(modelTable).columns(field(idColumn), field(titleColumn)).values(id, "irrelevant"),
-//      errorCode = errorCode,
-//      errorContains = errorContains
-//    )
-//  }
-//
-//  def executeRaw(query: Query): JsValue = {
-//    server.query(
-//      s"""mutation {
-//         |  executeRaw(
-//         |    query: "${queryString(query)}"
-//         |  )
-//         |}
-//      """.stripMargin,
-//      project
-//    )
-//  }
-//
-//  def executeRawThatMustFail(query: Query, errorCode: Int, errorContains: String): JsValue = {
-//    server.queryThatMustFail(
-//      s"""mutation {
-//         |  executeRaw(
-//         |    query: "${queryString(query)}"
-//         |  )
-//         |}
-//      """.stripMargin,
-//      project,
-//      errorCode = errorCode,
-//      errorContains = errorContains
-//    )
-//  }
-//
-//  def queryString(query: Query): String = StringUtil.escapeString(query.getSQL(ParamType.INLINED))
-//
-//  def createTodo(title: String) = {
-//    val finalTitle = Option(title).map(s => s""""$s"""").orNull
-//    server
-//      .query(
-//        s"""mutation {
-//           |  createTodo(
-//           |    data: {
-//           |      title: $finalTitle
-//           |    }
-//           |  ) {
-//           |    id
-//           |  }
-//           |}
-//      """.stripMargin,
-//        project
-//      )
-//      .pathAsString("data.createTodo.id")
-//  }
-//}
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json._
+import util.ConnectorCapability.RawAccessCapability
+import util._
+
+class ExecuteRawSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  override def runOnlyForCapabilities: Set[ConnectorCapability] = Set(RawAccessCapability)
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String  @id @default(cuid())
+      |  title  String
+      |  count  Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  lazy val isMySQL    = connectorTag == ConnectorTag.MySqlConnectorTag
+  lazy val isPostgres = connectorTag == ConnectorTag.PostgresConnectorTag
+
+  lazy val todoTable = s"${quote(project.id)}.${quote("Todo")}"
+
+  def quote(identifier: String): String = if (isMySQL) s"`$identifier`" else "\"" + identifier + "\""
+  def param(index: Int): String         = if (isPostgres) "$" + index else "?"
+
+  def raw(operation: String, sql: String, parameters: JsValue*): JsValue = {
+    val args   = s"query: ${JsString(sql)}, parameters: ${JsString(JsArray(parameters).toString)}"
+    val result = server.query(s"mutation { $operation($args) }", project)
+
+    result.pathAsJsValue(s"data.$operation")
+  }
+
+  def createTodo(title: String, count: Int): Unit = {
+    server.query(s"""mutation { createTodo(data: { title: "$title", count: $count }) { id } }""", project)
+  }
+
+  "The executeRaw mutation" should "run a parameterized statement and return the number of affected rows" in {
+    val insert = s"INSERT INTO $todoTable (id, title, count) VALUES (${param(1)}, ${param(2)}, ${param(3)})"
+
+    raw("executeRaw", insert, JsString("id1"), JsString("title1"), JsNumber(1)) should equal(JsNumber(1))
+
+    val todoes = server.query("""{ todoes { id title count } }""", project)
+    todoes.toString should be("""{"data":{"todoes":[{"id":"id1","title":"title1","count":1}]}}""")
+  }
+
+  "The executeRaw mutation" should "count all rows changed by a statement" in {
+    createTodo("a", 1)
+    createTodo("b", 2)
+    createTodo("c", 3)
+
+    val update = s"UPDATE $todoTable SET title = ${param(1)} WHERE count >= ${param(2)}"
+
+    raw("executeRaw", update, JsString("updated"), JsNumber(2)) should equal(JsNumber(2))
+  }
+
+  "The queryRaw mutation" should "return the rows of a parameterized query as typed JSON" in {
+    createTodo("a", 1)
+    createTodo("b", 2)
+    createTodo("c", 3)
+
+    val select = s"SELECT title, count FROM $todoTable WHERE count > ${param(1)} ORDER BY count"
+
+    raw("queryRaw", select, JsNumber(1)) should equal(
+      Json.arr(Json.obj("title" -> "b", "count" -> 2), Json.obj("title" -> "c", "count" -> 3))
+    )
+  }
+
+  "The queryRaw mutation" should "return an empty list if no rows match" in {
+    val select = s"SELECT title FROM $todoTable WHERE title = ${param(1)}"
+
+    raw("queryRaw", select, JsString("missing")) should equal(Json.arr())
+  }
+
+  "The queryRaw mutation" should "fail with invalid SQL" in {
+    server.queryThatMustFail(
+      s"""mutation { queryRaw(query: "SELECT * FROM does_not_exist") }""",
+      project,
+      errorCode = 0
+    )
+  }
+}
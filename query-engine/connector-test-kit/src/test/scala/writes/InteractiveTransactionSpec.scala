@@ -0,0 +1,61 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json._
+import util._
+
+class InteractiveTransactionSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String  @id @default(cuid())
+      |  title  String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  val createTodo = Json.obj("query" -> """mutation { createTodo(data: { title: "title1" }) { title } }""")
+  val readTodoes = Json.obj("query" -> """{ todoes { title } }""")
+
+  "An interactive transaction" should "make its writes visible after a commit" in {
+    server.withEngine(project) { post =>
+      val id = post("/transaction/begin", Json.obj()).pathAsString("id")
+
+      post(s"/transaction/$id", createTodo).pathAsString("data.createTodo.title") should equal("title1")
+      post(s"/transaction/$id", readTodoes).toString should be("""{"data":{"todoes":[{"title":"title1"}]}}""")
+      post(s"/transaction/$id/commit", Json.obj()).toString should be("{}")
+
+      post("/", readTodoes).toString should be("""{"data":{"todoes":[{"title":"title1"}]}}""")
+    }
+  }
+
+  "An interactive transaction" should "discard its writes after a rollback" in {
+    server.withEngine(project) { post =>
+      val id = post("/transaction/begin", Json.obj()).pathAsString("id")
+
+      post(s"/transaction/$id", createTodo)
+      post(s"/transaction/$id/rollback", Json.obj()).toString should be("{}")
+
+      post("/", readTodoes).toString should be("""{"data":{"todoes":[]}}""")
+      post(s"/transaction/$id", readTodoes).toString should include("does not exist")
+    }
+  }
+
+  "An interactive transaction" should "be rolled back once its timeout expires" in {
+    server.withEngine(project) { post =>
+      val id = post("/transaction/begin", Json.obj("timeout" -> 200)).pathAsString("id")
+
+      post(s"/transaction/$id", createTodo)
+      Thread.sleep(1000)
+
+      post(s"/transaction/$id/commit", Json.obj()).toString should include("does not exist")
+      post("/", readTodoes).toString should be("""{"data":{"todoes":[]}}""")
+    }
+  }
+}
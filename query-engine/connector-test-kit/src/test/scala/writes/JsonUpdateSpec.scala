@@ -0,0 +1,121 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json.{JsValue, Json}
+import util._
+
+class JsonUpdateSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Item {
+                                                   |  id     String @id @default(cuid())
+                                                   |  unique Int    @unique
+                                                   |  meta   Json?
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+    createItem(1, """{\"status\": \"active\", \"size\": {\"width\": 10}}""")
+    createItem(2, """{\"status\": \"inactive\"}""")
+  }
+
+  "A set update" should "replace the value at the path and keep the rest of the document" in {
+    server.query(
+      """mutation {
+        |  updateItem(where: { unique: 1 }, data: { meta_set: [{ path: ["size", "width"], value: "20" }] }) {
+        |    unique
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    itemMeta(1) should be(Json.parse("""{"status": "active", "size": {"width": 20}}"""))
+  }
+
+  "A set update" should "add a missing key" in {
+    server.query(
+      """mutation {
+        |  updateItem(where: { unique: 2 }, data: { meta_set: [{ path: ["owner"], value: "\"alice\"" }] }) {
+        |    unique
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    itemMeta(2) should be(Json.parse("""{"status": "inactive", "owner": "alice"}"""))
+  }
+
+  "A set update" should "create the missing parent objects of the path" in {
+    server.query(
+      """mutation {
+        |  updateManyItems(where: {}, data: { meta_set: [{ path: ["size", "depth", "cm"], value: "3" }] }) {
+        |    count
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    itemMeta(1) should be(Json.parse("""{"status": "active", "size": {"width": 10, "depth": {"cm": 3}}}"""))
+    itemMeta(2) should be(Json.parse("""{"status": "inactive", "size": {"depth": {"cm": 3}}}"""))
+  }
+
+  "A merge update" should "merge the keys into the object at the path" in {
+    server.query(
+      """mutation {
+        |  updateItem(where: { unique: 1 }, data: { meta_merge: [{ path: ["size"], value: "{\"height\": 5}" }] }) {
+        |    unique
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    itemMeta(1) should be(Json.parse("""{"status": "active", "size": {"width": 10, "height": 5}}"""))
+  }
+
+  "A merge update" should "remove the keys set to null" in {
+    server.query(
+      """mutation {
+        |  updateItem(where: { unique: 1 }, data: { meta_merge: [{ path: [], value: "{\"status\": null}" }] }) {
+        |    unique
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    itemMeta(1) should be(Json.parse("""{"size": {"width": 10}}"""))
+  }
+
+  "Json updates" should "apply to all records of an updateMany" in {
+    val result = server.query(
+      """mutation {
+        |  updateManyItems(where: {}, data: { meta_set: [{ path: ["status"], value: "\"archived\"" }] }) {
+        |    count
+        |  }
+        |}""".stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.updateManyItems.count") should be(2)
+    itemMeta(1) should be(Json.parse("""{"status": "archived", "size": {"width": 10}}"""))
+    itemMeta(2) should be(Json.parse("""{"status": "archived"}"""))
+  }
+
+  def itemMeta(unique: Int): JsValue =
+    server.query(s"{ item(where: { unique: $unique }){ meta } }", project).pathAsJsValue("data.item.meta")
+
+  def createItem(unique: Int, meta: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { unique: $unique, meta: "$meta" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
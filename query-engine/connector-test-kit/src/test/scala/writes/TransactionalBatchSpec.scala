@@ -0,0 +1,61 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class TransactionalBatchSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String  @id @default(cuid())
+      |  title  String  @unique
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "A transactional request" should "execute all operations" in {
+    val result = server.query(
+      """mutation {
+        |  first: createTodo(data: { title: "title1" }) { title }
+        |  second: createTodo(data: { title: "title2" }) { title }
+        |  updateManyTodoes(where: { title: "title1" }, data: { title: "updated" }) { count }
+        |}
+      """.stripMargin,
+      project,
+      transactional = true
+    )
+
+    result.pathAsString("data.first.title") should equal("title1")
+    result.pathAsString("data.second.title") should equal("title2")
+    result.pathAsLong("data.updateManyTodoes.count") should equal(1)
+
+    val todoes = server.query("""{ todoes(orderBy: title_ASC) { title } }""", project)
+    todoes.toString should be("""{"data":{"todoes":[{"title":"title2"},{"title":"updated"}]}}""")
+  }
+
+  "A transactional request" should "roll back all operations if one fails" in {
+    server.query("""mutation { createTodo(data: { title: "existing" }) { id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation {
+        |  first: createTodo(data: { title: "title1" }) { id }
+        |  second: createTodo(data: { title: "existing" }) { id }
+        |  third: createTodo(data: { title: "title3" }) { id }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Operation 1 ('second') failed, the transaction was rolled back",
+      transactional = true
+    )
+
+    val todoes = server.query("""{ todoes { title } }""", project)
+    todoes.toString should be("""{"data":{"todoes":[{"title":"existing"}]}}""")
+  }
+}
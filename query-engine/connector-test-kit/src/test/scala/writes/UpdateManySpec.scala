@@ -88,6 +88,26 @@ class UpdateManySpec extends FlatSpec with Matchers with ApiSpecBase {
     )
   }
 
+  "The update items Mutation" should "count matching items even if their values don't change" in {
+    createTodo("title1")
+    createTodo("title1")
+    createTodo("title2")
+
+    val result = server.query(
+      """mutation {
+        |  updateManyTodoes(
+        |    where: { title_in: ["title1", "title2"] }
+        |    data: { title: "title1" }
+        |  ){
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+    result.pathAsLong("data.updateManyTodoes.count") should equal(3)
+  }
+
   "UpdateMany" should "work between top level types" in {
 
     val project = ProjectDsl.fromString {
@@ -0,0 +1,88 @@
+use crate::filter::ScalarCondition;
+use prisma_models::*;
+
+/// An aggregation to compute over the records of a model.
+#[derive(Debug, Clone)]
+pub enum Aggregator {
+    Count,
+    Sum(Vec<ScalarFieldRef>),
+    Average(Vec<ScalarFieldRef>),
+    Min(Vec<ScalarFieldRef>),
+    Max(Vec<ScalarFieldRef>),
+}
+
+impl Aggregator {
+    /// The fields the aggregator computes values for. Empty for `Count`.
+    pub fn fields(&self) -> &[ScalarFieldRef] {
+        match self {
+            Aggregator::Count => &[],
+            Aggregator::Sum(fields) => fields,
+            Aggregator::Average(fields) => fields,
+            Aggregator::Min(fields) => fields,
+            Aggregator::Max(fields) => fields,
+        }
+    }
+
+    /// The single aggregated values of the aggregator, one per field.
+    pub fn values(&self) -> Vec<AggregateValue> {
+        match self {
+            Aggregator::Count => vec![AggregateValue::Count],
+            Aggregator::Sum(fields) => fields.iter().cloned().map(AggregateValue::Sum).collect(),
+            Aggregator::Average(fields) => fields.iter().cloned().map(AggregateValue::Average).collect(),
+            Aggregator::Min(fields) => fields.iter().cloned().map(AggregateValue::Min).collect(),
+            Aggregator::Max(fields) => fields.iter().cloned().map(AggregateValue::Max).collect(),
+        }
+    }
+}
+
+/// A single aggregated value, e.g. the sum of a field.
+#[derive(Debug, Clone)]
+pub enum AggregateValue {
+    Count,
+    Sum(ScalarFieldRef),
+    Average(ScalarFieldRef),
+    Min(ScalarFieldRef),
+    Max(ScalarFieldRef),
+}
+
+/// A filter on the aggregated values of a group of records (`HAVING`).
+#[derive(Debug, Clone)]
+pub enum HavingFilter {
+    And(Vec<HavingFilter>),
+    Or(Vec<HavingFilter>),
+    Not(Vec<HavingFilter>),
+    Condition(AggregateValue, ScalarCondition),
+}
+
+/// The records of a model grouped by the values of one or more fields.
+#[derive(Debug, Clone)]
+pub struct RecordGroup {
+    /// Values of the grouped fields, in the order of the fields.
+    pub values: Vec<PrismaValue>,
+
+    /// Aggregation results of the group, in the order of the aggregators.
+    pub aggregations: Vec<AggregationResult>,
+}
+
+/// A single aggregated value.
+/// Aggregators computing values for multiple fields yield one result per field, in the order of the fields.
+#[derive(Debug, Clone)]
+pub enum AggregationResult {
+    Count(usize),
+    Sum(ScalarFieldRef, PrismaValue),
+    Average(ScalarFieldRef, PrismaValue),
+    Min(ScalarFieldRef, PrismaValue),
+    Max(ScalarFieldRef, PrismaValue),
+}
+
+impl AggregationResult {
+    pub fn into_value(self) -> PrismaValue {
+        match self {
+            AggregationResult::Count(count) => PrismaValue::Int(count as i64),
+            AggregationResult::Sum(_, value) => value,
+            AggregationResult::Average(_, value) => value,
+            AggregationResult::Min(_, value) => value,
+            AggregationResult::Max(_, value) => value,
+        }
+    }
+}
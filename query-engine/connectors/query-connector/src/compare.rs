@@ -58,6 +58,10 @@ pub trait ScalarCompare {
     fn greater_than_or_equals<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
 
 /// Comparison methods for relational fields.
@@ -87,13 +91,28 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
     fn contains_none(&self) -> Filter;
 }
+
+/// Comparison methods for values inside of Json fields, at the given path.
+pub trait JsonCompare {
+    fn json_equals<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn json_not_equals<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn json_array_contains<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+}
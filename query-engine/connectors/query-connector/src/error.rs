@@ -1,7 +1,7 @@
 use crate::filter::RecordFinder;
 use failure::{Error, Fail};
 use prisma_models::prelude::{DomainError, GraphqlId, ModelRef, PrismaValue};
-use std::fmt;
+use std::{fmt, time::Duration};
 
 #[derive(Debug)]
 pub struct RecordFinderInfo {
@@ -14,7 +14,7 @@ impl RecordFinderInfo {
     pub fn for_id(model: ModelRef, value: &GraphqlId) -> Self {
         Self {
             model: model.name.clone(),
-            field: model.fields().id().name.clone(),
+            field: model.id_field_names().join("_"),
             value: PrismaValue::from(value.clone()),
         }
     }
@@ -33,9 +33,9 @@ impl fmt::Display for RecordFinderInfo {
 impl From<&RecordFinder> for RecordFinderInfo {
     fn from(ns: &RecordFinder) -> Self {
         Self {
-            model: ns.field.model().name.clone(),
-            field: ns.field.name.clone(),
-            value: ns.value.clone(),
+            model: ns.model().name.clone(),
+            field: ns.field_names(),
+            value: ns.value(),
         }
     }
 }
@@ -114,6 +114,15 @@ pub enum ConnectorError {
 
     #[fail(display = "Authentication failed for user '{}'", user)]
     AuthenticationFailed { user: String },
+
+    #[fail(
+        display = "Timed out fetching a connection from the pool after {:?} (connection limit: {:?})",
+        timeout, connection_limit
+    )]
+    PoolTimeout {
+        connection_limit: Option<usize>,
+        timeout: Duration,
+    },
 }
 
 impl From<DomainError> for ConnectorError {
@@ -0,0 +1,61 @@
+use super::Filter;
+use crate::compare::JsonCompare;
+use prisma_models::{PrismaValue, ScalarField};
+use std::sync::Arc;
+
+/// A filter on the value at a path inside of a Json field.
+#[derive(Debug, Clone)]
+pub struct JsonFilter {
+    pub field: Arc<ScalarField>,
+
+    /// Object keys and array indexes leading to the compared value. Empty for the whole document.
+    pub path: Vec<String>,
+    pub condition: JsonCondition,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonCondition {
+    /// Value at the path is equal to the given Json value
+    Equals(PrismaValue),
+
+    /// Value at the path is not equal to the given Json value
+    NotEquals(PrismaValue),
+
+    /// Value at the path is an array containing the given Json value
+    ArrayContains(PrismaValue),
+}
+
+impl JsonCompare for Arc<ScalarField> {
+    fn json_equals<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(JsonFilter {
+            field: Arc::clone(self),
+            path,
+            condition: JsonCondition::Equals(value.into()),
+        })
+    }
+
+    fn json_not_equals<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(JsonFilter {
+            field: Arc::clone(self),
+            path,
+            condition: JsonCondition::NotEquals(value.into()),
+        })
+    }
+
+    fn json_array_contains<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(JsonFilter {
+            field: Arc::clone(self),
+            path,
+            condition: JsonCondition::ArrayContains(value.into()),
+        })
+    }
+}
@@ -4,16 +4,22 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod json;
 mod list;
 mod record_finder;
 mod relation;
 mod scalar;
 
+pub use json::*;
 pub use list::*;
 pub use record_finder::*;
 pub use relation::*;
 pub use scalar::*;
 
+use crate::compare::ScalarCompare;
+use prisma_models::{PrismaValue, ScalarField};
+use std::sync::Arc;
+
 #[derive(Debug, Clone)]
 pub enum Filter {
     And(Vec<Filter>),
@@ -21,6 +27,7 @@ pub enum Filter {
     Not(Vec<Filter>),
     Scalar(ScalarFilter),
     ScalarList(ScalarListFilter),
+    Json(JsonFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
     NodeSubscription,
@@ -43,6 +50,16 @@ impl Filter {
     pub fn empty() -> Self {
         Filter::BoolFilter(true)
     }
+
+    /// Matches the records with the given ids, which are compound ids if there is more than one id field.
+    pub fn ids(mut id_fields: Vec<Arc<ScalarField>>, ids: Vec<PrismaValue>) -> Self {
+        if id_fields.len() == 1 {
+            id_fields.pop().unwrap().is_in(Some(ids))
+        } else {
+            let finders = ids.into_iter().map(|id| RecordFinder::id(id_fields.clone(), id).into());
+            Filter::or(finders.collect())
+        }
+    }
 }
 
 impl From<ScalarFilter> for Filter {
@@ -57,6 +74,12 @@ impl From<ScalarListFilter> for Filter {
     }
 }
 
+impl From<JsonFilter> for Filter {
+    fn from(jf: JsonFilter) -> Self {
+        Filter::Json(jf)
+    }
+}
+
 impl From<OneRelationIsNullFilter> for Filter {
     fn from(sf: OneRelationIsNullFilter) -> Self {
         Filter::OneRelationIsNull(sf)
@@ -77,10 +100,23 @@ impl From<bool> for Filter {
 
 impl From<RecordFinder> for Filter {
     fn from(record_finder: RecordFinder) -> Self {
-        Filter::Scalar(ScalarFilter {
-            field: record_finder.field,
-            condition: ScalarCondition::Equals(record_finder.value),
-        })
+        let mut filters: Vec<Filter> = record_finder
+            .fields
+            .into_iter()
+            .map(|(field, value)| {
+                Filter::Scalar(ScalarFilter {
+                    field,
+                    condition: ScalarCondition::Equals(value),
+                    mode: QueryMode::Default,
+                })
+            })
+            .collect();
+
+        if filters.len() == 1 {
+            filters.pop().unwrap()
+        } else {
+            Filter::and(filters)
+        }
     }
 }
 
@@ -1,11 +1,11 @@
 use prisma_models::prelude::*;
-use std::sync::Arc;
+use std::{fmt, sync::Arc};
 
-/// Designates a specific record to find by a field and a value that field should have.
+/// Designates a specific record to find by the values of one or more fields that are unique together.
+/// Holds exactly one field for single field uniques and ids, and more for compound uniques and ids.
 #[derive(Debug, Clone)]
 pub struct RecordFinder {
-    pub field: Arc<ScalarField>,
-    pub value: PrismaValue,
+    pub fields: Vec<(Arc<ScalarField>, PrismaValue)>,
 }
 
 impl<T> From<(Arc<ScalarField>, T)> for RecordFinder
@@ -13,10 +13,7 @@ where
     T: Into<PrismaValue>,
 {
     fn from(tup: (Arc<ScalarField>, T)) -> RecordFinder {
-        RecordFinder {
-            field: tup.0,
-            value: tup.1.into(),
-        }
+        RecordFinder::new(tup.0, tup.1)
     }
 }
 
@@ -26,8 +23,51 @@ impl RecordFinder {
         T: Into<PrismaValue>,
     {
         Self {
-            field,
-            value: value.into(),
+            fields: vec![(field, value.into())],
         }
     }
+
+    pub fn compound(fields: Vec<(Arc<ScalarField>, PrismaValue)>) -> Self {
+        Self { fields }
+    }
+
+    /// Finds a record by id. The parts of a compound id are matched against the id fields in order.
+    pub fn id<T>(id_fields: Vec<Arc<ScalarField>>, id: T) -> Self
+    where
+        T: Into<PrismaValue>,
+    {
+        let values = match id.into() {
+            PrismaValue::GraphqlId(GraphqlId::Compound(ids)) => ids.into_iter().map(PrismaValue::from).collect(),
+            value => vec![value],
+        };
+
+        Self::compound(id_fields.into_iter().zip(values).collect())
+    }
+
+    pub fn model(&self) -> ModelRef {
+        self.fields[0].0.model()
+    }
+
+    /// The names of the fields, joined by an underscore for compound finders (e.g. `a_b`).
+    pub fn field_names(&self) -> String {
+        self.fields
+            .iter()
+            .map(|(field, _)| field.name.as_str())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// The value of the single field, or a list of the values of all fields for compound finders.
+    pub fn value(&self) -> PrismaValue {
+        match self.fields.as_slice() {
+            [(_, value)] => value.clone(),
+            fields => PrismaValue::List(Some(fields.iter().map(|(_, value)| value.clone()).collect())),
+        }
+    }
+}
+
+impl fmt::Display for RecordFinder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, {} = {:?}", self.model().name, self.field_names(), self.value())
+    }
 }
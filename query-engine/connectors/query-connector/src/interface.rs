@@ -1,8 +1,33 @@
-use crate::{Filter, QueryArguments, RecordFinder, WriteArgs};
+use crate::{
+    AggregationResult, Aggregator, Filter, HavingFilter, QueryArguments, RecordFinder, RecordGroup, WriteArgs,
+};
 use prisma_models::*;
+use quaint::ast::ParameterizedValue;
+use serde::Serialize;
 
 pub trait Connector {
     fn get_connection<'a>(&'a self) -> crate::IO<Box<dyn Connection + 'a>>;
+
+    /// The current usage of the connection pool, if the connector pools its connections.
+    fn pool_metrics(&self) -> Option<PoolMetrics> {
+        None
+    }
+}
+
+/// A snapshot of the usage of a connection pool.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PoolMetrics {
+    /// The maximum number of open connections, if configured.
+    pub connection_limit: Option<usize>,
+
+    /// Connections currently in use.
+    pub checked_out: usize,
+
+    /// Open connections not in use.
+    pub idle: usize,
+
+    /// Requests waiting for a connection.
+    pub waiters: usize,
 }
 
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
@@ -51,8 +76,34 @@ pub trait ReadOperations {
         record_ids: Vec<GraphqlId>,
     ) -> crate::IO<'a, Vec<ScalarListValues>>;
 
-    // This will eventually become a more generic `aggregate`
-    fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize>;
+    /// Counts the records related to each of the given records through the relation field, optionally
+    /// only counting the related records matching the filter. Records without related records are omitted.
+    fn count_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        from_record_ids: &'a [GraphqlId],
+        filter: Option<Filter>,
+    ) -> crate::IO<'a, Vec<RelatedRecordsCount>>;
+
+    /// Computes the given aggregations over the records of the model matching the query arguments.
+    /// Returns the results in the order of the aggregators.
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>>;
+
+    /// Groups the records of the model matching the query arguments by the given fields and computes
+    /// the given aggregations per group. Groups not matching the `having` filter are discarded.
+    fn group_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        having: Option<HavingFilter>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<RecordGroup>>;
 }
 
 impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
@@ -106,11 +157,41 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    // This will eventually become a more generic `aggregate`
-    fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize> {
+    fn count_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        from_record_ids: &'a [GraphqlId],
+        filter: Option<Filter>,
+    ) -> crate::IO<'a, Vec<RelatedRecordsCount>> {
+        match self {
+            Self::Connection(c) => c.count_related_records(from_field, from_record_ids, filter),
+            Self::Transaction(tx) => tx.count_related_records(from_field, from_record_ids, filter),
+        }
+    }
+
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>> {
+        match self {
+            Self::Connection(c) => c.aggregate_records(model, aggregators, query_arguments),
+            Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments),
+        }
+    }
+
+    fn group_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        having: Option<HavingFilter>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<RecordGroup>> {
         match self {
-            Self::Connection(c) => c.count_by_model(model, query_arguments),
-            Self::Transaction(tx) => tx.count_by_model(model, query_arguments),
+            Self::Connection(c) => c.group_records(model, group_by, aggregators, having, query_arguments),
+            Self::Transaction(tx) => tx.group_records(model, group_by, aggregators, having, query_arguments),
         }
     }
 }
@@ -121,13 +202,46 @@ pub struct ScalarListValues {
     pub values: Vec<PrismaValue>,
 }
 
+/// The number of records related to a record through a relation field.
+#[derive(Debug, Clone)]
+pub struct RelatedRecordsCount {
+    pub record_id: GraphqlId,
+    pub count: usize,
+}
+
 pub trait WriteOperations {
     fn create_record<'a>(&'a self, model: &'a ModelRef, args: WriteArgs) -> crate::IO<GraphqlId>;
 
+    /// Creates many records at once. Returns the number of created records, which is smaller than the number
+    /// of given records if duplicates (records violating a unique constraint) are skipped.
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::IO<usize>;
+
     fn update_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<Vec<GraphqlId>>;
 
+    /// Updates all records matching the filter without returning their ids. Returns the number of updated records.
+    fn update_many_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<usize>;
+
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> crate::IO<usize>;
 
+    /// Runs a raw query with the given parameters and returns the resulting rows as a JSON array of objects,
+    /// keyed by column name.
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value>;
+
+    /// Runs a raw query with parameters bound as they are, e.g. the `values` of a logged statement.
+    fn query_raw_values<'a>(
+        &'a self,
+        query: String,
+        parameters: Vec<ParameterizedValue<'static>>,
+    ) -> crate::IO<serde_json::Value>;
+
+    /// Runs a raw statement with the given parameters and returns the number of affected rows.
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize>;
+
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
     fn connect<'a>(
@@ -160,6 +274,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args, skip_duplicates),
+            Self::Transaction(tx) => tx.create_records(model, args, skip_duplicates),
+        }
+    }
+
     fn update_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<Vec<GraphqlId>> {
         match self {
             Self::Connection(c) => c.update_records(model, where_, args),
@@ -167,6 +293,13 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn update_many_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.update_many_records(model, where_, args),
+            Self::Transaction(tx) => tx.update_many_records(model, where_, args),
+        }
+    }
+
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> crate::IO<usize> {
         match self {
             Self::Connection(c) => c.delete_records(model, where_),
@@ -174,6 +307,31 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.query_raw(query, parameters),
+            Self::Transaction(tx) => tx.query_raw(query, parameters),
+        }
+    }
+
+    fn query_raw_values<'a>(
+        &'a self,
+        query: String,
+        parameters: Vec<ParameterizedValue<'static>>,
+    ) -> crate::IO<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.query_raw_values(query, parameters),
+            Self::Transaction(tx) => tx.query_raw_values(query, parameters),
+        }
+    }
+
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.execute_raw(query, parameters),
+            Self::Transaction(tx) => tx.execute_raw(query, parameters),
+        }
+    }
+
     fn connect<'a>(
         &'a self,
         field: &'a RelationFieldRef,
@@ -5,15 +5,19 @@ extern crate failure_derive;
 pub mod error;
 pub mod filter;
 
+mod aggregation;
 mod compare;
 mod interface;
 mod query_arguments;
+mod query_log;
 mod write_args;
 
+pub use aggregation::*;
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
+pub use query_log::*;
 pub use write_args::*;
 
 use futures::future::{BoxFuture, FutureExt};
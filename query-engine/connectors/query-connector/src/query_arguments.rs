@@ -15,7 +15,11 @@ pub struct QueryArguments {
     pub before: Option<GraphqlId>,
     pub last: Option<i64>,
     pub filter: Option<Filter>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
+
+    /// Only the first record (in order) of each combination of values of the fields is returned.
+    /// Pagination applies to the distinct records.
+    pub distinct: Option<Vec<ScalarFieldRef>>,
 }
 
 impl QueryArguments {
@@ -23,6 +27,20 @@ impl QueryArguments {
         self.last.or(self.first).or(self.skip).is_some()
     }
 
+    pub fn is_distinct(&self) -> bool {
+        self.distinct.is_some()
+    }
+
+    /// The arguments without skip and limits, e.g. to paginate the records after the query.
+    pub fn without_pagination(&self) -> Self {
+        QueryArguments {
+            skip: None,
+            first: None,
+            last: None,
+            ..self.clone()
+        }
+    }
+
     pub fn window_limits(&self) -> (i64, i64) {
         let skip = self.skip.unwrap_or(0) + 1;
 
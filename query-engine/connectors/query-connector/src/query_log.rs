@@ -0,0 +1,127 @@
+use quaint::ast::ParameterizedValue;
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+thread_local! {
+    /// The log of the request whose future is currently polled on this thread.
+    static CURRENT: RefCell<Option<QueryLog>> = RefCell::new(None);
+}
+
+/// A statement a connector sent to the database.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLogEntry {
+    pub query: String,
+    pub params: Vec<Value>,
+
+    /// The parameters as they were bound, to run the statement again, e.g. to explain it.
+    /// Never serialized, as they aren't redacted.
+    #[serde(skip)]
+    pub values: Vec<ParameterizedValue<'static>>,
+
+    pub duration_ms: f64,
+
+    /// Rows returned or affected by the statement, if known.
+    pub rows: Option<usize>,
+}
+
+/// Collects the statements executed for a single request.
+///
+/// Connectors don't get the log passed explicitly: running the request with `QueryLog::scope` makes the log
+/// available through `QueryLog::current` to everything executed while the request future is polled.
+#[derive(Debug, Clone)]
+pub struct QueryLog {
+    request_id: Arc<String>,
+    redact_params: bool,
+    entries: Arc<Mutex<Vec<QueryLogEntry>>>,
+}
+
+impl QueryLog {
+    pub fn new(request_id: String, redact_params: bool) -> Self {
+        Self {
+            request_id: Arc::new(request_id),
+            redact_params,
+            entries: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// The log of the request currently executed on this thread, if logging is enabled for it.
+    pub fn current() -> Option<QueryLog> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Runs the future with this log in scope.
+    pub fn scope<F: Future>(self, future: F) -> Scoped<F> {
+        Scoped {
+            log: self,
+            inner: Box::pin(future),
+        }
+    }
+
+    pub fn request_id(&self) -> &str {
+        self.request_id.as_str()
+    }
+
+    pub fn record(
+        &self,
+        query: String,
+        values: Vec<ParameterizedValue<'static>>,
+        duration: Duration,
+        rows: Option<usize>,
+    ) {
+        let params = if self.redact_params {
+            values.iter().map(|_| Value::String("[redacted]".into())).collect()
+        } else {
+            values.iter().cloned().map(Value::from).collect()
+        };
+
+        let entry = QueryLogEntry {
+            query,
+            params,
+            values,
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            rows,
+        };
+
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    pub fn entries(&self) -> Vec<QueryLogEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+/// A future running with a `QueryLog` in scope, see `QueryLog::scope`.
+pub struct Scoped<F> {
+    log: QueryLog,
+    inner: Pin<Box<F>>,
+}
+
+/// Restores the log that was in scope before polling a `Scoped` future, also if polling panics.
+struct Restore(Option<QueryLog>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let previous = CURRENT.with(|current| current.replace(Some(self.log.clone())));
+        let _restore = Restore(previous);
+
+        self.inner.as_mut().poll(ctx)
+    }
+}
@@ -3,6 +3,27 @@ use prisma_models::*;
 pub struct WriteArgs {
     non_list_args: PrismaArgs,
     list_args: Vec<(String, PrismaListValue)>,
+    json_updates: Vec<JsonUpdate>,
+}
+
+/// A change of the value at a path inside of a Json field, leaving the rest of the document as it is.
+#[derive(Debug, Clone)]
+pub struct JsonUpdate {
+    pub field: String,
+
+    /// Object keys and array indexes leading to the changed value. Empty for the whole document.
+    pub path: Vec<String>,
+    pub operation: JsonUpdateOperation,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonUpdateOperation {
+    /// Replaces the value at the path. The last key of the path is added to its object if it is missing.
+    Set(PrismaValue),
+
+    /// Merges the keys of the given object into the object at the path, which is created if it doesn't exist.
+    /// Nested objects are merged recursively and null values remove keys (RFC 7396).
+    Merge(PrismaValue),
 }
 
 impl WriteArgs {
@@ -10,9 +31,15 @@ impl WriteArgs {
         WriteArgs {
             non_list_args,
             list_args,
+            json_updates: Vec::new(),
         }
     }
 
+    pub fn with_json_updates(mut self, json_updates: Vec<JsonUpdate>) -> WriteArgs {
+        self.json_updates = json_updates;
+        self
+    }
+
     pub fn non_list_args(&self) -> &PrismaArgs {
         &self.non_list_args
     }
@@ -20,4 +47,8 @@ impl WriteArgs {
     pub fn list_args(&self) -> &Vec<(String, PrismaListValue)> {
         &self.list_args
     }
+
+    pub fn json_updates(&self) -> &Vec<JsonUpdate> {
+        &self.json_updates
+    }
 }
@@ -0,0 +1,231 @@
+//! Aggregations over the values of records (`count`, `sum`, `avg`, `min` and `max`) and the `having` filters on them.
+//!
+//! Quaint has no aggregate functions besides `COUNT`, so the values of the aggregated fields are read and aggregated
+//! here, see `aggregate_records` and `group_records`.
+use crate::SqlError;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use connector_interface::{filter::ScalarCondition, AggregateValue, AggregationResult, Aggregator, HavingFilter};
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use std::{cmp::Ordering, io};
+
+/// The values of the records to aggregate, one row per record. The rows hold the values of `fields` in order.
+pub struct AggregationRows<'a> {
+    pub fields: &'a [ScalarFieldRef],
+    pub rows: Vec<Vec<PrismaValue>>,
+}
+
+impl<'a> AggregationRows<'a> {
+    pub fn new(fields: &'a [ScalarFieldRef]) -> Self {
+        Self { fields, rows: vec![] }
+    }
+
+    /// The results of the aggregators, one per field of aggregators computing values for multiple fields.
+    pub fn aggregate(&self, aggregators: &[Aggregator]) -> crate::Result<Vec<AggregationResult>> {
+        let mut results = Vec::with_capacity(aggregators.len());
+
+        for aggregator in aggregators {
+            for value in aggregator.values() {
+                let result = match value {
+                    AggregateValue::Count => AggregationResult::Count(self.rows.len()),
+                    AggregateValue::Sum(ref field) => AggregationResult::Sum(field.clone(), self.value(&value)?),
+                    AggregateValue::Average(ref field) => {
+                        AggregationResult::Average(field.clone(), self.value(&value)?)
+                    }
+                    AggregateValue::Min(ref field) => AggregationResult::Min(field.clone(), self.value(&value)?),
+                    AggregateValue::Max(ref field) => AggregationResult::Max(field.clone(), self.value(&value)?),
+                };
+
+                results.push(result);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Whether the aggregated values match the filter. As in SQL, comparisons with null values match nothing,
+    /// not even when negated.
+    pub fn matches(&self, filter: &HavingFilter) -> crate::Result<bool> {
+        Ok(self.evaluate(filter)? == Some(true))
+    }
+
+    /// The aggregated value. Null values are ignored, the value is null if there are no values to aggregate.
+    fn value(&self, value: &AggregateValue) -> crate::Result<PrismaValue> {
+        let field = match value {
+            AggregateValue::Count => return Ok(PrismaValue::Int(self.rows.len() as i64)),
+            AggregateValue::Sum(field)
+            | AggregateValue::Average(field)
+            | AggregateValue::Min(field)
+            | AggregateValue::Max(field) => field,
+        };
+
+        // UNWRAP: The aggregated fields are always read, see `aggregated_fields`.
+        let position = self.fields.iter().position(|f| f.name == field.name).unwrap();
+        let values = self
+            .rows
+            .iter()
+            .map(|row| &row[position])
+            .filter(|value| !value.is_null());
+
+        match value {
+            AggregateValue::Sum(_) => values.fold(Ok(PrismaValue::Null), |sum, value| add(sum?, value)),
+            AggregateValue::Average(_) => {
+                let numbers = values.map(to_float).collect::<crate::Result<Vec<f64>>>()?;
+
+                match numbers.len() {
+                    0 => Ok(PrismaValue::Null),
+                    len => Ok(PrismaValue::Float(numbers.iter().sum::<f64>() / len as f64)),
+                }
+            }
+            AggregateValue::Min(_) => Ok(extreme(values, Ordering::Less)),
+            AggregateValue::Max(_) => Ok(extreme(values, Ordering::Greater)),
+            AggregateValue::Count => unreachable!(),
+        }
+    }
+
+    /// Evaluates the filter with the three-valued logic of SQL, `None` being unknown.
+    fn evaluate(&self, filter: &HavingFilter) -> crate::Result<Option<bool>> {
+        match filter {
+            HavingFilter::And(filters) => filters.iter().try_fold(Some(true), |acc, filter| {
+                Ok(match (acc, self.evaluate(filter)?) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                })
+            }),
+            HavingFilter::Or(filters) => filters.iter().try_fold(Some(false), |acc, filter| {
+                Ok(match (acc, self.evaluate(filter)?) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                })
+            }),
+            HavingFilter::Not(filters) => {
+                let negated = filters.iter().cloned().map(|filter| HavingFilter::Not(vec![filter]));
+                let not = |filter: &HavingFilter| self.evaluate(filter).map(|result| result.map(|b| !b));
+
+                match filters.len() {
+                    1 => not(&filters[0]),
+                    _ => self.evaluate(&HavingFilter::And(negated.collect())),
+                }
+            }
+            HavingFilter::Condition(value, condition) => Ok(compare(&self.value(value)?, condition)),
+        }
+    }
+}
+
+/// The fields whose values are aggregated by the aggregators or compared by the filter, without duplicates.
+pub fn aggregated_fields(aggregators: &[Aggregator], having: Option<&HavingFilter>) -> Vec<ScalarFieldRef> {
+    let mut fields: Vec<ScalarFieldRef> = aggregators
+        .iter()
+        .flat_map(|aggregator| aggregator.fields().to_vec())
+        .collect();
+
+    if let Some(having) = having {
+        collect_having_fields(having, &mut fields);
+    }
+
+    fields.into_iter().fold(vec![], |mut acc, field| {
+        if acc.iter().all(|f: &ScalarFieldRef| f.name != field.name) {
+            acc.push(field);
+        }
+
+        acc
+    })
+}
+
+fn collect_having_fields(filter: &HavingFilter, fields: &mut Vec<ScalarFieldRef>) {
+    match filter {
+        HavingFilter::And(filters) | HavingFilter::Or(filters) | HavingFilter::Not(filters) => {
+            filters.iter().for_each(|filter| collect_having_fields(filter, fields))
+        }
+        HavingFilter::Condition(AggregateValue::Count, _) => (),
+        HavingFilter::Condition(AggregateValue::Sum(field), _)
+        | HavingFilter::Condition(AggregateValue::Average(field), _)
+        | HavingFilter::Condition(AggregateValue::Min(field), _)
+        | HavingFilter::Condition(AggregateValue::Max(field), _) => fields.push(field.clone()),
+    }
+}
+
+fn compare(value: &PrismaValue, condition: &ScalarCondition) -> Option<bool> {
+    let is = |compared: &PrismaValue, orderings: &[Ordering]| match (value, compared) {
+        (PrismaValue::Null, _) | (_, PrismaValue::Null) => None,
+        (value, compared) => order(value, compared).map(|ordering| orderings.contains(&ordering)),
+    };
+
+    match condition {
+        ScalarCondition::Equals(PrismaValue::Null) => Some(value.is_null()),
+        ScalarCondition::NotEquals(PrismaValue::Null) => Some(!value.is_null()),
+        ScalarCondition::Equals(compared) => is(compared, &[Ordering::Equal]),
+        ScalarCondition::NotEquals(compared) => is(compared, &[Ordering::Less, Ordering::Greater]),
+        ScalarCondition::LessThan(compared) => is(compared, &[Ordering::Less]),
+        ScalarCondition::LessThanOrEquals(compared) => is(compared, &[Ordering::Less, Ordering::Equal]),
+        ScalarCondition::GreaterThan(compared) => is(compared, &[Ordering::Greater]),
+        ScalarCondition::GreaterThanOrEquals(compared) => is(compared, &[Ordering::Greater, Ordering::Equal]),
+        ScalarCondition::In(None) => Some(value.is_null()),
+        ScalarCondition::NotIn(None) => Some(!value.is_null()),
+        ScalarCondition::In(Some(values)) => values.iter().try_fold(false, |acc, compared| {
+            is(compared, &[Ordering::Equal]).map(|equal| acc || equal)
+        }),
+        ScalarCondition::NotIn(Some(values)) => values.iter().try_fold(true, |acc, compared| {
+            is(compared, &[Ordering::Less, Ordering::Greater]).map(|unequal| acc && unequal)
+        }),
+        _ => unreachable!(), // Other conditions are rejected when the having filter is extracted.
+    }
+}
+
+/// Orders numbers by value and DateTimes by time, other values can't be ordered.
+fn order(left: &PrismaValue, right: &PrismaValue) -> Option<Ordering> {
+    match (left, right) {
+        (PrismaValue::Int(l), PrismaValue::Int(r)) | (PrismaValue::BigInt(l), PrismaValue::BigInt(r)) => Some(l.cmp(r)),
+        (PrismaValue::Decimal(l), PrismaValue::Decimal(r)) => l.partial_cmp(r),
+        (PrismaValue::DateTime(l), PrismaValue::DateTime(r)) => Some(l.cmp(r)),
+        (l, r) => to_float(l).ok()?.partial_cmp(&to_float(r).ok()?),
+    }
+}
+
+fn extreme<'a>(values: impl Iterator<Item = &'a PrismaValue>, wanted: Ordering) -> PrismaValue {
+    values
+        .fold(None, |acc: Option<&PrismaValue>, value| match acc {
+            Some(acc) if order(value, acc) != Some(wanted) => Some(acc),
+            _ => Some(value),
+        })
+        .cloned()
+        .unwrap_or(PrismaValue::Null)
+}
+
+fn add(sum: PrismaValue, value: &PrismaValue) -> crate::Result<PrismaValue> {
+    match (sum, value) {
+        (PrismaValue::Null, value) => Ok(value.clone()),
+        (PrismaValue::Int(sum), PrismaValue::Int(value)) => sum
+            .checked_add(*value)
+            .map(PrismaValue::Int)
+            .ok_or_else(|| conversion_error("The sum is out of the range of a 64 bit integer.")),
+        (PrismaValue::BigInt(sum), PrismaValue::BigInt(value)) => sum
+            .checked_add(*value)
+            .map(PrismaValue::BigInt)
+            .ok_or_else(|| conversion_error("The sum is out of the range of a 64 bit integer.")),
+        (PrismaValue::Float(sum), PrismaValue::Float(value)) => Ok(PrismaValue::Float(sum + value)),
+        (PrismaValue::Decimal(sum), PrismaValue::Decimal(value)) => Ok(PrismaValue::Decimal(sum + value)),
+        (_, value) => Err(conversion_error(&format!("Cannot sum up the value {}.", value))),
+    }
+}
+
+fn to_float(value: &PrismaValue) -> crate::Result<f64> {
+    match value {
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => Ok(*i as f64),
+        PrismaValue::Float(f) => Ok(*f),
+        PrismaValue::Decimal(d) => decimal_to_float(d),
+        value => Err(conversion_error(&format!("Cannot average the value {}.", value))),
+    }
+}
+
+fn decimal_to_float(decimal: &BigDecimal) -> crate::Result<f64> {
+    decimal
+        .to_f64()
+        .ok_or_else(|| conversion_error(&format!("The decimal {} doesn't fit into a float.", decimal)))
+}
+
+fn conversion_error(message: &str) -> SqlError {
+    let error = io::Error::new(io::ErrorKind::InvalidData, message);
+    SqlError::ConversionError(error.into())
+}
@@ -1,7 +1,7 @@
+use crate::ordering::Ordering;
 use connector_interface::QueryArguments;
 use prisma_models::prelude::*;
 use quaint::ast::*;
-use std::sync::Arc;
 
 #[derive(Clone, Copy)]
 enum CursorType {
@@ -11,72 +11,173 @@ enum CursorType {
 
 pub struct CursorCondition;
 
+/// Builds the conditions for cursor based pagination (`before` / `after`).
+///
+/// With the orderings `[f1, f2, ..., id]`, a record comes after the cursor record if it compares
+/// greater in lexicographic order, i.e.:
+/// `(f1 > c1) OR (f1 = c1 AND f2 > c2) OR ... OR (f1 = c1 AND f2 = c2 AND ... AND id > cursor)`,
+/// where `cn` is the value of `fn` of the cursor record, and `>` flips to `<` for descending orderings.
+/// The id fields are always the last orderings to guarantee a total order, as it's done in `Ordering`.
+/// The cursor of a model with a compound id holds the values of all id fields, which are compared one after another.
+/// Orderings on related records compare the same correlated subselects `Ordering` orders by.
 impl CursorCondition {
     pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree<'static> {
-        match (
-            query_arguments.before.as_ref(),
-            query_arguments.after.as_ref(),
-            query_arguments.order_by.as_ref(),
-        ) {
-            (None, None, _) => ConditionTree::NoCondition,
-            (before, after, order_by) => {
-                let field = match order_by {
-                    Some(order) => Arc::clone(&order.field),
-                    None => model.fields().id(),
-                };
-
-                let sort_order: SortOrder = order_by.map(|order| order.sort_order).unwrap_or(SortOrder::Ascending);
-
-                let cursor_for = |cursor_type: CursorType, id: GraphqlId| {
-                    let model_id = model.fields().id();
-                    let where_condition = model_id.as_column().equals(id.clone());
-
-                    let select_query = Select::from_table(model.table())
-                        .column(field.as_column())
-                        .so_that(ConditionTree::single(where_condition));
-
-                    let compare = match (cursor_type, sort_order) {
-                        (CursorType::Before, SortOrder::Ascending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().less_than(id))
-                            .or(field.as_column().less_than(select_query)),
-                        (CursorType::Before, SortOrder::Descending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().less_than(id))
-                            .or(field.as_column().greater_than(select_query)),
-                        (CursorType::After, SortOrder::Ascending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().greater_than(id))
-                            .or(field.as_column().greater_than(select_query)),
-                        (CursorType::After, SortOrder::Descending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().greater_than(id))
-                            .or(field.as_column().less_than(select_query)),
-                    };
-
-                    ConditionTree::single(compare)
-                };
+        match (query_arguments.before.as_ref(), query_arguments.after.as_ref()) {
+            (None, None) => ConditionTree::NoCondition,
+            (before, after) => {
+                let order_by = &query_arguments.order_by;
 
                 let after_cursor = after
-                    .map(|id| {
-                        let id_val = id.clone();
-                        cursor_for(CursorType::After, id_val)
-                    })
+                    .map(|id| Self::cursor_for(CursorType::After, id.clone(), order_by, &model))
                     .unwrap_or(ConditionTree::NoCondition);
 
                 let before_cursor = before
-                    .map(|id| {
-                        let id_val = id.clone();
-                        cursor_for(CursorType::Before, id_val)
-                    })
+                    .map(|id| Self::cursor_for(CursorType::Before, id.clone(), order_by, &model))
                     .unwrap_or(ConditionTree::NoCondition);
 
                 ConditionTree::and(after_cursor, before_cursor)
             }
         }
     }
+
+    fn cursor_for(
+        cursor_type: CursorType,
+        id: GraphqlId,
+        order_by: &[OrderBy],
+        model: &ModelRef,
+    ) -> ConditionTree<'static> {
+        let mut ids: Vec<(ScalarFieldRef, GraphqlId)> = model.id_fields().into_iter().zip(id.into_parts()).collect();
+        let cursor_record = Self::id_condition(&ids);
+
+        let mut conditions: Vec<ConditionTree<'static>> = Vec::with_capacity(order_by.len() + ids.len());
+        let mut equalities: Vec<ConditionTree<'static>> = Vec::with_capacity(order_by.len() + ids.len());
+
+        for oby in order_by {
+            // Once all id fields are compared, the orderings are unique and further orderings have no effect.
+            if ids.is_empty() {
+                break;
+            }
+
+            let id_position = match oby.field.as_scalar() {
+                Some(sf) => ids.iter().position(|(field, _)| field.name == sf.name),
+                None => None,
+            };
+
+            match id_position {
+                Some(position) => {
+                    let (field, value) = ids.remove(position);
+
+                    conditions.push(Self::conjunct(
+                        equalities.clone(),
+                        Self::compare_id(cursor_type, oby.sort_order, &field, value.clone()),
+                    ));
+                    equalities.push(ConditionTree::single(field.as_column().equals(value)));
+                }
+                None => {
+                    let cursor_value = Select::from_table(model.table())
+                        .value(Ordering::value(&oby.field))
+                        .so_that(cursor_record.clone());
+
+                    let compare = Self::compare(cursor_type, oby, cursor_value.clone());
+                    conditions.push(Self::conjunct(equalities.clone(), compare));
+                    equalities.push(Self::equals(oby, cursor_value));
+                }
+            }
+        }
+
+        // The id fields that aren't ordered explicitly are ordered ascending after all orderings, see `Ordering`.
+        for (field, value) in ids {
+            conditions.push(Self::conjunct(
+                equalities.clone(),
+                Self::compare_id(cursor_type, SortOrder::Ascending, &field, value.clone()),
+            ));
+            equalities.push(ConditionTree::single(field.as_column().equals(value)));
+        }
+
+        let mut conditions = conditions.into_iter();
+        let first = conditions.next().unwrap();
+
+        conditions.fold(first, |acc, condition| acc.or(condition).into())
+    }
+
+    /// `id = cursor`, or `a = ? AND b = ?` for the parts of a compound id.
+    fn id_condition(ids: &[(ScalarFieldRef, GraphqlId)]) -> ConditionTree<'static> {
+        let mut conditions = ids
+            .iter()
+            .map(|(field, value)| ConditionTree::single(field.as_column().equals(value.clone())));
+
+        let first = conditions.next().unwrap();
+        conditions.fold(first, |acc, condition| acc.and(condition).into())
+    }
+
+    /// `id > cursor` (or `<`, depending on cursor type and sort order). Ids are never null.
+    fn compare_id(
+        cursor_type: CursorType,
+        sort_order: SortOrder,
+        field: &ScalarFieldRef,
+        value: GraphqlId,
+    ) -> ConditionTree<'static> {
+        let compare = match (cursor_type, sort_order) {
+            (CursorType::After, SortOrder::Ascending) | (CursorType::Before, SortOrder::Descending) => {
+                field.as_column().greater_than(value)
+            }
+            (CursorType::Before, SortOrder::Ascending) | (CursorType::After, SortOrder::Descending) => {
+                field.as_column().less_than(value)
+            }
+        };
+
+        ConditionTree::single(compare)
+    }
+
+    /// `field > cursor_value` (or `<`, depending on cursor type and sort order).
+    /// If the ordering defines the position of nulls explicitly, nulls are taken into account:
+    /// E.g. with nulls last, a null value comes after any non-null cursor value.
+    fn compare(cursor_type: CursorType, oby: &OrderBy, cursor_value: Select<'static>) -> ConditionTree<'static> {
+        let value = Ordering::value(&oby.field);
+
+        let compare = match (cursor_type, Ordering::sort_order(oby)) {
+            (CursorType::After, SortOrder::Ascending) | (CursorType::Before, SortOrder::Descending) => {
+                value.clone().greater_than(cursor_value.clone())
+            }
+            (CursorType::Before, SortOrder::Ascending) | (CursorType::After, SortOrder::Descending) => {
+                value.clone().less_than(cursor_value.clone())
+            }
+        };
+
+        let cursor_value = DatabaseValue::from(cursor_value);
+
+        match (cursor_type, oby.null_ordering) {
+            (_, None) => ConditionTree::single(compare),
+
+            // Null records are on the requested side of any non-null cursor value.
+            (CursorType::After, Some(NullOrdering::Last)) | (CursorType::Before, Some(NullOrdering::First)) => {
+                compare.or(value.is_null().and(cursor_value.is_not_null())).into()
+            }
+
+            // All non-null records are on the requested side of a null cursor value.
+            (CursorType::After, Some(NullOrdering::First)) | (CursorType::Before, Some(NullOrdering::Last)) => {
+                compare.or(value.is_not_null().and(cursor_value.is_null())).into()
+            }
+        }
+    }
+
+    /// `field = cursor_value`, treating nulls as equal if the ordering defines the position of nulls.
+    fn equals(oby: &OrderBy, cursor_value: Select<'static>) -> ConditionTree<'static> {
+        let value = Ordering::value(&oby.field);
+        let equals = value.clone().equals(cursor_value.clone());
+
+        match oby.null_ordering {
+            Some(_) => equals
+                .or(value.is_null().and(DatabaseValue::from(cursor_value).is_null()))
+                .into(),
+            None => ConditionTree::single(equals),
+        }
+    }
+
+    fn conjunct(equalities: Vec<ConditionTree<'static>>, condition: ConditionTree<'static>) -> ConditionTree<'static> {
+        equalities
+            .into_iter()
+            .rev()
+            .fold(condition, |acc, equality| equality.and(acc).into())
+    }
 }
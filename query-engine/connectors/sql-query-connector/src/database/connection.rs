@@ -1,15 +1,21 @@
-use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, query_builder::read::ManyRelatedRecordsQueryBuilder, QueryExt, SqlError};
+use super::{transaction::SqlConnectorTransaction, CheckedOut, ConnectionSettings};
+use crate::{
+    database::operations::*, metrics::metered, query_builder::read::ManyRelatedRecordsQueryBuilder, QueryExt, SqlError,
+};
 use connector_interface::{
     self as connector,
     filter::{Filter, RecordFinder},
-    Connection, QueryArguments, ReadOperations, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO
+    AggregationResult, Aggregator, Connection, HavingFilter, QueryArguments, ReadOperations, RecordGroup,
+    RelatedRecordsCount, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO
 };
 use prisma_models::prelude::*;
+use quaint::ast::ParameterizedValue;
 use std::marker::PhantomData;
 
 pub struct SqlConnection<C, T> {
     inner: C,
+    settings: ConnectionSettings,
+    _checked_out: CheckedOut,
     _p: PhantomData<T>,
 }
 
@@ -18,8 +24,13 @@ where
     C: QueryExt + Send + Sync + 'static,
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
-    pub fn new(inner: C) -> Self {
-        Self { inner, _p: PhantomData }
+    pub fn new(inner: C, checked_out: CheckedOut, settings: ConnectionSettings) -> Self {
+        Self {
+            inner,
+            settings,
+            _checked_out: checked_out,
+            _p: PhantomData,
+        }
     }
 }
 
@@ -34,7 +45,7 @@ where
         IO::new(async move {
             let tx: quaint::connector::Transaction<'a> = fut_tx.await.map_err(SqlError::from)?;
 
-            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx)) as Box<dyn Transaction<'a> + 'a>)
+            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx, self.settings)) as Box<dyn Transaction<'a> + 'a>)
         })
     }
 }
@@ -49,7 +60,9 @@ where
         record_finder: &'b RecordFinder,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(async move { read::get_single_record(&self.inner, record_finder, selected_fields).await })
+        metered(self.settings.family, "get_single_record", async move {
+            read::get_single_record(&self.inner, record_finder, selected_fields, self.settings).await
+        })
     }
 
     fn get_many_records<'b>(
@@ -58,7 +71,9 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        metered(self.settings.family, "get_many_records", async move {
+            read::get_many_records(&self.inner, model, query_arguments, selected_fields, self.settings).await
+        })
     }
 
     fn get_related_records<'b>(
@@ -68,13 +83,14 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move {
+        metered(self.settings.family, "get_related_records", async move {
             read::get_related_records::<T>(
                 &self.inner,
                 from_field,
                 from_record_ids,
                 query_arguments,
                 selected_fields,
+                self.settings,
             )
             .await
         })
@@ -85,11 +101,53 @@ where
         list_field: &'b ScalarFieldRef,
         record_ids: Vec<GraphqlId>,
     ) -> connector::IO<'b, Vec<ScalarListValues>> {
-        IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
+        metered(self.settings.family, "get_scalar_list_values", async move {
+            read::get_scalar_list_values(&self.inner, list_field, record_ids, self.settings).await
+        })
+    }
+
+    fn count_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        from_record_ids: &'b [GraphqlId],
+        filter: Option<Filter>,
+    ) -> connector::IO<'b, Vec<RelatedRecordsCount>> {
+        metered(self.settings.family, "count_related_records", async move {
+            read::count_related_records(&self.inner, from_field, from_record_ids, filter, self.settings).await
+        })
     }
 
-    fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(async move { read::count_by_model(&self.inner, model, query_arguments).await })
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        metered(self.settings.family, "aggregate_records", async move {
+            read::aggregate_records(&self.inner, model, aggregators, query_arguments, self.settings).await
+        })
+    }
+
+    fn group_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        having: Option<HavingFilter>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<RecordGroup>> {
+        metered(self.settings.family, "group_records", async move {
+            read::group_records(
+                &self.inner,
+                model,
+                group_by,
+                aggregators,
+                having,
+                query_arguments,
+                self.settings,
+            )
+            .await
+        })
     }
 }
 
@@ -99,15 +157,58 @@ where
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
     fn create_record<'a>(&'a self, model: &'a ModelRef, args: WriteArgs) -> connector::IO<GraphqlId> {
-        IO::new(async move { write::create_record(&self.inner, model, args).await })
+        metered(self.settings.family, "create_record", async move {
+            write::create_record(&self.inner, model, args, self.settings).await
+        })
+    }
+
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::IO<usize> {
+        metered(self.settings.family, "create_records", async move {
+            write::create_records(&self.inner, model, args, skip_duplicates, self.settings).await
+        })
     }
 
     fn update_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> connector::IO<Vec<GraphqlId>> {
-        IO::new(async move { write::update_records(&self.inner, model, where_, args).await })
+        metered(self.settings.family, "update_records", async move {
+            write::update_records(&self.inner, model, where_, args, self.settings).await
+        })
+    }
+
+    fn update_many_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> connector::IO<usize> {
+        metered(self.settings.family, "update_many_records", async move {
+            write::update_many_records(&self.inner, model, where_, args, self.settings).await
+        })
     }
 
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(async move { write::delete_records(&self.inner, model, where_).await })
+        metered(self.settings.family, "delete_records", async move {
+            write::delete_records(&self.inner, model, where_, self.settings).await
+        })
+    }
+
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+        metered(self.settings.family, "query_raw", async move { raw::query_raw(&self.inner, query, parameters).await })
+    }
+
+    fn query_raw_values<'a>(
+        &'a self,
+        query: String,
+        parameters: Vec<ParameterizedValue<'static>>,
+    ) -> connector::IO<serde_json::Value> {
+        metered(self.settings.family, "query_raw", async move {
+            raw::query_raw_values(&self.inner, query, parameters).await
+        })
+    }
+
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
+        metered(self.settings.family, "execute_raw", async move {
+            raw::execute_raw(&self.inner, query, parameters).await
+        })
     }
 
     fn connect<'a>(
@@ -116,7 +217,9 @@ where
         parent_id: &'a GraphqlId,
         child_id: &'a GraphqlId,
     ) -> connector::IO<()> {
-        IO::new(async move { write::connect(&self.inner, field, parent_id, child_id).await })
+        metered(self.settings.family, "connect", async move {
+            write::connect(&self.inner, field, parent_id, child_id, self.settings).await
+        })
     }
 
     fn disconnect<'a>(
@@ -125,7 +228,9 @@ where
         parent_id: &'a GraphqlId,
         child_id: &'a GraphqlId,
     ) -> connector::IO<()> {
-        IO::new(async move { write::disconnect(&self.inner, field, parent_id, child_id).await })
+        metered(self.settings.family, "disconnect", async move {
+            write::disconnect(&self.inner, field, parent_id, child_id, self.settings).await
+        })
     }
 
     fn set<'a>(
@@ -134,6 +239,8 @@ where
         parent_id: GraphqlId,
         wheres: Vec<GraphqlId>,
     ) -> connector::IO<()> {
-        IO::new(async move { write::set(&self.inner, relation_field, parent_id, wheres).await })
+        metered(self.settings.family, "set", async move {
+            write::set(&self.inner, relation_field, parent_id, wheres, self.settings).await
+        })
     }
 }
@@ -1,6 +1,8 @@
 mod connection;
 mod mysql;
+mod pool;
 mod postgresql;
+mod settings;
 mod sqlite;
 mod transaction;
 
@@ -10,7 +12,9 @@ use connector_interface::Connector;
 use datamodel::Source;
 
 pub use mysql::*;
+pub use pool::*;
 pub use postgresql::*;
+pub use settings::*;
 pub use sqlite::*;
 
 pub trait FromSource {
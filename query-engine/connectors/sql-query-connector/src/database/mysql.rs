@@ -1,13 +1,14 @@
-use super::connection::SqlConnection;
-use crate::{query_builder::ManyRelatedRecordsWithUnionAll, FromSource, QueryExt, SqlError};
-use connector_interface::{Connection, Connector, IO};
+use super::{connection::SqlConnection, ConnectionPool, ConnectionSettings, PoolSettings, SqlFamily};
+use crate::{query_builder::ManyRelatedRecordsWithUnionAll, FromSource, QueryExt};
+use connector_interface::{Connection, Connector, PoolMetrics, IO};
 use datamodel::Source;
 use quaint::pool::{self, MysqlManager};
-use tokio_resource_pool::{CheckOut, Pool};
+use tokio_resource_pool::CheckOut;
 use url::Url;
 
 pub struct Mysql {
-    pool: Pool<MysqlManager>,
+    pool: ConnectionPool<MysqlManager>,
+    settings: ConnectionSettings,
 }
 
 impl QueryExt for CheckOut<MysqlManager> {}
@@ -15,19 +16,25 @@ impl QueryExt for CheckOut<MysqlManager> {}
 impl FromSource for Mysql {
     fn from_source(source: &dyn Source) -> crate::Result<Self> {
         let url = Url::parse(&source.url().value)?;
-        let pool = pool::mysql(url)?;
+        let settings = ConnectionSettings::new(SqlFamily::Mysql, &source.url().value)?;
+        let pool_settings = PoolSettings::new(&source.url().value)?;
+        let pool = ConnectionPool::new(pool_settings, move || Ok(pool::mysql(url.clone())?))?;
 
-        Ok(Mysql { pool })
+        Ok(Mysql { pool, settings })
     }
 }
 
 impl Connector for Mysql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn);
+            let (conn, checked_out) = self.pool.check_out().await?;
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn, checked_out, self.settings);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
     }
+
+    fn pool_metrics(&self) -> Option<PoolMetrics> {
+        Some(self.pool.metrics())
+    }
 }
@@ -1,2 +1,3 @@
+pub mod raw;
 pub mod read;
 pub mod write;
@@ -0,0 +1,28 @@
+use crate::{QueryExt, RawQuery};
+use prisma_models::PrismaValue;
+use quaint::ast::ParameterizedValue;
+use serde_json::Value;
+
+pub async fn query_raw(
+    conn: &dyn QueryExt,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> connector_interface::Result<Value> {
+    Ok(conn.raw_json(RawQuery::new(query, parameters)).await?)
+}
+
+pub async fn query_raw_values(
+    conn: &dyn QueryExt,
+    sql: String,
+    parameters: Vec<ParameterizedValue<'static>>,
+) -> connector_interface::Result<Value> {
+    Ok(conn.raw_json(RawQuery { sql, parameters }).await?)
+}
+
+pub async fn execute_raw(
+    conn: &dyn QueryExt,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> connector_interface::Result<usize> {
+    Ok(conn.raw_count(RawQuery::new(query, parameters)).await?)
+}
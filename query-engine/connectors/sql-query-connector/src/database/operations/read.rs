@@ -1,11 +1,21 @@
 use crate::{
+    aggregation::{aggregated_fields, AggregationRows},
     query_builder::read::{ManyRelatedRecordsBaseQuery, ManyRelatedRecordsQueryBuilder, ReadQueryBuilder},
-    QueryExt, SqlError,
+    ConnectionSettings, QueryExt, SqlError,
 };
 use connector_interface::{error::ConnectorError, *};
 use itertools::Itertools;
 use prisma_models::*;
-use std::convert::TryFrom;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    mem,
+    sync::Arc,
+};
+
+/// Records made distinct after reading them are read in batches of at least this size.
+const DISTINCT_BATCH_SIZE: usize = 1000;
 
 struct ScalarListElement {
     record_id: GraphqlId,
@@ -16,13 +26,14 @@ pub async fn get_single_record(
     conn: &dyn QueryExt,
     record_finder: &RecordFinder,
     selected_fields: &SelectedFields,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<Option<SingleRecord>> {
-    let model = record_finder.field.model();
+    let model = record_finder.model();
     let query = ReadQueryBuilder::get_records(&model, selected_fields, record_finder);
     let field_names = selected_fields.names();
     let idents = selected_fields.type_identifiers();
 
-    let record = (match conn.find(query, idents.as_slice()).await {
+    let record = (match conn.find(settings.family, query, idents.as_slice()).await {
         Ok(result) => Ok(Some(result)),
         Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
         Err(e) => Err(e),
@@ -38,13 +49,20 @@ pub async fn get_many_records(
     model: &ModelRef,
     query_arguments: QueryArguments,
     selected_fields: &SelectedFields,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<ManyRecords> {
     let field_names = selected_fields.names();
     let idents = selected_fields.type_identifiers();
+
+    if query_arguments.is_distinct() {
+        let records = get_distinct_records(conn, model, query_arguments, selected_fields, settings).await?;
+        return Ok(ManyRecords { records, field_names });
+    }
+
     let query = ReadQueryBuilder::get_records(model, selected_fields, query_arguments);
 
     let records = conn
-        .filter(query.into(), idents.as_slice())
+        .filter(settings.family, query.into(), idents.as_slice())
         .await?
         .into_iter()
         .map(Record::from)
@@ -53,12 +71,58 @@ pub async fn get_many_records(
     Ok(ManyRecords { records, field_names })
 }
 
+/// Reads distinct records, which are made distinct after reading them. For `first` and `skip`, records are read
+/// in batches in the requested order until the requested distinct records are complete. With `last`, the first record of a
+/// combination of values is only known after reading all records.
+async fn get_distinct_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &SelectedFields,
+    settings: ConnectionSettings,
+) -> connector_interface::Result<Vec<Record>> {
+    let idents = selected_fields.type_identifiers();
+    let mut distinct = DistinctRecords::new(&query_arguments, selected_fields);
+
+    let SkipAndLimit { skip, limit } = query_arguments.skip_and_limit();
+    let batch_size = match (query_arguments.last, limit) {
+        (None, Some(limit)) => Some((skip + limit).max(DISTINCT_BATCH_SIZE)),
+        _ => None,
+    };
+
+    let mut offset = 0;
+
+    loop {
+        let batch = QueryArguments {
+            skip: Some(offset as i64),
+            first: batch_size.map(|size| size as i64),
+            ..query_arguments.without_pagination()
+        };
+
+        let query = ReadQueryBuilder::get_records(model, selected_fields, batch);
+        let rows = conn.filter(settings.family, query.into(), idents.as_slice()).await?;
+        let row_count = rows.len();
+
+        offset += row_count;
+        rows.into_iter().for_each(|row| distinct.push(Record::from(row)));
+
+        match (batch_size, limit) {
+            // The query reads one record more than the batch size, fewer records are the last ones.
+            (Some(size), Some(limit)) if row_count > size && distinct.len() < skip + limit => continue,
+            _ => break,
+        }
+    }
+
+    Ok(distinct.paginate(&query_arguments))
+}
+
 pub async fn get_related_records<T>(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
     from_record_ids: &[GraphqlId],
     query_arguments: QueryArguments,
     selected_fields: &SelectedFields,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<ManyRecords>
 where
     T: ManyRelatedRecordsQueryBuilder,
@@ -66,6 +130,14 @@ where
     let idents = selected_fields.type_identifiers();
     let field_names = selected_fields.names();
 
+    // Related records are made distinct per parent after reading them, which requires all of them.
+    let distinct_arguments = query_arguments.clone();
+    let query_arguments = if query_arguments.is_distinct() {
+        query_arguments.without_pagination()
+    } else {
+        query_arguments
+    };
+
     let query = {
         let is_with_pagination = query_arguments.is_with_pagination();
         let base = ManyRelatedRecordsBaseQuery::new(from_field, from_record_ids, query_arguments, selected_fields);
@@ -78,7 +150,7 @@ where
     };
 
     let records: Result<Vec<Record>> = conn
-        .filter(query, idents.as_slice())
+        .filter(settings.family, query, idents.as_slice())
         .await?
         .into_iter()
         .map(|mut row| {
@@ -95,21 +167,31 @@ where
         })
         .collect();
 
-    Ok(ManyRecords {
-        records: records?,
-        field_names,
-    })
+    let records = if distinct_arguments.is_distinct() {
+        let mut distinct = DistinctRecords::new(&distinct_arguments, selected_fields);
+        records?.into_iter().for_each(|record| distinct.push(record));
+        distinct.paginate(&distinct_arguments)
+    } else {
+        records?
+    };
+
+    Ok(ManyRecords { records, field_names })
 }
 
 pub async fn get_scalar_list_values(
     conn: &dyn QueryExt,
     list_field: &ScalarFieldRef,
     record_ids: Vec<GraphqlId>,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<Vec<ScalarListValues>> {
     let type_identifier = list_field.type_identifier;
     let query = ReadQueryBuilder::get_scalar_list_values_by_record_ids(list_field, record_ids);
     let rows = conn
-        .filter(query.into(), &[TypeIdentifier::GraphQLID, type_identifier])
+        .filter(
+            settings.family,
+            query.into(),
+            &[TypeIdentifier::GraphQLID, type_identifier],
+        )
         .await?;
 
     let results: Vec<ScalarListElement> = rows
@@ -140,13 +222,260 @@ pub async fn get_scalar_list_values(
     Ok(list_values)
 }
 
-pub async fn count_by_model(
+pub async fn count_related_records(
+    conn: &dyn QueryExt,
+    from_field: &RelationFieldRef,
+    from_record_ids: &[GraphqlId],
+    filter: Option<Filter>,
+    settings: ConnectionSettings,
+) -> connector_interface::Result<Vec<RelatedRecordsCount>> {
+    let queries = ReadQueryBuilder::count_related_records(settings.family, from_field, from_record_ids, filter);
+    let mut counts = Vec::new();
+
+    for query in queries {
+        let rows = conn
+            .filter(
+                settings.family,
+                query.into(),
+                &[TypeIdentifier::GraphQLID, TypeIdentifier::Int],
+            )
+            .await?;
+
+        for row in rows {
+            let mut iter = row.values.into_iter();
+
+            let record_id = iter.next().ok_or(SqlError::ColumnDoesNotExist)?;
+            let count = iter.next().ok_or(SqlError::ColumnDoesNotExist)?;
+
+            counts.push(RelatedRecordsCount {
+                record_id: GraphqlId::try_from(record_id)?,
+                count: i64::try_from(count)? as usize,
+            });
+        }
+    }
+
+    Ok(counts)
+}
+
+pub async fn aggregate_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+    settings: ConnectionSettings,
+) -> connector_interface::Result<Vec<AggregationResult>> {
+    let fields = aggregated_fields(&aggregators, None);
+    let mut selected_fields = SelectedFields::id(Arc::clone(model));
+
+    for field in fields.iter() {
+        if !field.is_id() {
+            selected_fields.add_scalar(Arc::clone(field));
+        }
+    }
+
+    let field_names = selected_fields.names();
+    let query = ReadQueryBuilder::aggregate(model, &selected_fields, query_arguments);
+    let rows = conn
+        .filter(settings.family, query.into(), &selected_fields.type_identifiers())
+        .await?;
+
+    // The aggregated values are read in the order of the aggregated fields.
+    let positions: Vec<usize> = fields
+        .iter()
+        .map(|field| field_names.iter().position(|name| name == &field.name).unwrap())
+        .collect();
+
+    let mut aggregation = AggregationRows::new(&fields);
+
+    for row in rows {
+        aggregation
+            .rows
+            .push(positions.iter().map(|position| row.values[*position].clone()).collect());
+    }
+
+    Ok(aggregation.aggregate(&aggregators)?)
+}
+
+pub async fn group_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    aggregators: Vec<Aggregator>,
+    having: Option<HavingFilter>,
     query_arguments: QueryArguments,
-) -> connector_interface::Result<usize> {
-    let query = ReadQueryBuilder::count_by_model(model, query_arguments);
-    let result = conn.find_int(query).await? as usize;
+    settings: ConnectionSettings,
+) -> connector_interface::Result<Vec<RecordGroup>> {
+    let skip = query_arguments.skip.unwrap_or(0) as usize;
+    let first = query_arguments.first.map(|first| first as usize);
+    let fields = aggregated_fields(&aggregators, having.as_ref());
+    let query = ReadQueryBuilder::group_by(model, &group_by, &fields, query_arguments);
+
+    // The grouped columns are selected first, followed by the aggregated fields.
+    let idents: Vec<TypeIdentifier> = group_by
+        .iter()
+        .chain(fields.iter())
+        .map(|field| field.type_identifier)
+        .collect();
+
+    let rows = conn.filter(settings.family, query.into(), idents.as_slice()).await?;
+    let mut positions: HashMap<DistinctKey, usize> = HashMap::new();
+    let mut groups: Vec<(Vec<PrismaValue>, AggregationRows)> = Vec::new();
+
+    // The groups are in the order of their first record.
+    for row in rows {
+        let mut values = row.values;
+        let aggregated_values = values.split_off(group_by.len());
 
-    Ok(result)
+        let position = *positions.entry(DistinctKey(values.clone())).or_insert_with(|| {
+            groups.push((values, AggregationRows::new(&fields)));
+            groups.len() - 1
+        });
+
+        groups[position].1.rows.push(aggregated_values);
+    }
+
+    let mut result = Vec::with_capacity(groups.len());
+
+    for (values, rows) in groups {
+        let is_included = match having {
+            Some(ref having) => rows.matches(having)?,
+            None => true,
+        };
+
+        if is_included {
+            result.push(RecordGroup {
+                values,
+                aggregations: rows.aggregate(&aggregators)?,
+            });
+        }
+    }
+
+    // Groups are paginated with `skip` and `first` only, the other pagination arguments aren't offered.
+    Ok(result
+        .into_iter()
+        .skip(skip)
+        .take(first.unwrap_or(usize::MAX))
+        .collect())
+}
+
+/// The first record of each combination of values of the distinct fields per parent, in the order they are pushed
+/// (`DISTINCT ON` semantics).
+struct DistinctRecords {
+    positions: Vec<usize>,
+    seen: HashSet<(Option<GraphqlId>, DistinctKey)>,
+    records: Vec<Record>,
+}
+
+impl DistinctRecords {
+    fn new(query_arguments: &QueryArguments, selected_fields: &SelectedFields) -> Self {
+        let field_names = selected_fields.names();
+
+        // UNWRAP: The distinct fields are always selected, see `inject_required_fields` of the core.
+        let positions = query_arguments
+            .distinct
+            .iter()
+            .flatten()
+            .map(|field| field_names.iter().position(|name| name == &field.name).unwrap())
+            .collect();
+
+        Self {
+            positions,
+            seen: HashSet::new(),
+            records: Vec::new(),
+        }
+    }
+
+    /// Keeps the record if it's the first with its values.
+    fn push(&mut self, record: Record) {
+        let values = self
+            .positions
+            .iter()
+            .map(|position| record.values[*position].clone())
+            .collect();
+        let key = (record.parent_id.clone(), DistinctKey(values));
+
+        if self.seen.insert(key) {
+            self.records.push(record);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Paginates the distinct records per parent. The records are returned the way connectors return paginated
+    /// records, e.g. reversed for `last`.
+    fn paginate(self, query_arguments: &QueryArguments) -> Vec<Record> {
+        let SkipAndLimit { skip, limit } = query_arguments.skip_and_limit();
+        let mut parents: HashMap<Option<GraphqlId>, usize> = HashMap::new();
+        let mut records_by_parent: Vec<Vec<Record>> = Vec::new();
+
+        for record in self.records {
+            let index = *parents.entry(record.parent_id.clone()).or_insert_with(|| {
+                records_by_parent.push(Vec::new());
+                records_by_parent.len() - 1
+            });
+
+            records_by_parent[index].push(record);
+        }
+
+        let mut records = Vec::new();
+
+        for mut parent_records in records_by_parent {
+            if query_arguments.last.is_some() {
+                parent_records.reverse();
+            }
+
+            records.extend(parent_records.into_iter().skip(skip).take(limit.unwrap_or(usize::MAX)));
+        }
+
+        records
+    }
+}
+
+/// The values of the distinct fields of a record, compared by value. `PrismaValue` can't implement `Eq` and `Hash`
+/// because of its floats, which are compared by their bits here.
+struct DistinctKey(Vec<PrismaValue>);
+
+impl PartialEq for DistinctKey {
+    fn eq(&self, other: &Self) -> bool {
+        values_equal(&self.0, &other.0)
+    }
+}
+
+impl Eq for DistinctKey {}
+
+impl Hash for DistinctKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.iter().for_each(|value| hash_value(value, state));
+    }
+}
+
+fn values_equal(left: &[PrismaValue], right: &[PrismaValue]) -> bool {
+    left.len() == right.len()
+        && left.iter().zip(right).all(|pair| match pair {
+            (PrismaValue::Float(l), PrismaValue::Float(r)) => l.to_bits() == r.to_bits(),
+            (PrismaValue::List(Some(l)), PrismaValue::List(Some(r))) => values_equal(l, r),
+            (l, r) => l == r,
+        })
+}
+
+fn hash_value<H: Hasher>(value: &PrismaValue, state: &mut H) {
+    mem::discriminant(value).hash(state);
+
+    match value {
+        PrismaValue::String(s) => s.hash(state),
+        PrismaValue::Float(f) => f.to_bits().hash(state),
+        PrismaValue::Decimal(d) => d.hash(state),
+        PrismaValue::Boolean(b) => b.hash(state),
+        PrismaValue::DateTime(dt) => dt.hash(state),
+        PrismaValue::Enum(e) => e.as_string().hash(state),
+        PrismaValue::Json(json) => json.to_string().hash(state),
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => i.hash(state),
+        PrismaValue::Bytes(bytes) => bytes.hash(state),
+        PrismaValue::Uuid(uuid) => uuid.hash(state),
+        PrismaValue::GraphqlId(id) => id.hash(state),
+        PrismaValue::List(Some(values)) => values.iter().for_each(|value| hash_value(value, state)),
+        PrismaValue::List(None) | PrismaValue::Null => (),
+    }
 }
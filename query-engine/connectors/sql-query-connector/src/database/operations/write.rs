@@ -1,41 +1,21 @@
-use crate::{error::SqlError, query_builder::WriteQueryBuilder, QueryExt};
+use crate::{error::SqlError, json, query_builder::WriteQueryBuilder, ConnectionSettings, QueryExt, SqlFamily};
 use connector_interface::{error::ConnectorError, *};
 use prisma_models::*;
 use quaint::error::Error as QueryError;
+use std::{convert::TryFrom, sync::Arc};
 
 pub async fn create_record(
     conn: &dyn QueryExt,
     model: &ModelRef,
     args: WriteArgs,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<GraphqlId> {
     let (insert, returned_id) = WriteQueryBuilder::create_record(model, args.non_list_args().clone());
 
-    let last_id = match conn.insert(insert).await {
-        Ok(id) => id,
-        Err(QueryError::UniqueConstraintViolation { field_name }) => {
-            if field_name == "PRIMARY" {
-                return Err(ConnectorError::UniqueConstraintViolation {
-                    field_name: format!("{}.{}", model.name, model.fields().id().name),
-                });
-            } else {
-                return Err(ConnectorError::UniqueConstraintViolation {
-                    field_name: format!("{}.{}", model.name, field_name),
-                });
-            }
-        }
-        Err(QueryError::NullConstraintViolation { field_name }) => {
-            if field_name == "PRIMARY" {
-                return Err(ConnectorError::NullConstraintViolation {
-                    field_name: format!("{}.{}", model.name, model.fields().id().name),
-                });
-            } else {
-                return Err(ConnectorError::NullConstraintViolation {
-                    field_name: format!("{}.{}", model.name, field_name),
-                });
-            }
-        }
-        Err(e) => return Err(SqlError::from(e).into()),
-    };
+    let last_id = conn
+        .execute_logged(settings.family, insert.into())
+        .await
+        .map_err(|e| constraint_error(model, e))?;
 
     let id = match returned_id {
         Some(id) => id,
@@ -47,20 +27,68 @@ pub async fn create_record(
         let table = field.scalar_list_table();
 
         if let Some(insert) = WriteQueryBuilder::create_scalar_list_value(table.table(), &list_value, &id) {
-            conn.insert(insert).await.map_err(SqlError::from)?;
+            conn.execute_logged(settings.family, insert.into())
+                .await
+                .map_err(SqlError::from)?;
         }
     }
 
     Ok(id)
 }
 
+/// Creates many records with as few statements as possible. Records with scalar list values are
+/// created one by one and can't skip duplicates, requesting both is rejected by the core.
+/// Returns the number of created records.
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    settings: ConnectionSettings,
+) -> connector_interface::Result<usize> {
+    let (with_lists, without_lists): (Vec<WriteArgs>, Vec<WriteArgs>) = args
+        .into_iter()
+        .partition(|args| args.list_args().iter().any(|(_, value)| value.is_some()));
+
+    let mut count = 0;
+
+    let inserts = WriteQueryBuilder::create_records(
+        model,
+        without_lists
+            .into_iter()
+            .map(|args| args.non_list_args().clone())
+            .collect(),
+        skip_duplicates,
+        settings.insert_batch_size,
+        settings.family.max_bind_values(),
+    );
+
+    for insert in inserts {
+        let (sql, params) = settings.family.render(insert.into());
+        let changes = conn
+            .execute_raw_logged(sql.as_str(), params.as_slice())
+            .await
+            .map_err(|e| constraint_error(model, e))?;
+
+        count += changes as usize;
+    }
+
+    for args in with_lists {
+        create_record(conn, model, args, settings).await?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
 pub async fn update_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     where_: Filter,
     args: WriteArgs,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<Vec<GraphqlId>> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+    let ids = conn.filter_ids(settings.family, model, where_.clone()).await?;
 
     if ids.len() == 0 {
         return Ok(vec![]);
@@ -72,7 +100,9 @@ pub async fn update_records(
     };
 
     for update in updates {
-        conn.update(update).await.map_err(SqlError::from)?;
+        conn.execute_logged(settings.family, update.into())
+            .await
+            .map_err(SqlError::from)?;
     }
 
     for (field_name, list_value) in args.list_args() {
@@ -81,23 +111,140 @@ pub async fn update_records(
         let (deletes, inserts) = WriteQueryBuilder::update_scalar_list_values(&table, &list_value, ids.to_vec());
 
         for delete in deletes {
-            conn.delete(delete).await.map_err(SqlError::from)?;
+            conn.execute_logged(settings.family, delete.into())
+                .await
+                .map_err(SqlError::from)?;
         }
 
         for insert in inserts {
-            conn.insert(insert).await.map_err(SqlError::from)?;
+            conn.execute_logged(settings.family, insert.into())
+                .await
+                .map_err(SqlError::from)?;
         }
     }
 
+    if !args.json_updates().is_empty() {
+        update_json_documents(conn, model, ids.as_slice(), args.json_updates(), settings).await?;
+    }
+
     Ok(ids)
 }
 
+/// Applies the Json path updates by reading the documents of the records, changing them with `json::apply_updates`
+/// and writing them back, one update per record.
+async fn update_json_documents(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    ids: &[GraphqlId],
+    updates: &[JsonUpdate],
+    settings: ConnectionSettings,
+) -> connector_interface::Result<()> {
+    let mut fields: Vec<ScalarFieldRef> = Vec::new();
+
+    for update in updates {
+        if !fields.iter().any(|field| field.name == update.field) {
+            fields.push(model.fields().find_from_scalar(&update.field).unwrap());
+        }
+    }
+
+    let id_count = model.id_columns().len();
+    let idents: Vec<TypeIdentifier> = std::iter::repeat(TypeIdentifier::GraphQLID)
+        .take(id_count)
+        .chain(fields.iter().map(|_| TypeIdentifier::Json))
+        .collect();
+
+    let ids: Vec<&GraphqlId> = ids.iter().collect();
+
+    for select in WriteQueryBuilder::select_json_documents(model, fields.as_slice(), ids.as_slice()) {
+        for mut row in conn.filter(settings.family, select.into(), idents.as_slice()).await? {
+            let documents = row.values.split_off(id_count);
+            let id = row
+                .values
+                .into_iter()
+                .map(GraphqlId::try_from)
+                .collect::<DomainResult<Vec<GraphqlId>>>()
+                .map_err(SqlError::from)?;
+
+            let documents = fields
+                .iter()
+                .zip(documents)
+                .map(|(field, document)| {
+                    let document = match document {
+                        PrismaValue::Json(document) => Some(document),
+                        _ => None,
+                    };
+
+                    let field_updates = updates.iter().filter(|update| update.field == field.name);
+
+                    (Arc::clone(field), json::apply_updates(document, field_updates))
+                })
+                .collect();
+
+            let update = WriteQueryBuilder::update_json_documents(model, &GraphqlId::from_parts(id), documents);
+
+            conn.execute_logged(settings.family, update.into())
+                .await
+                .map_err(SqlError::from)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Updates all records matching the filter with a single statement, unless scalar lists or Json paths are updated
+/// or the database requires the ids of the records first.
+pub async fn update_many_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    where_: Filter,
+    args: WriteArgs,
+    settings: ConnectionSettings,
+) -> connector_interface::Result<usize> {
+    // MySQL only counts changed rows of an update, so updates go through the ids of the records there.
+    let single_statement = settings.family != SqlFamily::Mysql
+        && args.list_args().is_empty()
+        && args.json_updates().is_empty()
+        && !args.non_list_args().args.is_empty();
+
+    if !single_statement {
+        return Ok(update_records(conn, model, where_, args, settings).await?.len());
+    }
+
+    let update = WriteQueryBuilder::update_by_filter(model, where_, args.non_list_args())?;
+    let (sql, params) = settings.family.render(update.into());
+
+    let changes = conn
+        .execute_raw_logged(sql.as_str(), params.as_slice())
+        .await
+        .map_err(|e| constraint_error(model, e))?;
+
+    Ok(changes as usize)
+}
+
+/// Deletes all records matching the filter with a single statement, unless the model has scalar list tables or the
+/// database requires the ids of the records first.
 pub async fn delete_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     where_: Filter,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<usize> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+    // MySQL can't select from the table a statement deletes from, which relation filters might do.
+    let single_statement = model.fields().scalar_list_non_embedded().is_empty()
+        && !(settings.family == SqlFamily::Mysql && references_relations(&where_));
+
+    if single_statement {
+        let delete = WriteQueryBuilder::delete_by_filter(model, where_);
+        let (sql, params) = settings.family.render(delete.into());
+        let changes = conn
+            .execute_raw_logged(sql.as_str(), params.as_slice())
+            .await
+            .map_err(SqlError::from)?;
+
+        return Ok(changes as usize);
+    }
+
+    let ids = conn.filter_ids(settings.family, model, where_.clone()).await?;
     let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -106,7 +253,9 @@ pub async fn delete_records(
     }
 
     for delete in WriteQueryBuilder::delete_many(model, ids.as_slice()) {
-        conn.delete(delete).await.map_err(SqlError::from)?;
+        conn.execute_logged(settings.family, delete.into())
+            .await
+            .map_err(SqlError::from)?;
     }
 
     Ok(count)
@@ -117,9 +266,12 @@ pub async fn connect(
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
     child_id: &GraphqlId,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<()> {
     let query = WriteQueryBuilder::create_relation(field, parent_id, child_id);
-    conn.execute(query).await.map_err(SqlError::from)?;
+    conn.execute_logged(settings.family, query)
+        .await
+        .map_err(SqlError::from)?;
 
     Ok(())
 }
@@ -129,9 +281,12 @@ pub async fn disconnect(
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
     child_id: &GraphqlId,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<()> {
     let query = WriteQueryBuilder::delete_relation(field, parent_id, child_id);
-    conn.execute(query).await.map_err(SqlError::from)?;
+    conn.execute_logged(settings.family, query)
+        .await
+        .map_err(SqlError::from)?;
 
     Ok(())
 }
@@ -141,14 +296,47 @@ pub async fn set(
     field: &RelationFieldRef,
     parent_id: GraphqlId,
     child_ids: Vec<GraphqlId>,
+    settings: ConnectionSettings,
 ) -> connector_interface::Result<()> {
     let query = WriteQueryBuilder::delete_relation_by_parent(field, &parent_id);
-    conn.execute(query).await.map_err(SqlError::from)?;
+    conn.execute_logged(settings.family, query)
+        .await
+        .map_err(SqlError::from)?;
 
     // TODO: we can avoid the multiple roundtrips in some cases
     for child_id in &child_ids {
-        connect(conn, field, &parent_id, child_id).await?;
+        connect(conn, field, &parent_id, child_id, settings).await?;
     }
 
     Ok(())
 }
+
+/// Whether the filter contains conditions on related records.
+fn references_relations(filter: &Filter) -> bool {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => filters.iter().any(references_relations),
+        Filter::Relation(_) | Filter::OneRelationIsNull(_) => true,
+        _ => false,
+    }
+}
+
+/// Maps constraint violations to errors naming the violated field of the model.
+fn constraint_error(model: &ModelRef, e: QueryError) -> ConnectorError {
+    let field_name = |field_name: String| {
+        if field_name == "PRIMARY" {
+            format!("{}.{}", model.name, model.id_field_names().join("_"))
+        } else {
+            format!("{}.{}", model.name, field_name)
+        }
+    };
+
+    match e {
+        QueryError::UniqueConstraintViolation { field_name: name } => ConnectorError::UniqueConstraintViolation {
+            field_name: field_name(name),
+        },
+        QueryError::NullConstraintViolation { field_name: name } => ConnectorError::NullConstraintViolation {
+            field_name: field_name(name),
+        },
+        e => SqlError::from(e).into(),
+    }
+}
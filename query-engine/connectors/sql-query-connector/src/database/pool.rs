@@ -0,0 +1,329 @@
+use crate::SqlError;
+use async_std::future;
+use connector_interface::PoolMetrics;
+use parking_lot::Mutex;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio_resource_pool::{CheckOut, Manage, Pool};
+use url::Url;
+
+/// Time a request waits for a connection if the pool is exhausted, unless set with `pool_timeout`.
+const DEFAULT_POOL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Settings of the connection pool, set with parameters of the connection string:
+///
+/// - `connection_limit`: The maximum number of open connections. Also read by quaint when building the pool.
+/// - `pool_timeout`: Seconds to wait for a connection if all connections are in use. Defaults to 10.
+/// - `idle_timeout`: Seconds after which the connections of a pool that wasn't used are closed.
+/// - `max_lifetime`: Seconds after which all connections are replaced with new ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PoolSettings {
+    pub connection_limit: Option<usize>,
+    pub pool_timeout: Option<Duration>,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+}
+
+impl PoolSettings {
+    pub fn new(url: &str) -> crate::Result<Self> {
+        let url = Url::parse(url)?;
+        let mut settings = Self::default();
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "connection_limit" => settings.connection_limit = Some(Self::parse_positive(&value)?),
+                "pool_timeout" => settings.pool_timeout = Some(Self::parse_seconds(&value)?),
+                "idle_timeout" => settings.idle_timeout = Some(Self::parse_seconds(&value)?),
+                "max_lifetime" => settings.max_lifetime = Some(Self::parse_seconds(&value)?),
+                _ => (),
+            }
+        }
+
+        Ok(settings)
+    }
+
+    fn parse_positive(value: &str) -> crate::Result<usize> {
+        match value.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(SqlError::InvalidConnectionArguments),
+        }
+    }
+
+    fn parse_seconds(value: &str) -> crate::Result<Duration> {
+        Self::parse_positive(value).map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    fn pool_timeout(&self) -> Duration {
+        self.pool_timeout.unwrap_or(DEFAULT_POOL_TIMEOUT)
+    }
+}
+
+/// The connection pool of a connector. Wraps the pool of quaint, which is replaced with a new pool
+/// (and therefore new connections) after `max_lifetime` or `idle_timeout`.
+///
+/// The pool is only replaced while no connection is checked out and no request waits for one. Dropping the replaced
+/// pool then closes all of its connections, so the open connections never exceed `connection_limit`. Under constant
+/// load, an expired pool is replaced at the next moment it is unused.
+pub struct ConnectionPool<M>
+where
+    M: Manage,
+{
+    settings: PoolSettings,
+    build: Box<dyn Fn() -> crate::Result<Pool<M>> + Send + Sync>,
+    current: Mutex<PoolGeneration<M>>,
+    usage: Arc<PoolUsage>,
+}
+
+struct PoolGeneration<M>
+where
+    M: Manage,
+{
+    pool: Pool<M>,
+    created_at: Instant,
+}
+
+#[derive(Default)]
+struct PoolUsage {
+    checked_out: AtomicUsize,
+    waiters: AtomicUsize,
+
+    /// The highest number of connections checked out at the same time from the current pool.
+    /// The pool opens connections on demand and keeps them, which makes this the number of open connections.
+    opened: AtomicUsize,
+
+    last_used: Mutex<Option<Instant>>,
+}
+
+/// Marks a connection as checked out of the pool until dropped.
+pub struct CheckedOut {
+    usage: Arc<PoolUsage>,
+}
+
+impl Drop for CheckedOut {
+    fn drop(&mut self) {
+        self.usage.checked_out.fetch_sub(1, Ordering::SeqCst);
+        *self.usage.last_used.lock() = Some(Instant::now());
+    }
+}
+
+/// Marks a request as waiting for a connection until dropped, also if the request is cancelled while waiting.
+struct Waiting {
+    usage: Arc<PoolUsage>,
+}
+
+impl Waiting {
+    fn new(usage: &Arc<PoolUsage>) -> Self {
+        usage.waiters.fetch_add(1, Ordering::SeqCst);
+
+        Self {
+            usage: Arc::clone(usage),
+        }
+    }
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        self.usage.waiters.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<M> ConnectionPool<M>
+where
+    M: Manage,
+    Pool<M>: Clone,
+{
+    pub fn new<F>(settings: PoolSettings, build: F) -> crate::Result<Self>
+    where
+        F: Fn() -> crate::Result<Pool<M>> + Send + Sync + 'static,
+    {
+        let current = PoolGeneration {
+            pool: build()?,
+            created_at: Instant::now(),
+        };
+
+        Ok(Self {
+            settings,
+            build: Box::new(build),
+            current: Mutex::new(current),
+            usage: Arc::new(PoolUsage::default()),
+        })
+    }
+
+    /// Checks out a connection, failing with `SqlError::PoolTimeout` if none is available within the pool timeout.
+    pub async fn check_out(&self) -> crate::Result<(CheckOut<M>, CheckedOut)>
+    where
+        SqlError: From<M::Error>,
+    {
+        let (pool, waiting) = self.current_pool()?;
+        let timeout = self.settings.pool_timeout();
+
+        let conn = match future::timeout(timeout, pool.check_out()).await {
+            Ok(conn) => conn?,
+            Err(_) => {
+                return Err(SqlError::PoolTimeout {
+                    connection_limit: self.settings.connection_limit,
+                    timeout,
+                })
+            }
+        };
+
+        // Counted as checked out before it stops waiting, so the pool is never seen as unused in between.
+        let checked_out = self.usage.checked_out.fetch_add(1, Ordering::SeqCst) + 1;
+        drop(waiting);
+
+        let mut opened = self.usage.opened.load(Ordering::SeqCst);
+
+        while opened < checked_out {
+            match self
+                .usage
+                .opened
+                .compare_and_swap(opened, checked_out, Ordering::SeqCst)
+            {
+                previous if previous == opened => break,
+                previous => opened = previous,
+            }
+        }
+
+        Ok((
+            conn,
+            CheckedOut {
+                usage: Arc::clone(&self.usage),
+            },
+        ))
+    }
+
+    pub fn metrics(&self) -> PoolMetrics {
+        let checked_out = self.usage.checked_out.load(Ordering::SeqCst);
+        let opened = self.usage.opened.load(Ordering::SeqCst);
+
+        PoolMetrics {
+            connection_limit: self.settings.connection_limit,
+            checked_out,
+            idle: opened.saturating_sub(checked_out),
+            waiters: self.usage.waiters.load(Ordering::SeqCst),
+        }
+    }
+
+    /// The pool to check out from, replacing the current pool if it's unused and its connections expired.
+    /// The request is marked as waiting while the lock is held, so no other request replaces the pool meanwhile.
+    fn current_pool(&self) -> crate::Result<(Pool<M>, Waiting)> {
+        let mut current = self.current.lock();
+
+        let unused =
+            self.usage.checked_out.load(Ordering::SeqCst) == 0 && self.usage.waiters.load(Ordering::SeqCst) == 0;
+
+        let expired = self
+            .settings
+            .max_lifetime
+            .map(|lifetime| current.created_at.elapsed() >= lifetime)
+            .unwrap_or(false);
+
+        let idle = match (self.settings.idle_timeout, *self.usage.last_used.lock()) {
+            (Some(timeout), Some(last_used)) => last_used.elapsed() >= timeout,
+            _ => false,
+        };
+
+        if unused && (expired || idle) {
+            *current = PoolGeneration {
+                pool: (self.build)()?,
+                created_at: Instant::now(),
+            };
+
+            self.usage.opened.store(0, Ordering::SeqCst);
+        }
+
+        Ok((current.pool.clone(), Waiting::new(&self.usage)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_std::task;
+    use quaint::pool::{self, SqliteManager};
+
+    #[test]
+    fn pool_settings_must_be_read_from_the_connection_string() {
+        let settings = PoolSettings::new(
+            "postgresql://localhost:5432/db?connection_limit=5&pool_timeout=2&idle_timeout=30&max_lifetime=600",
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings,
+            PoolSettings {
+                connection_limit: Some(5),
+                pool_timeout: Some(Duration::from_secs(2)),
+                idle_timeout: Some(Duration::from_secs(30)),
+                max_lifetime: Some(Duration::from_secs(600)),
+            }
+        );
+    }
+
+    #[test]
+    fn pool_settings_must_default_to_no_limits_and_the_default_pool_timeout() {
+        let settings = PoolSettings::new("postgresql://localhost:5432/db?schema=public").unwrap();
+
+        assert_eq!(settings, PoolSettings::default());
+        assert_eq!(settings.pool_timeout(), DEFAULT_POOL_TIMEOUT);
+    }
+
+    #[test]
+    fn pool_settings_must_reject_zero_and_invalid_values() {
+        for query in &[
+            "connection_limit=0",
+            "connection_limit=-1",
+            "pool_timeout=0",
+            "idle_timeout=soon",
+            "max_lifetime=1.5",
+        ] {
+            let url = format!("mysql://localhost:3306/db?{}", query);
+
+            match PoolSettings::new(&url) {
+                Err(SqlError::InvalidConnectionArguments) => (),
+                other => panic!("Expected invalid connection arguments for `{}`, got {:?}", query, other),
+            }
+        }
+    }
+
+    #[test]
+    fn check_out_must_time_out_if_all_connections_are_checked_out() {
+        let file_path = std::env::temp_dir().join("pool_timeout_test.db");
+        let url = format!("file:{}?connection_limit=1", file_path.to_str().unwrap());
+
+        let settings = PoolSettings {
+            connection_limit: Some(1),
+            pool_timeout: Some(Duration::from_secs(1)),
+            ..PoolSettings::default()
+        };
+
+        let pool: ConnectionPool<SqliteManager> =
+            ConnectionPool::new(settings, move || Ok(pool::sqlite(&url, "pool_timeout_test")?)).unwrap();
+
+        task::block_on(async {
+            let _first = pool.check_out().await.unwrap();
+
+            match pool.check_out().await {
+                Err(SqlError::PoolTimeout {
+                    connection_limit,
+                    timeout,
+                }) => {
+                    assert_eq!(connection_limit, Some(1));
+                    assert_eq!(timeout, Duration::from_secs(1));
+                }
+                Err(e) => panic!("Expected a pool timeout, got {:?}", e),
+                Ok(_) => panic!("Expected a pool timeout, got a connection"),
+            }
+
+            let metrics = pool.metrics();
+
+            assert_eq!(metrics.checked_out, 1);
+            assert_eq!(metrics.waiters, 0);
+        });
+    }
+}
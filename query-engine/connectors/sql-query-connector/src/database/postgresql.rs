@@ -1,12 +1,13 @@
-use super::connection::SqlConnection;
-use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt, SqlError};
-use connector_interface::{Connection, Connector, IO};
+use super::{connection::SqlConnection, ConnectionPool, ConnectionSettings, PoolSettings, SqlFamily};
+use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt};
+use connector_interface::{Connection, Connector, PoolMetrics, IO};
 use datamodel::Source;
 use quaint::pool::{self, PostgresManager};
-use tokio_resource_pool::{CheckOut, Pool};
+use tokio_resource_pool::CheckOut;
 
 pub struct PostgreSql {
-    pool: Pool<PostgresManager>,
+    pool: ConnectionPool<PostgresManager>,
+    settings: ConnectionSettings,
 }
 
 impl QueryExt for CheckOut<PostgresManager> {}
@@ -14,19 +15,25 @@ impl QueryExt for CheckOut<PostgresManager> {}
 impl FromSource for PostgreSql {
     fn from_source(source: &dyn Source) -> crate::Result<Self> {
         let url = url::Url::parse(&source.url().value)?;
-        let pool = pool::postgres(url)?;
+        let settings = ConnectionSettings::new(SqlFamily::Postgres, &source.url().value)?;
+        let pool_settings = PoolSettings::new(&source.url().value)?;
+        let pool = ConnectionPool::new(pool_settings, move || Ok(pool::postgres(url.clone())?))?;
 
-        Ok(PostgreSql { pool })
+        Ok(PostgreSql { pool, settings })
     }
 }
 
 impl Connector for PostgreSql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn);
+            let (conn, checked_out) = self.pool.check_out().await?;
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn, checked_out, self.settings);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
     }
+
+    fn pool_metrics(&self) -> Option<PoolMetrics> {
+        Some(self.pool.metrics())
+    }
 }
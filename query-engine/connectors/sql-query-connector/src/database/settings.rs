@@ -0,0 +1,75 @@
+use crate::SqlError;
+use quaint::{
+    ast::{ParameterizedValue, Query},
+    visitor::{self, Visitor},
+};
+use url::Url;
+
+/// The SQL database a connector talks to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlFamily {
+    Sqlite,
+    Postgres,
+    Mysql,
+}
+
+impl SqlFamily {
+    /// The name of the connector for the database, as used in the datasource `provider`.
+    pub fn connector_name(self) -> &'static str {
+        match self {
+            SqlFamily::Sqlite => "sqlite",
+            SqlFamily::Postgres => "postgresql",
+            SqlFamily::Mysql => "mysql",
+        }
+    }
+
+    /// The maximum number of bind parameters a single statement can hold.
+    pub fn max_bind_values(self) -> usize {
+        match self {
+            SqlFamily::Sqlite => 999,
+            SqlFamily::Postgres => 32767,
+            SqlFamily::Mysql => 65535,
+        }
+    }
+
+    /// Renders a query to SQL in the dialect of the database.
+    pub fn render<'a>(self, query: Query<'a>) -> (String, Vec<ParameterizedValue<'a>>) {
+        match self {
+            SqlFamily::Sqlite => visitor::Sqlite::build(query),
+            SqlFamily::Postgres => visitor::Postgres::build(query),
+            SqlFamily::Mysql => visitor::Mysql::build(query),
+        }
+    }
+}
+
+/// Settings of a connector that apply to all of its connections.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionSettings {
+    pub family: SqlFamily,
+
+    /// The maximum number of records inserted by a single statement when creating many records.
+    /// Set with the `insert_batch_size` parameter of the connection string. The number of records per
+    /// statement is always bounded by the maximum number of bind parameters of the database.
+    pub insert_batch_size: Option<usize>,
+}
+
+impl ConnectionSettings {
+    pub fn new(family: SqlFamily, url: &str) -> crate::Result<Self> {
+        let url = Url::parse(url)?;
+        let mut insert_batch_size = None;
+
+        for (key, value) in url.query_pairs() {
+            if key == "insert_batch_size" {
+                match value.parse::<usize>() {
+                    Ok(size) if size > 0 => insert_batch_size = Some(size),
+                    _ => return Err(SqlError::InvalidConnectionArguments),
+                }
+            }
+        }
+
+        Ok(Self {
+            family,
+            insert_batch_size,
+        })
+    }
+}
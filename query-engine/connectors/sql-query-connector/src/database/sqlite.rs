@@ -1,17 +1,18 @@
-use super::connection::SqlConnection;
-use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt, SqlError};
-use connector_interface::{Connection, Connector, IO};
+use super::{connection::SqlConnection, ConnectionPool, ConnectionSettings, PoolSettings, SqlFamily};
+use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, QueryExt};
+use connector_interface::{Connection, Connector, PoolMetrics, IO};
 use datamodel::Source;
 use quaint::{
     connector::SqliteParams,
     pool::{self, SqliteManager},
 };
 use std::convert::TryFrom;
-use tokio_resource_pool::{CheckOut, Pool};
+use tokio_resource_pool::CheckOut;
 
 pub struct Sqlite {
-    pool: Pool<SqliteManager>,
+    pool: ConnectionPool<SqliteManager>,
     file_path: String,
+    settings: ConnectionSettings,
 }
 
 impl QueryExt for CheckOut<SqliteManager> {}
@@ -25,21 +26,35 @@ impl Sqlite {
 impl FromSource for Sqlite {
     fn from_source(source: &dyn Source) -> crate::Result<Self> {
         let params = SqliteParams::try_from(source.url().value.as_str())?;
+        let settings = ConnectionSettings::new(SqlFamily::Sqlite, &source.url().value)?;
         let db_name = params.file_path.file_stem().unwrap().to_str().unwrap().to_owned();
         let file_path = params.file_path.to_str().unwrap().to_string();
-        let pool = pool::sqlite(&file_path, &db_name)?;
+        let pool_settings = PoolSettings::new(&source.url().value)?;
 
-        Ok(Self { pool, file_path })
+        let pool = {
+            let file_path = file_path.clone();
+            ConnectionPool::new(pool_settings, move || Ok(pool::sqlite(&file_path, &db_name)?))?
+        };
+
+        Ok(Self {
+            pool,
+            file_path,
+            settings,
+        })
     }
 }
 
 impl Connector for Sqlite {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn);
+            let (conn, checked_out) = self.pool.check_out().await?;
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn, checked_out, self.settings);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
     }
+
+    fn pool_metrics(&self) -> Option<PoolMetrics> {
+        Some(self.pool.metrics())
+    }
 }
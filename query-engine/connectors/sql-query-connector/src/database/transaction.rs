@@ -1,22 +1,28 @@
+use super::ConnectionSettings;
 use crate::database::operations::*;
+use crate::metrics::metered;
 use crate::{query_builder::read::ManyRelatedRecordsQueryBuilder, SqlError};
 use connector_interface::{
     self as connector,
     filter::{Filter, RecordFinder},
-    QueryArguments, ReadOperations, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO
+    AggregationResult, Aggregator, HavingFilter, QueryArguments, ReadOperations, RecordGroup, RelatedRecordsCount,
+    ScalarListValues, Transaction, WriteArgs, WriteOperations, IO
 };
 use prisma_models::prelude::*;
+use quaint::ast::ParameterizedValue;
 use std::marker::PhantomData;
 
 pub struct SqlConnectorTransaction<'a, T> {
     inner: quaint::connector::Transaction<'a>,
+    settings: ConnectionSettings,
     _p: PhantomData<T>,
 }
 
 impl<'a, T> SqlConnectorTransaction<'a, T> {
-    pub fn new(tx: quaint::connector::Transaction<'a>) -> Self {
+    pub fn new(tx: quaint::connector::Transaction<'a>, settings: ConnectionSettings) -> Self {
         Self {
             inner: tx,
+            settings,
             _p: PhantomData,
         }
     }
@@ -44,7 +50,9 @@ where
         record_finder: &'b RecordFinder,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(async move { read::get_single_record(&self.inner, record_finder, selected_fields).await })
+        metered(self.settings.family, "get_single_record", async move {
+            read::get_single_record(&self.inner, record_finder, selected_fields, self.settings).await
+        })
     }
 
     fn get_many_records<'b>(
@@ -53,7 +61,9 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        metered(self.settings.family, "get_many_records", async move {
+            read::get_many_records(&self.inner, model, query_arguments, selected_fields, self.settings).await
+        })
     }
 
     fn get_related_records<'b>(
@@ -63,13 +73,14 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(async move {
+        metered(self.settings.family, "get_related_records", async move {
             read::get_related_records::<T>(
                 &self.inner,
                 from_field,
                 from_record_ids,
                 query_arguments,
                 selected_fields,
+                self.settings,
             )
             .await
         })
@@ -80,11 +91,53 @@ where
         list_field: &'b ScalarFieldRef,
         record_ids: Vec<GraphqlId>,
     ) -> connector::IO<'b, Vec<ScalarListValues>> {
-        IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
+        metered(self.settings.family, "get_scalar_list_values", async move {
+            read::get_scalar_list_values(&self.inner, list_field, record_ids, self.settings).await
+        })
+    }
+
+    fn count_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        from_record_ids: &'b [GraphqlId],
+        filter: Option<Filter>,
+    ) -> connector::IO<'b, Vec<RelatedRecordsCount>> {
+        metered(self.settings.family, "count_related_records", async move {
+            read::count_related_records(&self.inner, from_field, from_record_ids, filter, self.settings).await
+        })
     }
 
-    fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(async move { read::count_by_model(&self.inner, model, query_arguments).await })
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        metered(self.settings.family, "aggregate_records", async move {
+            read::aggregate_records(&self.inner, model, aggregators, query_arguments, self.settings).await
+        })
+    }
+
+    fn group_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        having: Option<HavingFilter>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<RecordGroup>> {
+        metered(self.settings.family, "group_records", async move {
+            read::group_records(
+                &self.inner,
+                model,
+                group_by,
+                aggregators,
+                having,
+                query_arguments,
+                self.settings,
+            )
+            .await
+        })
     }
 }
 
@@ -93,15 +146,58 @@ where
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
     fn create_record<'b>(&'b self, model: &'b ModelRef, args: WriteArgs) -> connector::IO<GraphqlId> {
-        IO::new(async move { write::create_record(&self.inner, model, args).await })
+        metered(self.settings.family, "create_record", async move {
+            write::create_record(&self.inner, model, args, self.settings).await
+        })
+    }
+
+    fn create_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::IO<usize> {
+        metered(self.settings.family, "create_records", async move {
+            write::create_records(&self.inner, model, args, skip_duplicates, self.settings).await
+        })
     }
 
     fn update_records<'b>(&'b self, model: &'b ModelRef, where_: Filter, args: WriteArgs) -> connector::IO<Vec<GraphqlId>> {
-        IO::new(async move { write::update_records(&self.inner, model, where_, args).await })
+        metered(self.settings.family, "update_records", async move {
+            write::update_records(&self.inner, model, where_, args, self.settings).await
+        })
+    }
+
+    fn update_many_records<'b>(&'b self, model: &'b ModelRef, where_: Filter, args: WriteArgs) -> connector::IO<usize> {
+        metered(self.settings.family, "update_many_records", async move {
+            write::update_many_records(&self.inner, model, where_, args, self.settings).await
+        })
     }
 
     fn delete_records<'b>(&'b self, model: &'b ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(async move { write::delete_records(&self.inner, model, where_).await })
+        metered(self.settings.family, "delete_records", async move {
+            write::delete_records(&self.inner, model, where_, self.settings).await
+        })
+    }
+
+    fn query_raw<'b>(&'b self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+        metered(self.settings.family, "query_raw", async move { raw::query_raw(&self.inner, query, parameters).await })
+    }
+
+    fn query_raw_values<'b>(
+        &'b self,
+        query: String,
+        parameters: Vec<ParameterizedValue<'static>>,
+    ) -> connector::IO<serde_json::Value> {
+        metered(self.settings.family, "query_raw", async move {
+            raw::query_raw_values(&self.inner, query, parameters).await
+        })
+    }
+
+    fn execute_raw<'b>(&'b self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
+        metered(self.settings.family, "execute_raw", async move {
+            raw::execute_raw(&self.inner, query, parameters).await
+        })
     }
 
     fn connect<'b>(
@@ -110,7 +206,9 @@ where
         parent_id: &'b GraphqlId,
         child_id: &'b GraphqlId,
     ) -> connector::IO<()> {
-        IO::new(async move { write::connect(&self.inner, field, parent_id, child_id).await })
+        metered(self.settings.family, "connect", async move {
+            write::connect(&self.inner, field, parent_id, child_id, self.settings).await
+        })
     }
 
     fn disconnect<'b>(
@@ -119,7 +217,9 @@ where
         parent_id: &'b GraphqlId,
         child_id: &'b GraphqlId,
     ) -> connector::IO<()> {
-        IO::new(async move { write::disconnect(&self.inner, field, parent_id, child_id).await })
+        metered(self.settings.family, "disconnect", async move {
+            write::disconnect(&self.inner, field, parent_id, child_id, self.settings).await
+        })
     }
 
     fn set<'b>(
@@ -128,6 +228,8 @@ where
         parent_id: GraphqlId,
         wheres: Vec<GraphqlId>,
     ) -> connector::IO<()> {
-        IO::new(async move { write::set(&self.inner, relation_field, parent_id, wheres).await })
+        metered(self.settings.family, "set", async move {
+            write::set(&self.inner, relation_field, parent_id, wheres, self.settings).await
+        })
     }
 }
@@ -1,7 +1,7 @@
 use connector_interface::error::*;
 use failure::{Error, Fail};
 use prisma_models::prelude::DomainError;
-use std::string::FromUtf8Error;
+use std::{string::FromUtf8Error, time::Duration};
 
 #[derive(Debug, Fail)]
 pub enum SqlError {
@@ -74,6 +74,15 @@ pub enum SqlError {
 
     #[fail(display = "Authentication failed for user '{}'", user)]
     AuthenticationFailed { user: String },
+
+    #[fail(
+        display = "Timed out fetching a connection from the pool after {:?} (connection limit: {:?})",
+        timeout, connection_limit
+    )]
+    PoolTimeout {
+        connection_limit: Option<usize>,
+        timeout: Duration,
+    },
 }
 
 impl From<tokio_postgres::error::Error> for SqlError {
@@ -125,6 +134,13 @@ impl From<SqlError> for ConnectorError {
             SqlError::ConversionError(e) => ConnectorError::ConversionError(e),
             SqlError::DatabaseCreationError(e) => ConnectorError::DatabaseCreationError(e),
             SqlError::QueryError(e) => ConnectorError::QueryError(e),
+            SqlError::PoolTimeout {
+                connection_limit,
+                timeout,
+            } => ConnectorError::PoolTimeout {
+                connection_limit,
+                timeout,
+            },
         }
     }
 }
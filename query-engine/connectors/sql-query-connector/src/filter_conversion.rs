@@ -1,3 +1,4 @@
+use crate::fulltext;
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
 use quaint::ast::*;
@@ -120,6 +121,9 @@ impl AliasedCondition for Filter {
                 }
             },
             Filter::Scalar(filter) => filter.aliased_cond(alias),
+            // Json path filters are only offered with `ConnectorCapability::JsonFilters`, which quaint can't express.
+            Filter::Json(_) => unreachable!(),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
@@ -137,41 +141,19 @@ impl AliasedCondition for Filter {
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        if let ScalarCondition::Search(query) = self.condition {
+            let alias = alias.map(|alias| alias.to_string(None));
+            return fulltext::search_condition(&self.field, alias, query);
+        }
+
         let column = match alias {
             Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
             None => self.field.as_column(),
         };
 
-        let condition = match self.condition {
-            ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
-            ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
-            ScalarCondition::Contains(value) => column.like(format!("{}", value)),
-            ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
-            ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
-            ScalarCondition::NotStartsWith(value) => column.not_begins_with(format!("{}", value)),
-            ScalarCondition::EndsWith(value) => column.ends_into(format!("{}", value)),
-            ScalarCondition::NotEndsWith(value) => column.not_ends_into(format!("{}", value)),
-            ScalarCondition::LessThan(value) => column.less_than(value),
-            ScalarCondition::LessThanOrEquals(value) => column.less_than_or_equals(value),
-            ScalarCondition::GreaterThan(value) => column.greater_than(value),
-            ScalarCondition::GreaterThanOrEquals(value) => column.greater_than_or_equals(value),
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::In(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_null(),
-                _ => column.in_selection(values),
-            },
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::NotIn(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_not_null(),
-                _ => column.not_in_selection(values),
-            },
-            ScalarCondition::In(None) => column.is_null(),
-            ScalarCondition::NotIn(None) => column.is_not_null(),
-        };
-
-        ConditionTree::single(condition)
+        // The insensitive mode is only offered for MySQL (`ConnectorCapability::InsensitiveFilters`), whose default
+        // collations compare strings case-insensitively in both modes.
+        ConditionTree::single(scalar_condition(column.into(), self.condition))
     }
 }
 
@@ -274,6 +256,23 @@ impl AliasedSelect for RelationFilter {
     }
 }
 
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree on the native array column of an embedded
+    /// scalar list. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = self.field.as_column().opt_table(alias.map(|a| a.to_string(None)));
+        let empty: DatabaseValue<'static> = PrismaValue::List(Some(Vec::new())).into_parameter().into();
+
+        match self.condition {
+            ScalarListCondition::ContainsNone => ConditionTree::or(column.clone().is_null(), column.equals(empty)),
+
+            // The element filters are only offered with `ConnectorCapability::ScalarListFilters`, quaint can't
+            // express the array operators they need.
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -305,3 +304,37 @@ impl AliasedCondition for OneRelationIsNullFilter {
         ConditionTree::single(condition)
     }
 }
+
+/// The comparison of a value against a scalar condition, shared by record filters (`WHERE`)
+/// and filters on aggregated values (`HAVING`).
+fn scalar_condition(expression: DatabaseValue<'static>, condition: ScalarCondition) -> Compare<'static> {
+    match condition {
+        ScalarCondition::Equals(PrismaValue::Null) => expression.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null) => expression.is_not_null(),
+        ScalarCondition::Equals(value) => expression.equals(value),
+        ScalarCondition::NotEquals(value) => expression.not_equals(value),
+        ScalarCondition::Contains(value) => expression.like(format!("{}", value)),
+        ScalarCondition::NotContains(value) => expression.not_like(format!("{}", value)),
+        ScalarCondition::StartsWith(value) => expression.begins_with(format!("{}", value)),
+        ScalarCondition::NotStartsWith(value) => expression.not_begins_with(format!("{}", value)),
+        ScalarCondition::EndsWith(value) => expression.ends_into(format!("{}", value)),
+        ScalarCondition::NotEndsWith(value) => expression.not_ends_into(format!("{}", value)),
+        ScalarCondition::LessThan(value) => expression.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => expression.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => expression.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => expression.greater_than_or_equals(value),
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::In(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => expression.is_null(),
+            _ => expression.in_selection(values),
+        },
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::NotIn(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => expression.is_not_null(),
+            _ => expression.not_in_selection(values),
+        },
+        ScalarCondition::In(None) => expression.is_null(),
+        ScalarCondition::NotIn(None) => expression.is_not_null(),
+        ScalarCondition::Search(_) => unreachable!(), // Handled per field, see `fulltext::search_condition`.
+    }
+}
@@ -0,0 +1,75 @@
+//! Full-text search on the fields of a `@@fulltext` index, offered with `ConnectorCapability::FullTextSearch`.
+//!
+//! Only SQLite is supported: its full-text indexes are FTS5 tables, which quaint can query like any other table.
+//! A search is a lookup in the FTS5 table of the index, `rowid IN (SELECT rowid FROM <index> WHERE <index> = <query>)`,
+//! ranked with the `rank` of the FTS5 table. A search on a field always searches all fields of its index.
+use prisma_models::prelude::*;
+use quaint::ast::*;
+
+/// The condition of a `search` filter on the given field. Columns point to the given table alias if provided.
+pub fn search_condition(field: &ScalarFieldRef, alias: Option<String>, query: PrismaValue) -> ConditionTree<'static> {
+    let query = format!("{}", query);
+    let (schema, index_table) = index_table(field);
+    let matching_rows = Select::from_table((schema, index_table.clone()))
+        .column("rowid")
+        .so_that(Column::from(index_table).equals(query));
+
+    ConditionTree::single(record_rowid(field, alias).in_selection(matching_rows))
+}
+
+/// The relevance of a record for the given search query, to order by. Columns point to the given table alias
+/// if provided.
+///
+/// The FTS5 `rank` is the negated bm25 score of a record, lower values are therefore more relevant.
+pub fn relevance(field: &ScalarFieldRef, query: &str, alias: Option<String>) -> DatabaseValue<'static> {
+    let (schema, index_table) = index_table(field);
+    let index_rowid: Column<'static> = ((schema.clone(), index_table.clone()), "rowid".to_string()).into();
+
+    Select::from_table((schema, index_table.clone()))
+        .column("rank")
+        .so_that(
+            Column::from(index_table)
+                .equals(query.to_string())
+                .and(index_rowid.equals(record_rowid(field, alias))),
+        )
+        .into()
+}
+
+/// The fields searched together with the given field, from the full-text index of the field.
+fn index_fields(field: &ScalarFieldRef) -> Vec<ScalarFieldRef> {
+    match field.model().fulltext_index(field) {
+        Some(index) => index.fields(),
+        None => vec![field.clone()],
+    }
+}
+
+fn record_rowid(field: &ScalarFieldRef, alias: Option<String>) -> Column<'static> {
+    let model = field.model();
+
+    match alias {
+        Some(alias) => (alias, "rowid".to_string()).into(),
+        None => (
+            (model.internal_data_model().db_name.clone(), model.db_name().to_string()),
+            "rowid".to_string(),
+        )
+            .into(),
+    }
+}
+
+/// Schema and name of the FTS5 table of the full-text index of the field. Unnamed indexes are named like all
+/// indexes created by the migration engine, `<table>.<columns joined by _>`.
+fn index_table(field: &ScalarFieldRef) -> (String, String) {
+    let model = field.model();
+    let index_name = model.fulltext_index(field).and_then(|index| index.name.clone());
+
+    let name = index_name.unwrap_or_else(|| {
+        let columns: Vec<String> = index_fields(field)
+            .iter()
+            .map(|field| field.db_name().to_string())
+            .collect();
+
+        format!("{}.{}", model.db_name(), columns.join("_"))
+    });
+
+    (model.internal_data_model().db_name.clone(), name)
+}
@@ -0,0 +1,93 @@
+//! Updates of values inside of Json fields, addressed by a path of object keys and array indexes.
+//!
+//! Quaint can't express the Json functions of the databases, so the documents of the updated records are read,
+//! changed here and written back with a regular update per record, see `update_records`.
+use connector_interface::{JsonUpdate, JsonUpdateOperation};
+use prisma_models::PrismaValue;
+use serde_json::{Map, Value};
+
+/// Applies the updates to the document after each other, so later updates see the changes of earlier ones.
+/// A missing document is updated like an empty object.
+pub fn apply_updates<'a, I>(document: Option<Value>, updates: I) -> Value
+where
+    I: IntoIterator<Item = &'a JsonUpdate>,
+{
+    let document = match document {
+        None | Some(Value::Null) => Value::Object(Map::new()),
+        Some(document) => document,
+    };
+
+    updates.into_iter().fold(document, |mut document, update| {
+        let (value, merge) = match update.operation {
+            JsonUpdateOperation::Set(ref value) => (json_value(value), false),
+            JsonUpdateOperation::Merge(ref value) => (json_value(value), true),
+        };
+
+        if let Some(target) = target(&mut document, &update.path) {
+            if merge {
+                merge_patch(target, value);
+            } else {
+                *target = value;
+            }
+        }
+
+        document
+    })
+}
+
+/// The value at the path, null for a missing last key of an object. Missing parents are created as empty objects,
+/// paths through scalars or past the end of arrays don't point to any value.
+fn target<'a>(document: &'a mut Value, path: &[String]) -> Option<&'a mut Value> {
+    let mut current = document;
+
+    for (idx, segment) in path.iter().enumerate() {
+        let is_last = idx == path.len() - 1;
+
+        if current.is_null() && !is_last {
+            *current = Value::Object(Map::new());
+        }
+
+        current = match current {
+            Value::Object(object) => object.entry(segment.clone()).or_insert(if is_last {
+                Value::Null
+            } else {
+                Value::Object(Map::new())
+            }),
+            Value::Array(array) => array.get_mut(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+/// Merges the keys of the patch into the object (RFC 7396): nested objects are merged, null values remove keys.
+/// Patches that aren't objects replace the value.
+fn merge_patch(target: &mut Value, patch: Value) {
+    match patch {
+        Value::Object(patch) => {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+
+            if let Value::Object(object) = target {
+                for (key, value) in patch {
+                    if value.is_null() {
+                        object.remove(&key);
+                    } else {
+                        merge_patch(object.entry(key).or_insert(Value::Null), value);
+                    }
+                }
+            }
+        }
+        patch => *target = patch,
+    }
+}
+
+fn json_value(value: &PrismaValue) -> Value {
+    match value {
+        PrismaValue::Json(value) => value.clone(),
+        PrismaValue::Null => Value::Null,
+        value => Value::String(value.to_string()),
+    }
+}
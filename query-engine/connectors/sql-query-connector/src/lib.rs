@@ -6,13 +6,24 @@
 //! - [DatabaseWriter](../query-connector/trait.DatabaseWriter.html) to write
 //!   data.
 
+#[macro_use]
+extern crate lazy_static;
+
+#[macro_use]
+extern crate prometheus;
+
+mod aggregation;
 mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod fulltext;
+mod json;
+mod metrics;
 mod ordering;
 mod query_builder;
 mod query_ext;
+mod query_log;
 mod raw_query;
 mod row;
 
@@ -0,0 +1,38 @@
+use crate::SqlFamily;
+use connector_interface::IO;
+use prometheus::{HistogramVec, IntCounterVec};
+use std::{future::Future, time::Instant};
+
+lazy_static! {
+    static ref QUERIES: IntCounterVec = register_int_counter_vec!(
+        "prisma_connector_queries_total",
+        "Number of connector operations executed, by connector and operation.",
+        &["connector", "operation"]
+    )
+    .unwrap();
+    static ref QUERY_DURATION: HistogramVec = register_histogram_vec!(
+        "prisma_connector_query_duration_seconds",
+        "Duration of connector operations, by connector and operation.",
+        &["connector", "operation"]
+    )
+    .unwrap();
+}
+
+/// Runs a connector operation, counting it and recording its duration.
+pub(crate) fn metered<'a, T, F>(family: SqlFamily, operation: &'static str, fut: F) -> IO<'a, T>
+where
+    F: Future<Output = connector_interface::Result<T>> + Send + 'a,
+{
+    IO::new(async move {
+        let start = Instant::now();
+        let result = fut.await;
+        let labels = [family.connector_name(), operation];
+
+        QUERIES.with_label_values(&labels).inc();
+        QUERY_DURATION
+            .with_label_values(&labels)
+            .observe(start.elapsed().as_secs_f64());
+
+        result
+    })
+}
@@ -1,3 +1,4 @@
+use crate::fulltext;
 use prisma_models::prelude::*;
 use quaint::ast::*;
 
@@ -6,78 +7,254 @@ pub type OrderVec<'a> = Vec<(DatabaseValue<'a>, Option<Order>)>;
 pub struct Ordering;
 
 /// Tooling for generating orderings for different query types.
+///
+/// All orderings end with secondary columns (usually the id columns) to guarantee a stable ordering,
+/// unless the secondary columns are already part of the given orderings.
+///
+/// Orderings on related records are expressed as subselects correlated to the id of the ordered record:
+/// - `author_name`: `(SELECT name FROM <relation table> INNER JOIN User ON ... WHERE <relation column> = Post.id)`
+/// - `comments_COUNT`: `(SELECT COUNT(*) FROM <relation table> WHERE <relation column> = Post.id)`
+///
+/// Orderings by the relevance of a full-text search order by the relevance expression of `fulltext`.
+///
+/// Not all databases support `NULLS FIRST` / `NULLS LAST`, explicit null orderings are therefore preceded by
+/// an ordering by `(SELECT COUNT(*) FROM <model table> WHERE <id> = <id of the ordered record> AND <value> IS NULL)`,
+/// which is 1 for nulls and 0 otherwise.
 impl Ordering {
-    pub fn for_model(model: ModelRef, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static> {
+    const RELATION_TABLE_ALIAS: &'static str = "prismaOrderByRelation";
+    const RELATED_TABLE_ALIAS: &'static str = "prismaOrderByRelated";
+    const NULLS_TABLE_ALIAS: &'static str = "prismaOrderByNulls";
+
+    pub fn for_model(model: ModelRef, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static> {
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
-            model.fields().id().as_column(),
-            order_by,
+            &model,
+            order_by.iter().map(|oby| (Self::value(&oby.field), oby)).collect(),
+            model.id_columns(),
+            model.id_columns(),
             reverse,
         )
     }
 
-    pub fn internal<C>(second_field: C, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static>
+    pub fn internal<C>(model: ModelRef, second_field: C, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static>
     where
         C: Into<Column<'static>>,
     {
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
-            second_field.into(),
-            order_by,
+            &model,
+            order_by.iter().map(|oby| (Self::value(&oby.field), oby)).collect(),
+            model.id_columns(),
+            vec![second_field.into()],
             reverse,
         )
     }
 
+    /// Orderings on groups of records. Groups are unique by their grouped columns, which therefore
+    /// take the place of the secondary column and order the groups ascending by default.
+    pub fn for_groups(
+        model: &ModelRef,
+        order_by: &[OrderBy],
+        grouped_columns: &[Column<'static>],
+    ) -> OrderVec<'static> {
+        let values: Vec<DatabaseValue<'static>> = order_by.iter().map(|oby| Self::value(&oby.field)).collect();
+
+        let mut orderings: OrderVec<'static> = values
+            .iter()
+            .zip(order_by)
+            .flat_map(|(value, oby)| {
+                let nulls = oby.null_ordering.map(|_| Self::nulls(model, value.clone(), &[]));
+                Self::order_definition(value.clone(), nulls, Self::sort_order(oby), oby.null_ordering, false)
+            })
+            .collect();
+
+        for column in grouped_columns {
+            let value = DatabaseValue::from(column.clone());
+
+            if values.iter().all(|ordered| ordered != &value) {
+                orderings.push((value, Some(Order::Asc)));
+            }
+        }
+
+        orderings
+    }
+
+    /// Orderings on an aliased subquery. The secondary column is expected to hold the id of the ordered
+    /// record, subselects of orderings on related records are correlated to it.
     pub fn aliased_internal(
+        model: ModelRef,
         alias: &str,
         secondary_alias: &str,
         secondary_field: &str,
-        order_by: Option<&OrderBy>,
+        order_by: &[OrderBy],
         reverse: bool,
     ) -> OrderVec<'static> {
+        let secondary_column: Column<'static> = (secondary_alias.to_string(), secondary_field.to_string()).into();
+
         Self::by_fields(
-            order_by.map(|oby| (alias.to_string(), oby.field.db_name().to_string()).into()),
-            (secondary_alias.to_string(), secondary_field.to_string()).into(),
-            order_by,
+            &model,
+            order_by
+                .iter()
+                .map(|oby| {
+                    let value = match oby.field {
+                        OrderByField::Scalar(ref sf) => {
+                            Column::from((alias.to_string(), sf.db_name().to_string())).into()
+                        }
+                        ref field => Self::correlated_value(field, secondary_column.clone()),
+                    };
+
+                    (value, oby)
+                })
+                .collect(),
+            vec![secondary_column.clone()],
+            vec![secondary_column],
             reverse,
         )
     }
 
+    /// The value to order by in a query on the table of the ordered model.
+    pub fn value(field: &OrderByField) -> DatabaseValue<'static> {
+        let id_column = match field {
+            OrderByField::Scalar(sf) => return sf.as_column().into(),
+            OrderByField::Relevance(sf, query) => return fulltext::relevance(sf, query, None),
+            OrderByField::RelatedScalar(rf, _) | OrderByField::RelationCount(rf) => rf.model().id_column(),
+        };
+
+        Self::correlated_value(field, id_column)
+    }
+
+    /// Scalar fields are ordered by their column, orderings on related records and relevance by a subselect
+    /// correlated to the given id column of the ordered record.
+    fn correlated_value(field: &OrderByField, id_column: Column<'static>) -> DatabaseValue<'static> {
+        let relation_alias = Self::RELATION_TABLE_ALIAS;
+        let related_alias = Self::RELATED_TABLE_ALIAS;
+
+        let select = match field {
+            OrderByField::Scalar(sf) => return sf.as_column().into(),
+            OrderByField::Relevance(sf, query) => {
+                let model = sf.model();
+
+                Select::from_table(model.table().alias(related_alias))
+                    .value(fulltext::relevance(sf, query, Some(related_alias.to_string())))
+                    .so_that(model.id_column().table(related_alias).equals(id_column))
+            }
+            OrderByField::RelatedScalar(rf, sf) => {
+                let related_model = rf.related_model();
+                let join = related_model.table().alias(related_alias).on(related_model
+                    .id_column()
+                    .table(related_alias)
+                    .equals(rf.opposite_column().table(relation_alias)));
+
+                Select::from_table(rf.relation().relation_table().alias(relation_alias))
+                    .column(sf.as_column().table(related_alias))
+                    .inner_join(join)
+                    .so_that(rf.relation_column().table(relation_alias).equals(id_column))
+            }
+            OrderByField::RelationCount(rf) => Select::from_table(rf.relation().relation_table().alias(relation_alias))
+                .value(count(asterisk()))
+                .so_that(rf.relation_column().table(relation_alias).equals(id_column)),
+        };
+
+        select.into()
+    }
+
     fn by_fields(
-        first_column: Option<Column<'static>>,
-        second_column: Column<'static>,
-        order_by: Option<&OrderBy>,
+        model: &ModelRef,
+        values: Vec<(DatabaseValue<'static>, &OrderBy)>,
+        id_columns: Vec<Column<'static>>,
+        second_columns: Vec<Column<'static>>,
         reverse: bool,
     ) -> OrderVec<'static> {
-        let default_order = order_by
-            .as_ref()
-            .map(|order| order.sort_order)
-            .unwrap_or(SortOrder::Ascending);
-
-        match first_column {
-            Some(first) => {
-                if first != second_column {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![first.descend(), second_column.descend()],
-                        (SortOrder::Descending, true) => vec![first.ascend(), second_column.descend()],
-                        (SortOrder::Ascending, false) => vec![first.ascend(), second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![first.descend(), second_column.ascend()],
-                    }
-                } else {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![second_column.descend()],
-                        (SortOrder::Descending, true) => vec![second_column.ascend()],
-                        (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![second_column.descend()],
-                    }
-                }
-            }
-            _ => match (default_order, reverse) {
-                (SortOrder::Ascending, true) => vec![second_column.descend()],
-                (SortOrder::Descending, true) => vec![second_column.ascend()],
-                (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                (SortOrder::Descending, false) => vec![second_column.descend()],
-            },
+        let second_values: Vec<DatabaseValue<'static>> = second_columns
+            .into_iter()
+            .map(DatabaseValue::from)
+            .filter(|second_value| values.iter().all(|(value, _)| value != second_value))
+            .collect();
+
+        let mut orderings: OrderVec<'static> = values
+            .into_iter()
+            .flat_map(|(value, oby)| {
+                let nulls = oby
+                    .null_ordering
+                    .map(|_| Self::nulls(model, value.clone(), &id_columns));
+
+                Self::order_definition(value, nulls, Self::sort_order(oby), oby.null_ordering, reverse)
+            })
+            .collect();
+
+        for second_value in second_values {
+            orderings.extend(Self::order_definition(
+                second_value,
+                None,
+                SortOrder::Ascending,
+                None,
+                reverse,
+            ));
+        }
+
+        orderings
+    }
+
+    /// The order of the value to order by. Lower relevance values are more relevant, relevance is therefore
+    /// ordered in the opposite direction, so that `DESC` returns the most relevant records first.
+    pub fn sort_order(oby: &OrderBy) -> SortOrder {
+        match oby.field {
+            OrderByField::Relevance(_, _) => oby.sort_order.reverse(),
+            _ => oby.sort_order,
+        }
+    }
+
+    /// `(SELECT COUNT(*) FROM <model table> WHERE <ids> = <id columns> AND <value> IS NULL)`, 1 if the value
+    /// of the record with the ids in `id_columns` is null.
+    ///
+    /// Groups have no id, their subselect counts all records of the model if the grouped value is null,
+    /// which are at least the records of the group.
+    fn nulls(
+        model: &ModelRef,
+        value: DatabaseValue<'static>,
+        id_columns: &[Column<'static>],
+    ) -> DatabaseValue<'static> {
+        let alias = Self::NULLS_TABLE_ALIAS;
+
+        let conditions = model
+            .id_columns()
+            .into_iter()
+            .zip(id_columns.iter().cloned())
+            .fold(ConditionTree::single(value.is_null()), |acc, (id, id_column)| {
+                acc.and(id.table(alias).equals(id_column))
+            });
+
+        Select::from_table(model.table().alias(alias))
+            .value(count(asterisk()))
+            .so_that(conditions)
+            .into()
+    }
+
+    /// Reversing an ordering also reverses the position of explicitly ordered nulls,
+    /// so that a reversed result is exactly the original result backwards.
+    ///
+    /// `nulls` is the value that is positive for nulls and 0 otherwise, it's only ordered by if the null ordering
+    /// is explicit.
+    fn order_definition(
+        value: DatabaseValue<'static>,
+        nulls: Option<DatabaseValue<'static>>,
+        sort_order: SortOrder,
+        null_ordering: Option<NullOrdering>,
+        reverse: bool,
+    ) -> OrderVec<'static> {
+        let (sort_order, null_ordering) = if reverse {
+            (sort_order.reverse(), null_ordering.map(NullOrdering::reverse))
+        } else {
+            (sort_order, null_ordering)
+        };
+
+        let order = match sort_order {
+            SortOrder::Ascending => Order::Asc,
+            SortOrder::Descending => Order::Desc,
+        };
+
+        match (nulls, null_ordering) {
+            (Some(nulls), Some(NullOrdering::First)) => vec![(nulls, Some(Order::Desc)), (value, Some(order))],
+            (Some(nulls), Some(NullOrdering::Last)) => vec![(nulls, Some(Order::Asc)), (value, Some(order))],
+            _ => vec![(value, Some(order))],
         }
     }
 }
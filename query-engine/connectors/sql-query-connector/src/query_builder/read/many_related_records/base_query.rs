@@ -8,7 +8,7 @@ pub struct ManyRelatedRecordsBaseQuery<'a> {
     pub selected_fields: &'a SelectedFields,
     pub from_record_ids: &'a [GraphqlId],
     pub query: Select<'a>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
     pub is_reverse_order: bool,
     pub condition: ConditionTree<'a>,
     pub cursor: ConditionTree<'a>,
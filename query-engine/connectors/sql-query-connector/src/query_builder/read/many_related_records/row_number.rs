@@ -17,19 +17,25 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithRowNumber {
 
         let mut base_query = base.query.so_that(conditions);
 
-        if let Some(order_by) = base.order_by.as_ref() {
-            let column = order_by.field.as_column();
+        // The row number window orders by the aliased base table, which requires all ordering columns to be selected.
+        // Orderings on related records are correlated to the selected id of the related model instead.
+        let mut selected_columns = base.selected_fields.columns().to_vec();
 
-            if !base.selected_fields.columns().contains(&column) {
-                base_query = base_query.column(order_by.field.as_column());
+        for field in base.order_by.iter().filter_map(|oby| oby.field.as_scalar()) {
+            let column = field.as_column();
+
+            if !selected_columns.contains(&column) {
+                base_query = base_query.column(column.clone());
+                selected_columns.push(column);
             }
         }
 
         let order_columns = Ordering::aliased_internal(
+            base.from_field.related_model(),
             Self::BASE_TABLE_ALIAS,
             Self::BASE_TABLE_ALIAS,
             SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
+            &base.order_by,
             base.is_reverse_order,
         );
 
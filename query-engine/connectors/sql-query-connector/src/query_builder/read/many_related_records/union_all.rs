@@ -16,8 +16,9 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithUnionAll {
         };
 
         let order_columns = Ordering::internal(
+            base.from_field.related_model(),
             SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
+            &base.order_by,
             base.is_reverse_order,
         );
 
@@ -1,6 +1,6 @@
 mod many_related_records;
 
-use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition, ordering::Ordering};
+use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition, ordering::Ordering, SqlFamily};
 use connector_interface::{
     filter::{Filter, RecordFinder},
     QueryArguments,
@@ -44,8 +44,7 @@ impl SelectDefinition for Select<'static> {
 impl SelectDefinition for QueryArguments {
     fn into_select(self, model: &ModelRef) -> Select<'static> {
         let cursor: ConditionTree = CursorCondition::build(&self, Arc::clone(&model));
-        let order_by = self.order_by;
-        let ordering = Ordering::for_model(Arc::clone(&model), order_by.as_ref(), self.last.is_some());
+        let ordering = Ordering::for_model(Arc::clone(&model), &self.order_by, self.last.is_some());
 
         let filter: ConditionTree = self
             .filter
@@ -105,17 +104,94 @@ impl ReadQueryBuilder {
             .so_that(vhere)
     }
 
-    pub fn count_by_model(model: &ModelRef, query_arguments: QueryArguments) -> Select<'static> {
-        let id_field = model.fields().id();
+    /// Counts the records related to each of the given records, grouped by the id of the record they are related
+    /// to, e.g. `SELECT Relation.A, COUNT(*) FROM Relation WHERE Relation.A IN (...) GROUP BY Relation.A`.
+    /// A filter on the related records is applied as a subselect on the related model. The record ids are split
+    /// into as many queries as needed to stay below the bind parameter limit of the database.
+    pub fn count_related_records(
+        family: SqlFamily,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[GraphqlId],
+        filter: Option<Filter>,
+    ) -> Vec<Select<'static>> {
+        let relation_column = from_field.relation_column().table(Relation::TABLE_ALIAS);
+
+        let related_ids = filter.map(|filter| {
+            let related_model = from_field.related_model();
+
+            Select::from_table(related_model.table())
+                .column(related_model.id_column())
+                .so_that(filter.aliased_cond(None))
+        });
+
+        let filter_params = related_ids
+            .as_ref()
+            .map(|select| family.render(select.clone().into()).1.len())
+            .unwrap_or(0);
+
+        let chunk_size = family.max_bind_values().saturating_sub(filter_params).max(1);
+
+        from_record_ids
+            .chunks(chunk_size)
+            .map(|ids| {
+                let condition = relation_column.clone().in_selection(ids.to_owned());
+
+                let conditions = match related_ids.clone() {
+                    Some(related_ids) => {
+                        let opposite_column = from_field.opposite_column().table(Relation::TABLE_ALIAS);
+                        condition.and(opposite_column.in_selection(related_ids))
+                    }
+                    None => condition.into(),
+                };
+
+                Select::from_table(from_field.relation().relation_table().alias(Relation::TABLE_ALIAS))
+                    .column(relation_column.clone())
+                    .value(count(asterisk()))
+                    .so_that(conditions)
+                    .group_by(relation_column.clone())
+            })
+            .collect()
+    }
+
+    /// Reads the values of the fields of the records matching the query arguments, which are aggregated
+    /// by the connector. The ids are always read, so that there is a column to read for `count`.
+    pub fn aggregate(model: &ModelRef, fields: &SelectedFields, query_arguments: QueryArguments) -> Select<'static> {
+        // Unlike record selections, aggregations don't need the additional record to check for more data.
+        let limit = query_arguments.last.or(query_arguments.first);
+        let select = Self::get_records(model, fields, query_arguments);
+
+        match limit {
+            Some(limit) => select.limit(limit as usize),
+            None => select,
+        }
+    }
 
-        let mut selected_fields = SelectedFields::default();
-        selected_fields.add_scalar(id_field.clone());
+    /// Reads the values of the grouped fields and the aggregated fields of all records matching the filter, the
+    /// grouped fields first. The records are in the order of their groups, which are grouped, aggregated and
+    /// paginated by the connector.
+    pub fn group_by(
+        model: &ModelRef,
+        group_by: &[ScalarFieldRef],
+        aggregated_fields: &[ScalarFieldRef],
+        query_arguments: QueryArguments,
+    ) -> Select<'static> {
+        let columns: Vec<Column<'static>> = group_by.iter().map(|field| field.as_column()).collect();
+        let ordering = Ordering::for_groups(model, &query_arguments.order_by, &columns);
+
+        let filter: ConditionTree = query_arguments
+            .filter
+            .map(|f| f.aliased_cond(None))
+            .unwrap_or(ConditionTree::NoCondition);
 
-        let base_query = Self::get_records(model, &selected_fields, query_arguments);
-        let table = Table::from(base_query).alias("sub");
-        let column = Column::from(("sub", id_field.db_name().to_string()));
+        let select = columns
+            .into_iter()
+            .chain(aggregated_fields.iter().map(|field| field.as_column()))
+            .fold(Select::from_table(model.table()), |select, column| {
+                select.column(column)
+            })
+            .so_that(filter);
 
-        Select::from_table(table).value(count(column))
+        ordering.into_iter().fold(select, |select, ord| select.order_by(ord))
     }
 
     pub fn count_by_table(database: &str, table: &str) -> Select<'static> {
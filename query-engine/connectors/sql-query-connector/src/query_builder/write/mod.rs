@@ -1,4 +1,5 @@
-use crate::error::SqlError;
+use crate::{error::SqlError, AliasedCondition};
+use connector_interface::filter::Filter;
 use prisma_models::prelude::*;
 use quaint::ast::*;
 use std::convert::TryFrom;
@@ -10,19 +11,7 @@ impl WriteQueryBuilder {
     const PARAMETER_LIMIT: usize = 10000;
 
     pub fn create_record(model: &ModelRef, mut args: PrismaArgs) -> (Insert<'static>, Option<GraphqlId>) {
-        let id_field = model.fields().id();
-
-        let return_id = match args.get_field_value(&id_field.name) {
-            _ if id_field.is_auto_generated => None,
-            Some(PrismaValue::Null) | None => {
-                let id = model.generate_id();
-                args.insert(id_field.name.as_str(), id.clone());
-                Some(id)
-            }
-            Some(prisma_value) => {
-                Some(GraphqlId::try_from(prisma_value).expect("Could not convert prisma value to graphqlid"))
-            }
-        };
+        let return_id = Self::assign_id(model, &mut args);
 
         let fields: Vec<&Field> = model
             .fields()
@@ -31,9 +20,12 @@ impl WriteQueryBuilder {
             .filter(|field| args.has_arg_for(&field.name()))
             .collect();
 
-        let fields = fields
-            .iter()
-            .map(|field| (field.db_name(), args.take_field_value(field.name()).unwrap()));
+        let fields = fields.iter().map(|field| {
+            (
+                field.db_name(),
+                Self::column_value(args.take_field_value(field.name()).unwrap()),
+            )
+        });
 
         let base = Insert::single_into(model.table());
 
@@ -41,7 +33,109 @@ impl WriteQueryBuilder {
             .into_iter()
             .fold(base, |acc, (name, value)| acc.value(name.into_owned(), value));
 
-        (Insert::from(insert).returning(vec![id_field.as_column()]), return_id)
+        (Insert::from(insert).returning(model.id_columns()), return_id)
+    }
+
+    /// Creates many records with multi-row inserts. Records setting the same fields are inserted together,
+    /// each insert holding at most `max_rows` records and `max_bind_values` values.
+    /// Records violating a unique constraint are skipped if `skip_duplicates` is set.
+    pub fn create_records(
+        model: &ModelRef,
+        args: Vec<PrismaArgs>,
+        skip_duplicates: bool,
+        max_rows: Option<usize>,
+        max_bind_values: usize,
+    ) -> Vec<Insert<'static>> {
+        let mut batches: Vec<(Vec<String>, Vec<Vec<DatabaseValue<'static>>>)> = Vec::new();
+
+        for mut args in args {
+            Self::assign_id(model, &mut args);
+
+            let fields: Vec<&Field> = model
+                .fields()
+                .all
+                .iter()
+                .filter(|field| args.has_arg_for(&field.name()))
+                .collect();
+
+            let columns: Vec<String> = fields.iter().map(|field| field.db_name().into_owned()).collect();
+            let row: Vec<DatabaseValue<'static>> = fields
+                .iter()
+                .map(|field| Self::column_value(args.take_field_value(field.name()).unwrap()))
+                .collect();
+
+            match batches.iter_mut().find(|(batch_columns, _)| batch_columns == &columns) {
+                Some((_, rows)) => rows.push(row),
+                None => batches.push((columns, vec![row])),
+            }
+        }
+
+        let mut inserts = Vec::new();
+
+        for (columns, rows) in batches {
+            let rows_per_insert = (max_bind_values / columns.len().max(1))
+                .min(max_rows.unwrap_or(usize::max_value()))
+                .max(1);
+
+            for chunk in rows.chunks(rows_per_insert) {
+                let insert = chunk
+                    .iter()
+                    .fold(Insert::multi_into(model.table(), columns.clone()), |acc, row| {
+                        acc.values(row.clone())
+                    });
+
+                let insert = Insert::from(insert);
+
+                if skip_duplicates {
+                    inserts.push(insert.on_conflict(OnConflict::DoNothing));
+                } else {
+                    inserts.push(insert);
+                }
+            }
+        }
+
+        inserts
+    }
+
+    /// The value written to a column. Lists are the values of embedded scalar lists, written to native array
+    /// columns.
+    fn column_value(value: PrismaValue) -> DatabaseValue<'static> {
+        match value {
+            PrismaValue::List(_) => value.into_parameter().into(),
+            value => value.into(),
+        }
+    }
+
+    /// Sets the id of a record to be created if it isn't generated by the database.
+    /// Returns the id if it is known before inserting the record.
+    /// Compound ids are never generated, all of their parts are given with the record.
+    fn assign_id(model: &ModelRef, args: &mut PrismaArgs) -> Option<GraphqlId> {
+        let id_field = match model.fields().id() {
+            Some(id_field) => id_field,
+            None => {
+                return model
+                    .id_fields()
+                    .iter()
+                    .map(|field| {
+                        args.get_field_value(&field.name)
+                            .and_then(|value| GraphqlId::try_from(value).ok())
+                    })
+                    .collect::<Option<Vec<GraphqlId>>>()
+                    .map(GraphqlId::from_parts)
+            }
+        };
+
+        match args.get_field_value(&id_field.name) {
+            _ if id_field.is_auto_generated => None,
+            Some(PrismaValue::Null) | None => {
+                let id = model.generate_id();
+                args.insert(id_field.name.as_str(), id.clone());
+                Some(id)
+            }
+            Some(prisma_value) => {
+                Some(GraphqlId::try_from(prisma_value).expect("Could not convert prisma value to graphqlid"))
+            }
+        }
     }
 
     pub fn create_relation(field: &RelationFieldRef, parent_id: &GraphqlId, child_id: &GraphqlId) -> Query<'static> {
@@ -57,8 +151,8 @@ impl WriteQueryBuilder {
                 };
 
                 let update_condition = match field.relation_is_inlined_in_parent() {
-                    true => field.model().fields().id().as_column().equals(update_id),
-                    false => field.related_model().fields().id().as_column().equals(update_id),
+                    true => Self::id_condition(&field.model(), update_id),
+                    false => Self::id_condition(&field.related_model(), update_id),
                 };
 
                 Update::table(relation.relation_table())
@@ -98,8 +192,8 @@ impl WriteQueryBuilder {
                 };
 
                 let update_condition = match field.relation_is_inlined_in_parent() {
-                    true => field.model().fields().id().as_column().equals(update_id),
-                    false => field.related_model().fields().id().as_column().equals(update_id),
+                    true => Self::id_condition(&field.model(), update_id),
+                    false => Self::id_condition(&field.related_model(), update_id),
                 };
 
                 Update::table(relation.relation_table())
@@ -129,9 +223,9 @@ impl WriteQueryBuilder {
             Some(referencing_column) => {
                 let referencing_column_name = referencing_column.name.to_string();
                 let update_condition = if field.relation_is_inlined_in_parent() {
-                    field.model().fields().id().as_column().equals(parent_id)
+                    Self::id_condition(&field.model(), parent_id)
                 } else {
-                    referencing_column.equals(parent_id)
+                    referencing_column.equals(parent_id).into()
                 };
 
                 Update::table(relation.relation_table())
@@ -191,6 +285,62 @@ impl WriteQueryBuilder {
             return Ok(Vec::new());
         }
 
+        let query = Self::update_base(model, args)?;
+
+        let result: Vec<Update> = ids
+            .chunks(Self::PARAMETER_LIMIT)
+            .into_iter()
+            .map(|ids| query.clone().so_that(Self::ids_condition(model, ids)))
+            .collect();
+
+        Ok(result)
+    }
+
+    /// Selects the ids and the documents of the Json fields of the records, which path updates are applied to.
+    pub fn select_json_documents(
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        ids: &[&GraphqlId],
+    ) -> Vec<Select<'static>> {
+        let columns: Vec<Column<'static>> = model
+            .id_columns()
+            .into_iter()
+            .chain(fields.iter().map(|field| field.as_column()))
+            .collect();
+
+        ids.chunks(Self::PARAMETER_LIMIT)
+            .map(|chunk| {
+                columns
+                    .iter()
+                    .cloned()
+                    .fold(Select::from_table(model.table()), |select, column| {
+                        select.column(column)
+                    })
+                    .so_that(Self::ids_condition(model, chunk))
+            })
+            .collect()
+    }
+
+    /// Writes the changed Json documents of a record.
+    pub fn update_json_documents(
+        model: &ModelRef,
+        id: &GraphqlId,
+        documents: Vec<(ScalarFieldRef, serde_json::Value)>,
+    ) -> Update<'static> {
+        documents
+            .into_iter()
+            .fold(Update::table(model.table()), |update, (field, document)| {
+                update.set(field.db_name().to_string(), PrismaValue::Json(document))
+            })
+            .so_that(Self::id_condition(model, id))
+    }
+
+    /// A single update of all records matching the filter.
+    pub fn update_by_filter(model: &ModelRef, filter: Filter, args: &PrismaArgs) -> crate::Result<Update<'static>> {
+        Ok(Self::update_base(model, args)?.so_that(filter.aliased_cond(None)))
+    }
+
+    fn update_base(model: &ModelRef, args: &PrismaArgs) -> crate::Result<Update<'static>> {
         let fields = model.fields();
         let mut query = Update::table(model.table());
 
@@ -203,39 +353,35 @@ impl WriteQueryBuilder {
                 });
             }
 
-            query = query.set(field.db_name().to_string(), value.clone());
+            query = query.set(field.db_name().to_string(), Self::column_value(value.clone()));
         }
 
-        let result: Vec<Update> = ids
-            .chunks(Self::PARAMETER_LIMIT)
-            .into_iter()
-            .map(|ids| {
-                query
-                    .clone()
-                    .so_that(fields.id().as_column().in_selection(ids.to_vec()))
-            })
-            .collect();
-
-        Ok(result)
+        Ok(query)
     }
 
     pub fn delete_many(model: &ModelRef, ids: &[&GraphqlId]) -> Vec<Delete<'static>> {
         let mut deletes = Vec::new();
 
         for chunk in ids.chunks(Self::PARAMETER_LIMIT).into_iter() {
-            for lf in model.fields().scalar_list() {
+            for lf in model.fields().scalar_list_non_embedded() {
                 let scalar_list_table = lf.scalar_list_table();
                 let condition = scalar_list_table.node_id_column().in_selection(chunk.to_vec());
                 deletes.push(Delete::from_table(scalar_list_table.table()).so_that(condition));
             }
 
-            let condition = model.fields().id().as_column().in_selection(chunk.to_vec());
+            let condition = Self::ids_condition(model, chunk);
             deletes.push(Delete::from_table(model.table()).so_that(condition));
         }
 
         deletes
     }
 
+    /// A single delete of all records matching the filter. Only valid for models without scalar lists,
+    /// the values of which would be left behind.
+    pub fn delete_by_filter(model: &ModelRef, filter: Filter) -> Delete<'static> {
+        Delete::from_table(model.table()).so_that(filter.aliased_cond(None))
+    }
+
     pub fn update_scalar_list_values(
         scalar_list_table: &ScalarListTable,
         list_value: &PrismaListValue,
@@ -290,7 +436,7 @@ impl WriteQueryBuilder {
             .flat_map(|m| {
                 let tables: Vec<Table> = m
                     .fields()
-                    .scalar_list()
+                    .scalar_list_non_embedded()
                     .iter()
                     .map(|slf| slf.scalar_list_table().table())
                     .collect();
@@ -305,6 +451,30 @@ impl WriteQueryBuilder {
         tables
     }
 
+    /// `id = ?`, or `a = ? AND b = ?` for the parts of a compound id.
+    fn id_condition(model: &ModelRef, id: &GraphqlId) -> ConditionTree<'static> {
+        let mut conditions = model
+            .id_columns()
+            .into_iter()
+            .zip(id.clone().into_parts())
+            .map(|(column, part)| ConditionTree::single(column.equals(part)));
+
+        let first = conditions.next().unwrap();
+        conditions.fold(first, |acc, condition| acc.and(condition).into())
+    }
+
+    /// `id IN (...)`, or the id conditions of all ids joined with `OR` for compound ids.
+    fn ids_condition(model: &ModelRef, ids: &[&GraphqlId]) -> ConditionTree<'static> {
+        if !model.has_compound_id() {
+            return model.id_column().in_selection(ids.to_vec()).into();
+        }
+
+        let mut conditions = ids.iter().map(|id| Self::id_condition(model, id));
+        let first = conditions.next().unwrap_or(ConditionTree::NoCondition);
+
+        conditions.fold(first, |acc, condition| acc.or(condition).into())
+    }
+
     fn delete_in_chunks<F>(table: Table<'static>, ids: &[&GraphqlId], conditions: F) -> Vec<Delete<'static>>
     where
         F: Fn(&[&GraphqlId]) -> Compare<'static>,
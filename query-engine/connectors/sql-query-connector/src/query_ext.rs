@@ -1,15 +1,19 @@
-use crate::{error::*, query_builder::ReadQueryBuilder, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
+use crate::{
+    error::*, query_builder::ReadQueryBuilder, query_log::LoggedQuery, AliasedCondition, RawQuery, SqlFamily, SqlRow,
+    ToSqlRow,
+};
+use async_trait::async_trait;
 use connector_interface::{
     error::RecordFinderInfo,
     filter::{Filter, RecordFinder},
 };
-use async_trait::async_trait;
 use prisma_models::*;
 use quaint::{
     ast::*,
     connector::{self, Queryable},
+    error::Error as QueryError,
 };
-use serde_json::{Map, Number, Value};
+use serde_json::{Map, Value};
 use std::convert::TryFrom;
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
@@ -18,7 +22,8 @@ impl<'t> QueryExt for connector::Transaction<'t> {}
 /// Basically represents a connection wrapper?
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
-    async fn filter(&self, q: Query<'_>, idents: &[TypeIdentifier]) -> crate::Result<Vec<SqlRow>> {
+    async fn filter(&self, family: SqlFamily, q: Query<'_>, idents: &[TypeIdentifier]) -> crate::Result<Vec<SqlRow>> {
+        let logged = LoggedQuery::start(family, &q);
         let result_set = self.query(q).await?;
         let mut sql_rows = Vec::new();
 
@@ -26,46 +31,77 @@ pub trait QueryExt: Queryable + Send + Sync {
             sql_rows.push(row.to_sql_row(idents)?);
         }
 
+        logged.finish(Some(sql_rows.len()));
+
         Ok(sql_rows)
     }
 
-    async fn raw_json(&self, q: RawQuery) -> crate::Result<Value> {
-        if q.is_select() {
-            let result_set = self.query_raw(q.0.as_str(), &[]).await?;
-            let columns: Vec<String> = result_set.columns().map(ToString::to_string).collect();
-            let mut result = Vec::new();
+    /// Executes a statement, recording it in the query log. Returns the id of an inserted row, if any.
+    async fn execute_logged(&self, family: SqlFamily, q: Query<'_>) -> Result<Option<Id>, QueryError> {
+        let logged = LoggedQuery::start(family, &q);
+        let id = self.execute(q).await?;
+
+        logged.finish(None);
+
+        Ok(id)
+    }
 
-            for row in result_set.into_iter() {
-                let mut object = Map::new();
+    /// Executes a rendered statement, recording it in the query log. Returns the number of affected rows.
+    async fn execute_raw_logged(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QueryError> {
+        let logged = LoggedQuery::start_raw(sql, params);
+        let changes = self.execute_raw(sql, params).await?;
 
-                for (idx, p_value) in row.into_iter().enumerate() {
-                    let column_name: String = columns[idx].clone();
-                    object.insert(column_name, Value::from(p_value));
-                }
+        logged.finish(Some(changes as usize));
+
+        Ok(changes)
+    }
+
+    /// Runs a raw query and returns the rows as a JSON array of objects, keyed by column name.
+    async fn raw_json(&self, q: RawQuery) -> crate::Result<Value> {
+        let logged = LoggedQuery::start_raw(q.sql.as_str(), q.parameters.as_slice());
+        let result_set = self.query_raw(q.sql.as_str(), q.parameters.as_slice()).await?;
+        let columns: Vec<String> = result_set.columns().map(ToString::to_string).collect();
+        let mut result = Vec::new();
 
-                result.push(Value::Object(object));
+        for row in result_set.into_iter() {
+            let mut object = Map::new();
+
+            for (idx, p_value) in row.into_iter().enumerate() {
+                let column_name: String = columns[idx].clone();
+                object.insert(column_name, Value::from(p_value));
             }
 
-            Ok(Value::Array(result))
-        } else {
-            let changes = self.execute_raw(q.0.as_str(), &[]).await?;
-            Ok(Value::Number(Number::from(changes)))
+            result.push(Value::Object(object));
         }
+
+        logged.finish(Some(result.len()));
+
+        Ok(Value::Array(result))
+    }
+
+    /// Runs a raw statement and returns the number of affected rows.
+    async fn raw_count(&self, q: RawQuery) -> crate::Result<usize> {
+        let changes = self.execute_raw_logged(q.sql.as_str(), q.parameters.as_slice()).await?;
+
+        Ok(changes as usize)
     }
 
     /// Find one full record selecting all scalar fields.
-    async fn find_record(&self, record_finder: &RecordFinder) -> crate::Result<SingleRecord> {
+    async fn find_record(&self, family: SqlFamily, record_finder: &RecordFinder) -> crate::Result<SingleRecord> {
         use SqlError::*;
 
-        let model = record_finder.field.model();
+        let model = record_finder.model();
         let selected_fields = SelectedFields::from(&model);
         let select = ReadQueryBuilder::get_records(&model, &selected_fields, record_finder);
         let idents = selected_fields.type_identifiers();
 
-        let row = self.find(select, idents.as_slice()).await.map_err(|e| match e {
-            RecordDoesNotExist => RecordNotFoundForWhere(RecordFinderInfo::from(record_finder)),
-            e => e,
-        })?;
+        let row = self
+            .find(family, select, idents.as_slice())
+            .await
+            .map_err(|e| match e {
+                RecordDoesNotExist => RecordNotFoundForWhere(RecordFinderInfo::from(record_finder)),
+                e => e,
+            })?;
 
         let record = Record::from(row);
 
@@ -73,8 +109,8 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[TypeIdentifier]) -> crate::Result<SqlRow> {
-        self.filter(q.limit(1).into(), idents)
+    async fn find(&self, family: SqlFamily, q: Select<'_>, idents: &[TypeIdentifier]) -> crate::Result<SqlRow> {
+        self.filter(family, q.limit(1).into(), idents)
             .await?
             .into_iter()
             .next()
@@ -82,10 +118,10 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an integer.
-    async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
+    async fn find_int(&self, family: SqlFamily, q: Select<'_>) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[TypeIdentifier::Int])
+            .find(family, q, &[TypeIdentifier::Int])
             .await?
             .values
             .into_iter()
@@ -96,12 +132,12 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an `GraphqlId`.
-    async fn find_id(&self, record_finder: &RecordFinder) -> crate::Result<GraphqlId> {
-        let model = record_finder.field.model();
+    async fn find_id(&self, family: SqlFamily, record_finder: &RecordFinder) -> crate::Result<GraphqlId> {
+        let model = record_finder.model();
         let filter = Filter::from(record_finder.clone());
 
         let id = self
-            .filter_ids(&model, filter)
+            .filter_ids(family, &model, filter)
             .await?
             .into_iter()
             .next()
@@ -110,23 +146,41 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(id)
     }
 
-    /// Read the all columns as an `GraphqlId`
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<GraphqlId>> {
-        let select = Select::from_table(model.table())
-            .column(model.fields().id().as_column())
+    /// Read the ids of all records matching the filter.
+    async fn filter_ids(&self, family: SqlFamily, model: &ModelRef, filter: Filter) -> crate::Result<Vec<GraphqlId>> {
+        let id_columns = model.id_columns();
+        let id_count = id_columns.len();
+
+        let select = id_columns
+            .into_iter()
+            .fold(Select::from_table(model.table()), |select, column| {
+                select.column(column)
+            })
             .so_that(filter.aliased_cond(None));
 
-        self.select_ids(select).await
+        self.select_ids(family, select, id_count).await
     }
 
-    async fn select_ids(&self, select: Select<'_>) -> crate::Result<Vec<GraphqlId>> {
-        let mut rows = self.filter(select.into(), &[TypeIdentifier::GraphQLID]).await?;
+    /// Read the selected id columns as one `GraphqlId` per row, rows with more than one id column
+    /// as a compound id.
+    async fn select_ids(
+        &self,
+        family: SqlFamily,
+        select: Select<'_>,
+        id_count: usize,
+    ) -> crate::Result<Vec<GraphqlId>> {
+        let idents = vec![TypeIdentifier::GraphQLID; id_count];
+        let mut rows = self.filter(family, select.into(), &idents).await?;
         let mut result = Vec::new();
 
         for mut row in rows.drain(0..) {
-            for value in row.values.drain(0..) {
-                result.push(GraphqlId::try_from(value)?)
-            }
+            let ids = row
+                .values
+                .drain(0..)
+                .map(GraphqlId::try_from)
+                .collect::<Result<Vec<GraphqlId>, _>>()?;
+
+            result.push(GraphqlId::from_parts(ids));
         }
 
         Ok(result)
@@ -136,12 +190,14 @@ pub trait QueryExt: Queryable + Send + Sync {
     /// the given parameters. A more restrictive version of `get_ids_by_parents`.
     async fn find_id_by_parent(
         &self,
+        family: SqlFamily,
         parent_field: &RelationFieldRef,
         parent_id: &GraphqlId,
         selector: &Option<RecordFinder>,
     ) -> crate::Result<GraphqlId> {
         let ids = self
             .filter_ids_by_parents(
+                family,
                 parent_field,
                 vec![parent_id],
                 selector.clone().map(Filter::from),
@@ -163,6 +219,7 @@ pub trait QueryExt: Queryable + Send + Sync {
     /// a `Filter` for extra filtering.
     async fn filter_ids_by_parents(
         &self,
+        family: SqlFamily,
         parent_field: &RelationFieldRef,
         parent_ids: Vec<&GraphqlId>,
         selector: Option<Filter>,
@@ -176,12 +233,7 @@ pub trait QueryExt: Queryable + Send + Sync {
             .column(child_id_field)
             .so_that(parent_id_field.in_selection(parent_ids));
 
-        let conditions = related_model
-            .fields()
-            .id()
-            .db_name()
-            .to_string()
-            .in_selection(subselect);
+        let conditions = related_model.id_column().in_selection(subselect);
 
         let conditions = match selector {
             Some(into_cond) => {
@@ -192,9 +244,9 @@ pub trait QueryExt: Queryable + Send + Sync {
         };
 
         let select = Select::from_table(related_model.table())
-            .column(related_model.fields().id().as_column())
+            .column(related_model.id_column())
             .so_that(conditions);
 
-        self.select_ids(select).await
+        self.select_ids(family, select, 1).await
     }
 }
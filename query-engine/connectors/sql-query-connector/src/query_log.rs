@@ -0,0 +1,63 @@
+use crate::SqlFamily;
+use connector_interface::QueryLog;
+use quaint::ast::{ParameterizedValue, Query};
+use std::{borrow::Cow, time::Instant};
+
+/// Times a statement and records it in the query log of the current request. Does nothing if the request
+/// doesn't log its queries.
+///
+/// The statement is recorded when the `LoggedQuery` is dropped, so failed statements returning early with an
+/// error are recorded as well, without rows.
+pub(crate) struct LoggedQuery {
+    pending: Option<(QueryLog, String, Vec<ParameterizedValue<'static>>, Instant)>,
+    rows: Option<usize>,
+}
+
+impl LoggedQuery {
+    pub fn start(family: SqlFamily, query: &Query<'_>) -> Self {
+        let pending = QueryLog::current().map(|log| {
+            let (sql, params) = family.render(query.clone());
+            (log, sql, params.iter().map(owned_value).collect(), Instant::now())
+        });
+
+        Self { pending, rows: None }
+    }
+
+    pub fn start_raw(sql: &str, params: &[ParameterizedValue<'_>]) -> Self {
+        let pending = QueryLog::current().map(|log| {
+            let params = params.iter().map(owned_value).collect();
+            (log, sql.to_string(), params, Instant::now())
+        });
+
+        Self { pending, rows: None }
+    }
+
+    /// Records the statement as successful.
+    pub fn finish(mut self, rows: Option<usize>) {
+        self.rows = rows;
+    }
+}
+
+impl Drop for LoggedQuery {
+    fn drop(&mut self) {
+        if let Some((log, sql, params, start)) = self.pending.take() {
+            log.record(sql, params, start.elapsed(), self.rows);
+        }
+    }
+}
+
+/// Copies a bound value out of the statement it was bound to, to keep it in the log.
+fn owned_value(value: &ParameterizedValue<'_>) -> ParameterizedValue<'static> {
+    match value {
+        ParameterizedValue::Null => ParameterizedValue::Null,
+        ParameterizedValue::Integer(i) => ParameterizedValue::Integer(*i),
+        ParameterizedValue::Real(f) => ParameterizedValue::Real(*f),
+        ParameterizedValue::Text(s) => ParameterizedValue::Text(Cow::Owned(s.to_string())),
+        ParameterizedValue::Boolean(b) => ParameterizedValue::Boolean(*b),
+        ParameterizedValue::Char(c) => ParameterizedValue::Char(*c),
+        ParameterizedValue::Array(values) => ParameterizedValue::Array(values.iter().map(owned_value).collect()),
+        ParameterizedValue::Json(json) => ParameterizedValue::Json(json.clone()),
+        ParameterizedValue::Uuid(uuid) => ParameterizedValue::Uuid(*uuid),
+        ParameterizedValue::DateTime(dt) => ParameterizedValue::DateTime(*dt),
+    }
+}
@@ -1,21 +1,17 @@
-pub struct RawQuery(pub String);
+use prisma_models::PrismaValue;
+use quaint::ast::ParameterizedValue;
 
-#[allow(dead_code)]
-impl RawQuery {
-    pub fn is_select(&self) -> bool {
-        let splitted: Vec<&str> = self.0.split(" ").collect();
-        splitted
-            .first()
-            .map(|t| t.to_uppercase().trim() == "SELECT")
-            .unwrap_or(false)
-    }
+/// A raw SQL query written by the user, with its parameters in the order of the placeholders in the query.
+pub struct RawQuery {
+    pub sql: String,
+    pub parameters: Vec<ParameterizedValue<'static>>,
 }
 
-impl<T> From<T> for RawQuery
-where
-    T: Into<String>,
-{
-    fn from(s: T) -> Self {
-        RawQuery(s.into())
+impl RawQuery {
+    pub fn new(sql: String, parameters: Vec<PrismaValue>) -> Self {
+        Self {
+            sql,
+            parameters: parameters.into_iter().map(PrismaValue::into_parameter).collect(),
+        }
     }
 }
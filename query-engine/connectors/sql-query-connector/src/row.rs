@@ -1,11 +1,12 @@
 use crate::error::SqlError;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use prisma_models::{EnumValue, GraphqlId, PrismaValue, Record, TypeIdentifier};
 use quaint::{
     ast::{DatabaseValue, ParameterizedValue},
     connector::ResultRow,
 };
-use std::{borrow::Borrow, io};
+use std::{borrow::Borrow, io, str::FromStr};
 use uuid::Uuid;
 
 /// An allocated representation of a `Row` returned from the database.
@@ -33,99 +34,17 @@ impl ToSqlRow for ResultRow {
         let row_width = idents.len();
 
         for (i, p_value) in self.into_iter().enumerate().take(row_width) {
-            let pv = match idents[i] {
-                TypeIdentifier::GraphQLID | TypeIdentifier::Relation => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(s) => {
-                        let id = Uuid::parse_str(s.borrow())
-                            .map(|uuid| GraphqlId::UUID(uuid))
-                            .unwrap_or_else(|_| GraphqlId::String(s.into_owned()));
-
-                        PrismaValue::GraphqlId(id)
-                    }
-                    ParameterizedValue::Integer(i) => PrismaValue::GraphqlId(GraphqlId::Int(i as usize)),
-                    ParameterizedValue::Uuid(u) => PrismaValue::GraphqlId(GraphqlId::UUID(u)),
-                    _ => {
-                        let error =
-                            io::Error::new(io::ErrorKind::InvalidData, "ID value not stored as string, int or uuid");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Boolean => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Integer(i) => PrismaValue::Boolean(i != 0),
-                    ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Bool value not stored as bool or int");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Enum => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(cow) => PrismaValue::Enum(EnumValue::from(cow.into_owned())),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Enum value not stored as text");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Json => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(json) => PrismaValue::Json(serde_json::from_str(json.borrow())?),
-                    ParameterizedValue::Json(json) => PrismaValue::Json(json),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::UUID => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(uuid) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
-                    ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Uuid value not stored as text or uuid");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::DateTime => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
-                    ParameterizedValue::Integer(ts) => {
-                        let nsecs = ((ts % 1000) * 1_000_000) as u32;
-                        let secs = (ts / 1000) as i64;
-                        let naive = chrono::NaiveDateTime::from_timestamp(secs, nsecs);
-                        let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
-
-                        PrismaValue::DateTime(datetime)
-                    }
-                    ParameterizedValue::Text(dt_string) => {
-                        let dt = DateTime::parse_from_rfc3339(dt_string.borrow())
-                            .or_else(|_| DateTime::parse_from_rfc2822(dt_string.borrow()))
-                            .expect(&format!("Could not parse stored DateTime string: {}", dt_string));
-
-                        PrismaValue::DateTime(dt.with_timezone(&Utc))
-                    }
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            "DateTime value not stored as datetime, int or text",
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Float => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Real(f) => PrismaValue::Float(f),
-                    ParameterizedValue::Integer(i) => PrismaValue::Float(i as f64),
-                    ParameterizedValue::Text(s) => PrismaValue::Float(s.parse().unwrap()),
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            "Float value not stored as float, int or text",
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                _ => PrismaValue::from(p_value),
+            let pv = match p_value {
+                // Embedded scalar lists are stored in native array columns on PostgreSQL.
+                ParameterizedValue::Array(values) => {
+                    let values = values
+                        .into_iter()
+                        .map(|value| row_value(value, idents[i]))
+                        .collect::<crate::Result<Vec<_>>>()?;
+
+                    PrismaValue::List(Some(values))
+                }
+                p_value => row_value(p_value, idents[i])?,
             };
 
             row.values.push(pv);
@@ -135,6 +54,149 @@ impl ToSqlRow for ResultRow {
     }
 }
 
+/// Converts a single value read from the database to the `PrismaValue` of the given type.
+fn row_value(p_value: ParameterizedValue<'static>, ident: TypeIdentifier) -> crate::Result<PrismaValue> {
+    let pv = match ident {
+        TypeIdentifier::GraphQLID | TypeIdentifier::Relation => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(s) => {
+                let id = Uuid::parse_str(s.borrow())
+                    .map(|uuid| GraphqlId::UUID(uuid))
+                    .unwrap_or_else(|_| GraphqlId::String(s.into_owned()));
+
+                PrismaValue::GraphqlId(id)
+            }
+            ParameterizedValue::Integer(i) => PrismaValue::GraphqlId(GraphqlId::Int(i as usize)),
+            ParameterizedValue::Uuid(u) => PrismaValue::GraphqlId(GraphqlId::UUID(u)),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "ID value not stored as string, int or uuid");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Boolean => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::Boolean(i != 0),
+            ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bool value not stored as bool or int");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Enum => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(cow) => PrismaValue::Enum(EnumValue::from(cow.into_owned())),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Enum value not stored as text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Json => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(json) => PrismaValue::Json(serde_json::from_str(json.borrow())?),
+            ParameterizedValue::Json(json) => PrismaValue::Json(json),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::UUID => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(uuid) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
+            ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Uuid value not stored as text or uuid");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::DateTime => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
+            ParameterizedValue::Integer(ts) => {
+                let nsecs = ((ts % 1000) * 1_000_000) as u32;
+                let secs = (ts / 1000) as i64;
+                let naive = chrono::NaiveDateTime::from_timestamp(secs, nsecs);
+                let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
+
+                PrismaValue::DateTime(datetime)
+            }
+            ParameterizedValue::Text(dt_string) => {
+                let dt = DateTime::parse_from_rfc3339(dt_string.borrow())
+                    .or_else(|_| DateTime::parse_from_rfc2822(dt_string.borrow()))
+                    .expect(&format!("Could not parse stored DateTime string: {}", dt_string));
+
+                PrismaValue::DateTime(dt.with_timezone(&Utc))
+            }
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "DateTime value not stored as datetime, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Int => match p_value {
+            // Aggregations over integer columns can return floats, e.g. `SUM` on PostgreSQL.
+            ParameterizedValue::Real(f) => PrismaValue::Int(float_to_int(f)?),
+            ParameterizedValue::Text(s) => PrismaValue::Int(parse_int(s.borrow())?),
+            p_value => PrismaValue::from(p_value),
+        },
+        TypeIdentifier::BigInt => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::BigInt(i),
+            // Aggregations over bigint columns return floats on PostgreSQL and MySQL.
+            ParameterizedValue::Real(f) => PrismaValue::BigInt(float_to_int(f)?),
+            ParameterizedValue::Text(s) => PrismaValue::BigInt(parse_int(s.borrow())?),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "BigInt value not stored as int, float or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Float => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Real(f) => PrismaValue::Float(f),
+            ParameterizedValue::Integer(i) => PrismaValue::Float(i as f64),
+            ParameterizedValue::Text(s) => PrismaValue::Float(parse_float(s.borrow())?),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Float value not stored as float, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        // Decimals are read from their string representation where the database returns one. Numeric columns
+        // are read as floats by quaint, which keeps about 15 significant digits.
+        TypeIdentifier::Decimal => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::Decimal(BigDecimal::from(i)),
+            ParameterizedValue::Real(f) => PrismaValue::Decimal(parse_decimal(&f.to_string())?),
+            ParameterizedValue::Text(s) => PrismaValue::Decimal(parse_decimal(s.borrow())?),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as int, float or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        // Bytes are stored base64 encoded, as quaint has no binary parameter type.
+        TypeIdentifier::Bytes => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(s) => PrismaValue::Bytes(parse_bytes(s.borrow())?),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as base64 text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        _ => PrismaValue::from(p_value),
+    };
+
+    Ok(pv)
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SqlId {
     String(String),
@@ -167,3 +229,48 @@ impl From<&SqlId> for DatabaseValue<'static> {
         id.clone().into()
     }
 }
+
+fn parse_decimal(s: &str) -> crate::Result<BigDecimal> {
+    BigDecimal::from_str(s).map_err(|err| {
+        let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid decimal value: {}", err));
+        SqlError::ConversionError(error.into())
+    })
+}
+
+fn parse_bytes(s: &str) -> crate::Result<Vec<u8>> {
+    base64::decode(s).map_err(|err| {
+        let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid base64 value: {}", err));
+        SqlError::ConversionError(error.into())
+    })
+}
+
+fn parse_int(s: &str) -> crate::Result<i64> {
+    s.parse().map_err(|err| {
+        let error = io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid integer value {}: {}", s, err),
+        );
+        SqlError::ConversionError(error.into())
+    })
+}
+
+fn parse_float(s: &str) -> crate::Result<f64> {
+    s.parse().map_err(|err| {
+        let error = io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid float value {}: {}", s, err),
+        );
+        SqlError::ConversionError(error.into())
+    })
+}
+
+/// Converts a float to an integer, failing if it has a fractional part or doesn't fit into an `i64`.
+fn float_to_int(f: f64) -> crate::Result<i64> {
+    // `i64::MAX as f64` rounds up to 2^63, which is out of range.
+    if f.is_finite() && f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 {
+        Ok(f as i64)
+    } else {
+        let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid integer value {}", f));
+        Err(SqlError::ConversionError(error.into()))
+    }
+}
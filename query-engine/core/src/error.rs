@@ -32,6 +32,42 @@ pub enum CoreError {
 
     #[fail(display = "{}", _0)]
     InterpreterError(InterpreterError),
+
+    /// An operation of a document executed in a single transaction failed, all changes were rolled back.
+    #[fail(
+        display = "Operation {} ('{}') failed, the transaction was rolled back: {}",
+        index, key, error
+    )]
+    TransactionOperationError {
+        index: usize,
+        key: String,
+        error: Box<CoreError>,
+    },
+
+    #[fail(
+        display = "Transaction '{}' does not exist, it was either committed, rolled back, or timed out.",
+        _0
+    )]
+    TransactionNotFound(String),
+}
+
+impl CoreError {
+    /// A short name of the kind of the error, used as metrics label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CoreError::QueryGraphError(_) => "query_graph",
+            CoreError::QueryGraphBuilderError(_) => "query_graph_builder",
+            CoreError::ConnectorError(_) => "connector",
+            CoreError::DomainError(_) => "domain",
+            CoreError::QueryParserError(_) => "query_parser",
+            CoreError::UnsupportedFeatureError(_) => "unsupported_feature",
+            CoreError::ConversionError(_) => "conversion",
+            CoreError::SerializationError(_) => "serialization",
+            CoreError::InterpreterError(_) => "interpreter",
+            CoreError::TransactionOperationError { .. } => "transaction_operation",
+            CoreError::TransactionNotFound(_) => "transaction_not_found",
+        }
+    }
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -0,0 +1,58 @@
+use crate::CoreResult;
+use connector::{QueryLogEntry, Transaction};
+use serde_json::Value;
+
+/// Describes how an operation of a query document is executed, see `QueryExecutor::explain`.
+#[derive(Debug, Default)]
+pub struct Explanation {
+    /// The key of the operation in the response.
+    pub key: String,
+
+    /// The query graph built for the operation.
+    pub query_graph: String,
+
+    /// The expression the query graph was translated to.
+    pub expression: String,
+
+    /// The steps the interpreter took evaluating the expression, including the expressions built at runtime.
+    pub interpretation: String,
+
+    /// The statements sent to the database, in order.
+    pub queries: Vec<ExplainedQuery>,
+
+    /// The error the operation failed with, if any.
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ExplainedQuery {
+    pub query: QueryLogEntry,
+
+    /// The output of the `EXPLAIN` of the database for the statement, if requested.
+    /// Only read statements are explained by the database.
+    pub plan: Option<CoreResult<Value>>,
+}
+
+/// Runs the `EXPLAIN` of the database for a logged statement, if it's a read.
+pub(super) async fn explain_statement(
+    tx: &dyn Transaction<'_>,
+    primary_connector: &str,
+    entry: &QueryLogEntry,
+) -> Option<CoreResult<Value>> {
+    if !entry.query.trim_start().to_uppercase().starts_with("SELECT") {
+        return None;
+    }
+
+    let explain = match primary_connector {
+        "sqlite" => "EXPLAIN QUERY PLAN",
+        _ => "EXPLAIN",
+    };
+
+    let query = format!("{} {}", explain, entry.query);
+
+    Some(
+        tx.query_raw_values(query, entry.values.clone())
+            .await
+            .map_err(Into::into),
+    )
+}
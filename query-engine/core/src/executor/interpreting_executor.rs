@@ -1,14 +1,19 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
+use super::{
+    explain::explain_statement, pipeline::QueryPipeline, ExplainedQuery, Explanation, QueryExecutor, TransactionManager,
+};
 use crate::{
-    CoreResult, IrSerializer, QueryDocument, QueryGraph, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response,
+    metrics, CoreError, CoreResult, IrSerializer, QueryDocument, QueryGraph, QueryGraphBuilder, QueryInterpreter,
+    QuerySchemaRef, Response,
 };
-use connector::{Connector, ConnectionLike};
+use connector::{Connector, ConnectionLike, PoolMetrics, QueryLog};
 use async_trait::async_trait;
+use std::{sync::Arc, time::Duration};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
-    connector: C,
+    connector: Arc<C>,
     primary_connector: &'static str,
+    transactions: TransactionManager,
 }
 
 // Todo:
@@ -19,8 +24,9 @@ where
 {
     pub fn new(connector: C, primary_connector: &'static str) -> Self {
         InterpretingExecutor {
-            connector,
+            connector: Arc::new(connector),
             primary_connector,
+            transactions: TransactionManager::default(),
         }
     }
 }
@@ -28,7 +34,7 @@ where
 #[async_trait]
 impl<C> QueryExecutor for InterpretingExecutor<C>
 where
-    C: Connector + Send + Sync,
+    C: Connector + Send + Sync + 'static,
 {
     async fn execute(
         &self,
@@ -52,8 +58,10 @@ where
 
                 if result.is_ok() {
                     tx.commit().await?;
+                    metrics::record_commit();
                 } else {
                     tx.rollback().await?;
+                    metrics::record_rollback();
                 }
 
                 result?
@@ -68,7 +76,115 @@ where
         Ok(results)
     }
 
+    async fn execute_transaction(
+        &self,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<Response>> {
+        let conn = self.connector.get_connection().await?;
+        let queries: Vec<(QueryGraph, IrSerializer)> = QueryGraphBuilder::new(query_schema).build(query_doc)?;
+
+        let tx = conn.start_transaction().await?;
+        let mut results: Vec<Response> = vec![];
+
+        for (index, (query_graph, info)) in queries.into_iter().enumerate() {
+            let key = info.key.clone();
+            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+
+            match QueryPipeline::new(query_graph, interpreter, info).execute().await {
+                Ok(result) => results.push(result),
+                Err(error) => {
+                    tx.rollback().await?;
+                    metrics::record_rollback();
+
+                    return Err(CoreError::TransactionOperationError {
+                        index,
+                        key,
+                        error: Box::new(error),
+                    });
+                }
+            }
+        }
+
+        tx.commit().await?;
+        metrics::record_commit();
+
+        Ok(results)
+    }
+
+    async fn begin_transaction(&self, timeout: Duration) -> CoreResult<String> {
+        self.transactions.begin(Arc::clone(&self.connector), timeout).await
+    }
+
+    async fn execute_in_transaction(
+        &self,
+        tx_id: &str,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<Response>> {
+        let queries: Vec<(QueryGraph, IrSerializer)> = QueryGraphBuilder::new(query_schema).build(query_doc)?;
+
+        self.transactions.execute(tx_id, queries).await
+    }
+
+    async fn commit_transaction(&self, tx_id: &str) -> CoreResult<()> {
+        self.transactions.commit(tx_id).await
+    }
+
+    async fn rollback_transaction(&self, tx_id: &str) -> CoreResult<()> {
+        self.transactions.rollback(tx_id).await
+    }
+
+    async fn explain(
+        &self,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+        explain_database: bool,
+    ) -> CoreResult<Vec<Explanation>> {
+        let conn = self.connector.get_connection().await?;
+        let queries: Vec<(QueryGraph, IrSerializer)> = QueryGraphBuilder::new(query_schema).build(query_doc)?;
+
+        // Writes are never committed: every operation runs in its own transaction, which is rolled back afterwards.
+        // A failing operation therefore can't abort the transaction the following operations run in.
+        let mut explanations = vec![];
+
+        for (query_graph, info) in queries {
+            let tx = conn.start_transaction().await?;
+            let mut explanation = Explanation {
+                key: info.key.clone(),
+                ..Default::default()
+            };
+
+            let log = QueryLog::new(info.key.clone(), false);
+            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+            let pipeline = QueryPipeline::new(query_graph, interpreter, info);
+
+            if let Err(err) = log.clone().scope(pipeline.explain(&mut explanation)).await {
+                explanation.error = Some(format!("{}", err));
+            }
+
+            for query in log.entries() {
+                let plan = if explain_database {
+                    explain_statement(tx.as_ref(), self.primary_connector, &query).await
+                } else {
+                    None
+                };
+
+                explanation.queries.push(ExplainedQuery { query, plan });
+            }
+
+            tx.rollback().await?;
+            explanations.push(explanation);
+        }
+
+        Ok(explanations)
+    }
+
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
+
+    fn pool_metrics(&self) -> Option<PoolMetrics> {
+        self.connector.pool_metrics()
+    }
 }
@@ -5,13 +5,19 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod explain;
 mod interpreting_executor;
 mod pipeline;
+mod transactions;
 
+pub use explain::*;
 pub use interpreting_executor::*;
+pub use transactions::*;
 
 use crate::{query_document::QueryDocument, response_ir::Response, schema::QuerySchemaRef, CoreResult};
 use async_trait::async_trait;
+use connector::PoolMetrics;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
@@ -21,5 +27,42 @@ pub trait QueryExecutor {
         query_schema: QuerySchemaRef,
     ) -> CoreResult<Vec<Response>>;
 
+    /// Executes all operations of the document on one connection inside a single transaction.
+    /// If any operation fails, the changes of all operations are rolled back.
+    async fn execute_transaction(
+        &self,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<Response>>;
+
+    /// Starts an interactive transaction that stays open across requests until it is committed, rolled back,
+    /// or the timeout expires. Returns the id of the transaction.
+    async fn begin_transaction(&self, timeout: Duration) -> CoreResult<String>;
+
+    /// Executes all operations of the document inside the interactive transaction with the given id.
+    async fn execute_in_transaction(
+        &self,
+        tx_id: &str,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<Response>>;
+
+    async fn commit_transaction(&self, tx_id: &str) -> CoreResult<()>;
+
+    async fn rollback_transaction(&self, tx_id: &str) -> CoreResult<()>;
+
+    /// Executes the document inside a transaction that is always rolled back and describes how each operation
+    /// was executed: its query graph, expression, and the statements sent to the database. With
+    /// `explain_database`, the read statements are also explained by the database.
+    async fn explain(
+        &self,
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+        explain_database: bool,
+    ) -> CoreResult<Vec<Explanation>>;
+
     fn primary_connector(&self) -> &'static str;
+
+    /// The current usage of the connection pool of the connector, if it pools its connections.
+    fn pool_metrics(&self) -> Option<PoolMetrics>;
 }
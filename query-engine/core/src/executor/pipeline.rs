@@ -1,4 +1,8 @@
-use crate::{CoreResult, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, Response};
+use super::Explanation;
+use crate::{
+    format_expression, metrics, CoreResult, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, Response,
+};
+use std::time::Instant;
 
 pub struct QueryPipeline<'conn, 'tx> {
     graph: QueryGraph,
@@ -16,6 +20,8 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
     }
 
     pub async fn execute(mut self) -> CoreResult<Response> {
+        let start = Instant::now();
+
         // Run final validations and transformations.
         self.graph.finalize()?;
         trace!("{}", self.graph);
@@ -25,6 +31,24 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
         let result = self.interpreter.interpret(expr, Env::default(), 0).await;
 
         trace!("{}", self.interpreter.log.lock().await);
+        metrics::record_operation(&serializer.operation, start.elapsed());
+
+        Ok(serializer.serialize(result?))
+    }
+
+    /// Executes the pipeline like `execute`, describing the query graph, expression, and interpretation in
+    /// the explanation.
+    pub async fn explain(mut self, explanation: &mut Explanation) -> CoreResult<Response> {
+        self.graph.finalize()?;
+        explanation.query_graph = format!("{}", self.graph);
+
+        let serializer = self.serializer;
+        let expr = Expressionista::translate(self.graph)?;
+        explanation.expression = format_expression(&expr, 0);
+
+        let result = self.interpreter.interpret(expr, Env::default(), 0).await;
+        explanation.interpretation = self.interpreter.log.lock().await.clone();
+
         Ok(serializer.serialize(result?))
     }
 }
@@ -0,0 +1,189 @@
+use super::pipeline::QueryPipeline;
+use crate::{metrics, CoreError, CoreResult, IrSerializer, QueryGraph, QueryInterpreter, Response};
+use async_std::{future, sync::Mutex, task};
+use connector::{ConnectionLike, Connector, QueryLog};
+use futures::{
+    channel::{mpsc, oneshot},
+    SinkExt, StreamExt,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+type Sender = mpsc::Sender<TransactionCommand>;
+
+/// The longest a transaction stays open. Longer timeouts are capped, as an open transaction holds a connection
+/// and its locks.
+pub const MAX_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+enum TransactionCommand {
+    /// Carries the query log of the request, as the queries run on the task of the transaction.
+    Execute(
+        Vec<(QueryGraph, IrSerializer)>,
+        Option<QueryLog>,
+        oneshot::Sender<CoreResult<Vec<Response>>>,
+    ),
+    Commit(oneshot::Sender<CoreResult<()>>),
+    Rollback(oneshot::Sender<CoreResult<()>>),
+}
+
+/// Keeps track of interactive transactions that stay open across requests.
+///
+/// A transaction borrows the connection it runs on, so every open transaction is driven by a task that owns
+/// both and receives the commands for the transaction over a channel. The task rolls the transaction back
+/// once its timeout expires, which also cleans up transactions abandoned by their clients.
+#[derive(Default)]
+pub struct TransactionManager {
+    open: Arc<Mutex<HashMap<String, Sender>>>,
+}
+
+impl TransactionManager {
+    /// Starts a new transaction on its own connection and returns its id.
+    /// The timeout is capped at `MAX_TRANSACTION_TIMEOUT`.
+    pub async fn begin<C>(&self, connector: Arc<C>, timeout: Duration) -> CoreResult<String>
+    where
+        C: Connector + Send + Sync + 'static,
+    {
+        let timeout = timeout.min(MAX_TRANSACTION_TIMEOUT);
+        let id = Uuid::new_v4().to_hyphenated().to_string();
+        let (sender, commands) = mpsc::channel(0);
+        let (started_sender, started) = oneshot::channel();
+
+        self.open.lock().await.insert(id.clone(), sender);
+
+        let open = Arc::clone(&self.open);
+        let tx_id = id.clone();
+
+        task::spawn(async move {
+            run_transaction(connector, commands, timeout, started_sender).await;
+            open.lock().await.remove(&tx_id);
+        });
+
+        match started.await {
+            Ok(Ok(())) => Ok(id),
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err(CoreError::TransactionNotFound(id)),
+        }
+    }
+
+    /// Executes the queries in the transaction. The transaction stays open if a query fails.
+    pub async fn execute(&self, id: &str, queries: Vec<(QueryGraph, IrSerializer)>) -> CoreResult<Vec<Response>> {
+        let log = QueryLog::current();
+        self.send(id, |reply| TransactionCommand::Execute(queries, log, reply))
+            .await
+    }
+
+    pub async fn commit(&self, id: &str) -> CoreResult<()> {
+        self.send(id, TransactionCommand::Commit).await
+    }
+
+    pub async fn rollback(&self, id: &str) -> CoreResult<()> {
+        self.send(id, TransactionCommand::Rollback).await
+    }
+
+    async fn send<T, F>(&self, id: &str, command: F) -> CoreResult<T>
+    where
+        F: FnOnce(oneshot::Sender<CoreResult<T>>) -> TransactionCommand,
+    {
+        let not_found = || CoreError::TransactionNotFound(id.to_owned());
+        let mut sender = self.open.lock().await.get(id).cloned().ok_or_else(not_found)?;
+        let (reply, result) = oneshot::channel();
+
+        sender.send(command(reply)).await.map_err(|_| not_found())?;
+        result.await.map_err(|_| not_found())?
+    }
+}
+
+/// Drives a single transaction until it is committed, rolled back, or timed out.
+async fn run_transaction<C>(
+    connector: Arc<C>,
+    mut commands: mpsc::Receiver<TransactionCommand>,
+    timeout: Duration,
+    started: oneshot::Sender<CoreResult<()>>,
+) where
+    C: Connector + Send + Sync + 'static,
+{
+    let conn = match connector.get_connection().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    let tx = match conn.start_transaction().await {
+        Ok(tx) => tx,
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    let deadline = Instant::now() + timeout;
+    let _ = started.send(Ok(()));
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        let command = match future::timeout(remaining, commands.next()).await {
+            Ok(Some(command)) => command,
+            Ok(None) | Err(_) => {
+                debug!("Rolling back transaction, its timeout expired.");
+
+                match tx.rollback().await {
+                    Ok(_) => metrics::record_rollback(),
+                    Err(err) => warn!("Error rolling back transaction: {}", err),
+                }
+
+                return;
+            }
+        };
+
+        match command {
+            TransactionCommand::Execute(queries, log, reply) => {
+                let execution = async {
+                    let mut results = vec![];
+
+                    for (query_graph, info) in queries {
+                        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                        results.push(QueryPipeline::new(query_graph, interpreter, info).execute().await?);
+                    }
+
+                    Ok::<_, CoreError>(results)
+                };
+
+                let result = match log {
+                    Some(log) => log.scope(execution).await,
+                    None => execution.await,
+                };
+
+                let _ = reply.send(result);
+            }
+
+            TransactionCommand::Commit(reply) => {
+                let result = tx.commit().await.map_err(CoreError::from);
+
+                if result.is_ok() {
+                    metrics::record_commit();
+                }
+
+                let _ = reply.send(result);
+                return;
+            }
+
+            TransactionCommand::Rollback(reply) => {
+                let result = tx.rollback().await.map_err(CoreError::from);
+
+                if result.is_ok() {
+                    metrics::record_rollback();
+                }
+
+                let _ = reply.send(result);
+                return;
+            }
+        }
+    }
+}
@@ -26,7 +26,7 @@ impl ExpressionResult {
                 // We always select IDs, the unwraps are safe.
                 QueryResult::RecordSelection(rs) => Some(
                     rs.scalars
-                        .collect_ids(rs.id_field.as_str())
+                        .collect_ids(&rs.id_fields)
                         .unwrap()
                         .into_iter()
                         .map(|val| val.into())
@@ -1,5 +1,5 @@
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, QueryArguments, ScalarListValues, ReadOperations, ConnectionLike};
+use connector::{self, ConnectionLike, QueryArguments, ReadOperations, RelatedRecordsCount, ScalarListValues};
 use futures::future::{BoxFuture, FutureExt};
 use prisma_models::{GraphqlId, ScalarField, SelectedFields};
 use std::sync::Arc;
@@ -15,6 +15,7 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_ids).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(tx, q).await,
         }
     };
 
@@ -27,19 +28,20 @@ fn read_one<'conn, 'tx>(
     query: RecordQuery,
 ) -> BoxFuture<'conn, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let selected_fields = inject_required_fields(query.selected_fields.clone());
+        let selected_fields = inject_required_fields(query.selected_fields.clone(), None);
         let scalars = tx
             .get_single_record(query.record_finder.as_ref().unwrap(), &selected_fields)
             .await?;
 
-        let model = query.record_finder.unwrap().field.model();
-        let id_field = model.fields().id().name.clone();
+        let model = query.record_finder.unwrap().model();
+        let id_fields = model.id_field_names();
 
         match scalars {
             Some(record) => {
-                let ids = vec![record.collect_id(&id_field)?];
+                let ids = vec![record.collect_id(&id_fields)?];
                 let list_fields = selected_fields.scalar_lists();
                 let lists = resolve_scalar_list_fields(tx, ids.clone(), list_fields).await?;
+                let relation_counts = resolve_relation_counts(tx, &ids, query.relation_counts).await?;
                 let nested: Vec<QueryResult> = process_nested(tx, query.nested, &ids).await?;
 
                 Ok(QueryResult::RecordSelection(RecordSelection {
@@ -48,7 +50,8 @@ fn read_one<'conn, 'tx>(
                     scalars: record.into(),
                     nested,
                     lists,
-                    id_field,
+                    relation_counts,
+                    id_fields,
                     ..Default::default()
                 }))
             }
@@ -56,7 +59,7 @@ fn read_one<'conn, 'tx>(
             None => Ok(QueryResult::RecordSelection(RecordSelection {
                 name: query.name,
                 fields: query.selection_order,
-                id_field,
+                id_fields,
                 ..Default::default()
             })),
         }
@@ -71,15 +74,16 @@ fn read_many<'a, 'b>(
     query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let selected_fields = inject_required_fields(query.selected_fields.clone());
+        let selected_fields = inject_required_fields(query.selected_fields.clone(), Some(&query.args));
         let scalars = tx
             .get_many_records(&query.model, query.args.clone(), &selected_fields)
             .await?;
 
-        let id_field = query.model.fields().id().name.clone();
-        let ids = scalars.collect_ids(&id_field)?;
+        let id_fields = query.model.id_field_names();
+        let ids = scalars.collect_ids(&id_fields)?;
         let list_fields = selected_fields.scalar_lists();
         let lists = resolve_scalar_list_fields(tx, ids.clone(), list_fields).await?;
+        let relation_counts = resolve_relation_counts(tx, &ids, query.relation_counts).await?;
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, &ids).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
@@ -89,7 +93,8 @@ fn read_many<'a, 'b>(
             scalars,
             nested,
             lists,
-            id_field,
+            relation_counts,
+            id_fields,
         }))
     };
 
@@ -103,7 +108,7 @@ fn read_related<'a, 'b>(
     parent_ids: &'a [GraphqlId],
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let selected_fields = inject_required_fields(query.selected_fields.clone());
+        let selected_fields = inject_required_fields(query.selected_fields.clone(), Some(&query.args));
         let parent_ids = match query.parent_ids {
             Some(ref ids) => ids,
             None => parent_ids,
@@ -119,10 +124,11 @@ fn read_related<'a, 'b>(
             .await?;
 
         let model = query.parent_field.related_model();
-        let id_field = model.fields().id().name.clone();
-        let ids = scalars.collect_ids(&id_field)?;
+        let id_fields = model.id_field_names();
+        let ids = scalars.collect_ids(&id_fields)?;
         let list_fields = selected_fields.scalar_lists();
         let lists = resolve_scalar_list_fields(tx, ids.clone(), list_fields).await?;
+        let relation_counts = resolve_relation_counts(tx, &ids, query.relation_counts).await?;
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, &ids).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
@@ -132,19 +138,40 @@ fn read_related<'a, 'b>(
             scalars,
             nested,
             lists,
-            id_field,
+            relation_counts,
+            id_fields,
         }))
     };
 
     fut.boxed()
 }
 
+/// Computes aggregations over a set of records.
 async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: AggregateRecordsQuery,
 ) -> InterpretationResult<QueryResult> {
-    let result = tx.count_by_model(&query.model, QueryArguments::default()).await?;
-    Ok(QueryResult::Count(result))
+    let results = tx.aggregate_records(&query.model, query.aggregators, query.args).await?;
+
+    Ok(QueryResult::RecordAggregation(RecordAggregation {
+        selection_order: query.selection_order,
+        results,
+    }))
+}
+
+/// Groups records by the values of the given fields and computes aggregations for each group.
+async fn group_by<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let groups = tx
+        .group_records(&query.model, query.group_by, query.aggregators, query.having, query.args)
+        .await?;
+
+    Ok(QueryResult::RecordGroups(RecordGroups {
+        selection_order: query.selection_order,
+        groups,
+    }))
 }
 
 /// Resolves scalar lists for a list field for a set of parent IDs.
@@ -169,19 +196,45 @@ async fn resolve_scalar_list_fields<'a, 'b>(
     }
 }
 
+/// Counts the related records of the selected relation counts for a set of record IDs, one query per count.
+async fn resolve_relation_counts<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    record_ids: &[GraphqlId],
+    relation_counts: Vec<RelationCount>,
+) -> connector::Result<Vec<(String, Vec<RelatedRecordsCount>)>> {
+    let mut results = vec![];
+
+    if record_ids.is_empty() {
+        return Ok(results);
+    }
+
+    for relation_count in relation_counts {
+        let counts = tx
+            .count_related_records(&relation_count.field, record_ids, relation_count.filter)
+            .await?;
+
+        results.push((relation_count.name, counts));
+    }
+
+    Ok(results)
+}
+
 /// Injects fields required for querying, if they're not already in the selection set.
 /// Currently, required fields for every query are:
-/// - ID field
-fn inject_required_fields(mut selected_fields: SelectedFields) -> SelectedFields {
-    let id_field = selected_fields.model().fields().id();
-
-    if selected_fields
-        .scalar
-        .iter()
-        .find(|f| f.field.name == id_field.name)
-        .is_none()
-    {
-        selected_fields.add_scalar(id_field);
+/// - ID fields
+/// - Distinct fields of the query arguments, if any
+fn inject_required_fields(mut selected_fields: SelectedFields, args: Option<&QueryArguments>) -> SelectedFields {
+    let distinct_fields = args.and_then(|args| args.distinct.clone()).unwrap_or_default();
+
+    for required_field in selected_fields.model().id_fields().into_iter().chain(distinct_fields) {
+        if selected_fields
+            .scalar
+            .iter()
+            .find(|f| f.field.name == required_field.name)
+            .is_none()
+        {
+            selected_fields.add_scalar(required_field);
+        }
     }
 
     selected_fields
@@ -11,6 +11,7 @@ pub async fn execute<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
@@ -19,6 +20,8 @@ pub async fn execute<'a, 'b>(
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
         WriteQuery::SetRecords(q) => set(tx, q).await,
         WriteQuery::ResetData(q) => reset(tx, q).await,
+        WriteQuery::QueryRaw(q) => query_raw(tx, q).await,
+        WriteQuery::ExecuteRaw(q) => execute_raw(tx, q).await,
     }
 }
 
@@ -33,6 +36,21 @@ async fn create_one<'a, 'b>(
     Ok(QueryResult::Id(res))
 }
 
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let args = q
+        .args
+        .into_iter()
+        .map(|(non_list_args, list_args)| WriteArgs::new(non_list_args, list_args))
+        .collect();
+
+    let res = tx.create_records(&q.model, args, q.skip_duplicates).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: UpdateRecord,
@@ -41,7 +59,7 @@ async fn update_one<'a, 'b>(
         .update_records(
             &q.model,
             Filter::from(q.where_),
-            WriteArgs::new(q.non_list_args, q.list_args),
+            WriteArgs::new(q.non_list_args, q.list_args).with_json_updates(q.json_updates),
         )
         .await?;
 
@@ -69,11 +87,10 @@ async fn update_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: UpdateManyRecords,
 ) -> InterpretationResult<QueryResult> {
-    let res = tx
-        .update_records(&q.model, q.filter, WriteArgs::new(q.non_list_args, q.list_args))
-        .await?;
+    let args = WriteArgs::new(q.non_list_args, q.list_args).with_json_updates(q.json_updates);
+    let res = tx.update_many_records(&q.model, q.filter, args).await?;
 
-    Ok(QueryResult::Count(res.len()))
+    Ok(QueryResult::Count(res))
 }
 
 async fn delete_many<'a, 'b>(
@@ -85,6 +102,18 @@ async fn delete_many<'a, 'b>(
     Ok(QueryResult::Count(res))
 }
 
+async fn query_raw<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: RawQuery) -> InterpretationResult<QueryResult> {
+    let res = tx.query_raw(q.query, q.parameters).await?;
+
+    Ok(QueryResult::Json(res))
+}
+
+async fn execute_raw<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: RawQuery) -> InterpretationResult<QueryResult> {
+    let res = tx.execute_raw(q.query, q.parameters).await?;
+
+    Ok(QueryResult::Json(res.into()))
+}
+
 async fn connect<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: ConnectRecords,
@@ -9,9 +9,13 @@ extern crate debug_stub_derive;
 #[macro_use]
 extern crate lazy_static;
 
+#[macro_use]
+extern crate prometheus;
+
 pub mod error;
 pub mod executor;
 pub mod interpreter;
+pub mod metrics;
 pub mod query_ast;
 pub mod query_document;
 pub mod query_graph;
@@ -0,0 +1,88 @@
+//! Prometheus metrics of the query engine, collected in the default registry of the `prometheus` crate.
+//! Connectors register their own metrics in the same registry, `render` gathers all of them.
+use crate::CoreError;
+use connector::PoolMetrics;
+use prometheus::{Encoder, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder};
+use std::time::Duration;
+
+lazy_static! {
+    static ref OPERATIONS: IntCounterVec = register_int_counter_vec!(
+        "prisma_operations_total",
+        "Number of top-level operations executed, by operation and model.",
+        &["operation", "model"]
+    )
+    .unwrap();
+    static ref OPERATION_DURATION: HistogramVec = register_histogram_vec!(
+        "prisma_operation_duration_seconds",
+        "Duration of top-level operations, by operation and model.",
+        &["operation", "model"]
+    )
+    .unwrap();
+    static ref TRANSACTIONS: IntCounterVec = register_int_counter_vec!(
+        "prisma_transactions_total",
+        "Number of finished transactions, by outcome (commit or rollback).",
+        &["outcome"]
+    )
+    .unwrap();
+    static ref ERRORS: IntCounterVec = register_int_counter_vec!(
+        "prisma_errors_total",
+        "Number of errors returned to clients, by kind.",
+        &["kind"]
+    )
+    .unwrap();
+    static ref POOL_CONNECTIONS: IntGaugeVec = register_int_gauge_vec!(
+        "prisma_pool_connections",
+        "Connections of the connection pool, by state (checked_out or idle).",
+        &["state"]
+    )
+    .unwrap();
+    static ref POOL_WAITERS: IntGauge =
+        register_int_gauge!("prisma_pool_waiters", "Requests waiting for a connection.").unwrap();
+}
+
+/// Identifies a top-level operation of a query document, e.g. `findMany` on the model `User`.
+/// Operations not bound to a model, like `queryRaw`, have an empty model.
+#[derive(Debug, Clone)]
+pub struct OperationLabels {
+    pub operation: String,
+    pub model: String,
+}
+
+pub(crate) fn record_operation(labels: &OperationLabels, duration: Duration) {
+    let values = [labels.operation.as_str(), labels.model.as_str()];
+
+    OPERATIONS.with_label_values(&values).inc();
+    OPERATION_DURATION
+        .with_label_values(&values)
+        .observe(duration.as_secs_f64());
+}
+
+pub(crate) fn record_commit() {
+    TRANSACTIONS.with_label_values(&["commit"]).inc();
+}
+
+pub(crate) fn record_rollback() {
+    TRANSACTIONS.with_label_values(&["rollback"]).inc();
+}
+
+pub fn record_error(error: &CoreError) {
+    ERRORS.with_label_values(&[error.kind()]).inc();
+}
+
+/// Renders all metrics in the Prometheus text format, updating the pool gauges first.
+pub fn render(pool: Option<PoolMetrics>) -> String {
+    if let Some(pool) = pool {
+        POOL_CONNECTIONS
+            .with_label_values(&["checked_out"])
+            .set(pool.checked_out as i64);
+
+        POOL_CONNECTIONS.with_label_values(&["idle"]).set(pool.idle as i64);
+        POOL_WAITERS.set(pool.waiters as i64);
+    }
+
+    let mut buffer = vec![];
+    let encoder = TextEncoder::new();
+
+    encoder.encode(&prometheus::gather(), &mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
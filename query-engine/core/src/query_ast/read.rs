@@ -1,6 +1,9 @@
 //! Prisma read query AST
 use super::RecordFinderInjector;
-use connector::{filter::RecordFinder, QueryArguments};
+use connector::{
+    filter::{Filter, RecordFinder},
+    Aggregator, HavingFilter, QueryArguments,
+};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -10,6 +13,7 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +23,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
         }
     }
 }
@@ -39,12 +44,7 @@ impl Display for ReadQuery {
                 f,
                 "RecordQuery(name: '{}', finder: {:?})",
                 q.name,
-                q.record_finder.as_ref().map(|finder| format!(
-                    "{}, {} = {:?}",
-                    finder.field.model().name,
-                    finder.field.name,
-                    finder.value
-                ))
+                q.record_finder.as_ref().map(ToString::to_string)
             ),
             Self::ManyRecordsQuery(q) => write!(f, "ManyRecordsQuery(name: '{}', model: {})", q.name, q.model.name),
             Self::RelatedRecordsQuery(q) => write!(
@@ -54,7 +54,19 @@ impl Display for ReadQuery {
                 q.parent_field.model().name,
                 q.parent_field.name
             ),
-            Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::AggregateRecordsQuery(q) => {
+                write!(f, "AggregateRecordsQuery(name: '{}', model: {})", q.name, q.model.name)
+            }
+            Self::GroupByRecordsQuery(q) => write!(
+                f,
+                "GroupByRecordsQuery(name: '{}', model: {}, by: {:?})",
+                q.name,
+                q.model.name,
+                q.group_by
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<&str>>()
+            ),
         }
     }
 }
@@ -66,6 +78,7 @@ pub struct RecordQuery {
     pub record_finder: Option<RecordFinder>,
     pub selected_fields: SelectedFields,
     pub nested: Vec<ReadQuery>,
+    pub relation_counts: Vec<RelationCount>,
     pub selection_order: Vec<String>,
 }
 
@@ -77,6 +90,7 @@ pub struct ManyRecordsQuery {
     pub args: QueryArguments,
     pub selected_fields: SelectedFields,
     pub nested: Vec<ReadQuery>,
+    pub relation_counts: Vec<RelationCount>,
     pub selection_order: Vec<String>,
 }
 
@@ -89,12 +103,54 @@ pub struct RelatedRecordsQuery {
     pub args: QueryArguments,
     pub selected_fields: SelectedFields,
     pub nested: Vec<ReadQuery>,
+    pub relation_counts: Vec<RelationCount>,
     pub selection_order: Vec<String>,
 }
 
+/// The name of the object field selecting the counts of related records, e.g. `_count { posts }`.
+pub const RELATION_COUNT_FIELD: &str = "_count";
+
+/// The number of records related through a to-many relation field, selected in the `_count` object of a record.
+#[derive(Debug, Clone)]
+pub struct RelationCount {
+    /// The selected field name (or alias).
+    pub name: String,
+    pub field: RelationFieldRef,
+
+    /// Only related records matching the filter are counted.
+    pub filter: Option<Filter>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
     pub alias: Option<String>,
     pub model: ModelRef,
+    pub args: QueryArguments,
+    pub aggregators: Vec<Aggregator>,
+
+    /// Selected field names (or aliases) in order, with the selected field names of nested aggregation objects.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub args: QueryArguments,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub aggregators: Vec<Aggregator>,
+    pub having: Option<HavingFilter>,
+    pub selection_order: Vec<GroupBySelection>,
+}
+
+/// A selected field (or alias) of a group of records.
+#[derive(Debug, Clone)]
+pub enum GroupBySelection {
+    /// A grouped field, with the position of its value in the grouped values.
+    Field(String, usize),
+
+    /// An aggregation (e.g. `count` or `sum`), with the selected field names of nested aggregation objects.
+    Aggregation(String, Option<Vec<String>>),
 }
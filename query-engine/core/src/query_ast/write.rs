@@ -1,11 +1,15 @@
 //! Write query AST
 use super::RecordFinderInjector;
-use connector::filter::{Filter, RecordFinder};
+use connector::{
+    filter::{Filter, RecordFinder},
+    JsonUpdate,
+};
 use prisma_models::prelude::*;
 
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
@@ -14,6 +18,8 @@ pub enum WriteQuery {
     DisconnectRecords(DisconnectRecords),
     SetRecords(SetRecords),
     ResetData(ResetData),
+    QueryRaw(RawQuery),
+    ExecuteRaw(RawQuery),
 }
 
 impl WriteQuery {
@@ -54,39 +60,35 @@ impl std::fmt::Display for WriteQuery {
                 "CreateRecord(model: {}, non-list-args: {:?}, list_args: {:?})",
                 q.model.name, q.non_list_args, q.list_args,
             ),
+            Self::CreateManyRecords(q) => write!(
+                f,
+                "CreateManyRecords(model: {}, records: {}, skip_duplicates: {})",
+                q.model.name,
+                q.args.len(),
+                q.skip_duplicates,
+            ),
             Self::UpdateRecord(q) => write!(
                 f,
-                "UpdateRecord(model: {}, finder: {:?}, non-list-args: {:?}, list_args: {:?})",
+                "UpdateRecord(model: {}, finder: {:?}, non-list-args: {:?}, list_args: {:?}, json_updates: {:?})",
                 q.model.name,
-                q.where_.as_ref().map(|finder| format!(
-                    "{}, {} = {:?}",
-                    finder.field.model().name,
-                    finder.field.name,
-                    finder.value
-                ),),
+                q.where_.as_ref().map(ToString::to_string),
                 q.non_list_args,
                 q.list_args,
+                q.json_updates,
             ),
-            Self::DeleteRecord(q) => write!(
-                f,
-                "DeleteRecord: {:?}",
-                q.where_.as_ref().map(|finder| format!(
-                    "{}, {} = {:?}",
-                    finder.field.model().name,
-                    finder.field.name,
-                    finder.value
-                ))
-            ),
+            Self::DeleteRecord(q) => write!(f, "DeleteRecord: {:?}", q.where_.as_ref().map(ToString::to_string)),
             Self::UpdateManyRecords(q) => write!(
                 f,
-                "UpdateManyRecords(model: {}, non-list-args: {:?}, list_args: {:?})",
-                q.model.name, q.non_list_args, q.list_args
+                "UpdateManyRecords(model: {}, non-list-args: {:?}, list_args: {:?}, json_updates: {:?})",
+                q.model.name, q.non_list_args, q.list_args, q.json_updates
             ),
             Self::DeleteManyRecords(q) => write!(f, "DeleteManyRecords: {}", q.model.name),
             Self::ConnectRecords(_) => write!(f, "ConnectRecords"),
             Self::DisconnectRecords(_) => write!(f, "DisconnectRecords"),
             Self::SetRecords(_) => write!(f, "SetRecords"),
             Self::ResetData(_) => write!(f, "ResetData"),
+            Self::QueryRaw(q) => write!(f, "QueryRaw(query: {}, parameters: {:?})", q.query, q.parameters),
+            Self::ExecuteRaw(q) => write!(f, "ExecuteRaw(query: {}, parameters: {:?})", q.query, q.parameters),
         }
     }
 }
@@ -98,12 +100,21 @@ pub struct CreateRecord {
     pub list_args: Vec<(String, PrismaListValue)>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    /// Non-list and list arguments of each record to create.
+    pub args: Vec<(PrismaArgs, Vec<(String, PrismaListValue)>)>,
+    pub skip_duplicates: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
     pub where_: Option<RecordFinder>,
     pub non_list_args: PrismaArgs,
     pub list_args: Vec<(String, PrismaListValue)>,
+    pub json_updates: Vec<JsonUpdate>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +123,7 @@ pub struct UpdateManyRecords {
     pub filter: Filter,
     pub non_list_args: PrismaArgs,
     pub list_args: Vec<(String, PrismaListValue)>,
+    pub json_updates: Vec<JsonUpdate>,
 }
 
 #[derive(Debug, Clone)]
@@ -151,3 +163,10 @@ pub struct SetRecords {
 pub struct ResetData {
     pub internal_data_model: InternalDataModelRef,
 }
+
+#[derive(Debug, Clone)]
+pub struct RawQuery {
+    pub query: String,
+    /// Values of the placeholders in the query, in order.
+    pub parameters: Vec<PrismaValue>,
+}
@@ -1,9 +1,11 @@
 use super::*;
 use crate::schema::*;
+use bigdecimal::BigDecimal;
 use chrono::prelude::*;
 use prisma_models::{GraphqlId, PrismaValue};
 use std::{
     collections::{BTreeMap, HashSet},
+    str::FromStr,
     sync::Arc,
 };
 use uuid::Uuid;
@@ -176,6 +178,12 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::DateTime) => Self::parse_datetime(s.as_str()).map(PrismaValue::DateTime),
             (QueryValue::String(s), ScalarType::Json)     => Self::parse_json(s.as_str()).map(PrismaValue::Json),
             (QueryValue::String(s), ScalarType::UUID)     => Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid),
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(BigDecimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Self::parse_decimal(&f.to_string()).map(PrismaValue::Decimal),
+            (QueryValue::String(s), ScalarType::BigInt)   => Self::parse_big_int(s.as_str()).map(PrismaValue::BigInt),
+            (QueryValue::Int(i), ScalarType::BigInt)      => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
             (QueryValue::Int(i), ScalarType::Float)       => Ok(PrismaValue::Float(i as f64)),
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
@@ -211,6 +219,21 @@ impl QueryDocumentParser {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
 
+    /// Decimals are exact if given as strings. Numbers have already been parsed to floats by then.
+    pub fn parse_decimal(s: &str) -> QueryParserResult<BigDecimal> {
+        BigDecimal::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
+    /// BigInts are given as strings by JSON clients that can't represent 64 bit integers as numbers.
+    pub fn parse_big_int(s: &str) -> QueryParserResult<i64> {
+        s.parse::<i64>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid BigInt: {}", err)))
+    }
+
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        base64::decode(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid base64 Bytes: {}", err)))
+    }
+
     pub fn parse_uuid(s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
@@ -128,6 +128,28 @@ impl TryInto<Option<OrderBy>> for ParsedInputValue {
     }
 }
 
+impl TryInto<Vec<OrderBy>> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<Vec<OrderBy>> {
+        match self {
+            ParsedInputValue::Single(PrismaValue::Null) => Ok(vec![]),
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .filter_map(|value| {
+                    let order_by: QueryParserResult<Option<OrderBy>> = value.try_into();
+                    order_by.transpose()
+                })
+                .collect(),
+
+            single => {
+                let order_by: Option<OrderBy> = single.try_into()?;
+                Ok(order_by.into_iter().collect())
+            }
+        }
+    }
+}
+
 impl TryInto<Option<f64>> for ParsedInputValue {
     type Error = QueryParserError;
 
@@ -1,5 +1,5 @@
 use super::*;
-use crate::{query_document::*, query_graph::*, schema::*, IrSerializer};
+use crate::{metrics::OperationLabels, query_document::*, query_graph::*, schema::*, IrSerializer};
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
 // the query_document module, possibly already as part of the parser.
@@ -69,6 +69,20 @@ impl QueryGraphBuilder {
         IrSerializer {
             key: selection.alias.clone().unwrap_or_else(|| selection.name.clone()),
             output_type: field.schema_field.field_type.clone(),
+            operation: Self::derive_operation_labels(field),
+        }
+    }
+
+    fn derive_operation_labels(field: &ParsedField) -> OperationLabels {
+        match &field.schema_field.query_builder {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) => OperationLabels {
+                operation: builder.tag.to_string(),
+                model: builder.model.name.clone(),
+            },
+            _ => OperationLabels {
+                operation: field.name.clone(),
+                model: String::new(),
+            },
         }
     }
 }
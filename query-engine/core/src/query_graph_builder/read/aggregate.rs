@@ -1,5 +1,6 @@
 use super::*;
 use crate::{query_document::ParsedField, AggregateRecordsQuery, ReadQuery};
+use connector::Aggregator;
 use prisma_models::ModelRef;
 
 pub struct AggregateRecordsBuilder {
@@ -15,14 +16,45 @@ impl AggregateRecordsBuilder {
 
 impl Builder<ReadQuery> for AggregateRecordsBuilder {
     fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let args = utils::extract_query_args(self.field.arguments, &self.model)?;
         let name = self.field.name;
         let alias = self.field.alias;
         let model = self.model;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+
+        let selection_order = nested_fields
+            .iter()
+            .map(|field| {
+                let nested = field
+                    .nested_fields
+                    .as_ref()
+                    .map(|nested| collect_selection_order(&nested.fields));
+
+                (field.alias.clone().unwrap_or_else(|| field.name.clone()), nested)
+            })
+            .collect();
+
+        let aggregators = nested_fields
+            .into_iter()
+            .map(|field| {
+                Ok(match field.name.as_str() {
+                    "count" => Aggregator::Count,
+                    "sum" => Aggregator::Sum(collect_aggregation_fields(&field, &model)?),
+                    "avg" => Aggregator::Average(collect_aggregation_fields(&field, &model)?),
+                    "min" => Aggregator::Min(collect_aggregation_fields(&field, &model)?),
+                    "max" => Aggregator::Max(collect_aggregation_fields(&field, &model)?),
+                    _ => unreachable!(), // Validated by the query schema.
+                })
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
         Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
             name,
             alias,
             model,
+            args,
+            aggregators,
+            selection_order,
         }))
     }
 }
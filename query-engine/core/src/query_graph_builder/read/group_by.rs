@@ -0,0 +1,138 @@
+use super::*;
+use crate::{
+    query_document::{ParsedArgument, ParsedField, ParsedInputMap, ParsedInputValue},
+    GroupByRecordsQuery, GroupBySelection, ReadQuery,
+};
+use connector::{Aggregator, HavingFilter};
+use prisma_models::{ModelRef, OrderByField, ScalarFieldRef};
+use std::convert::TryInto;
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let model = self.model;
+        let (group_by, having) = extract_group_by_args(&self.field.arguments, &model)?;
+        let args = utils::extract_query_args(self.field.arguments, &model)?;
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+
+        for order_by in args.order_by.iter() {
+            let is_grouped = match order_by.field {
+                OrderByField::Scalar(ref sf) => group_by.iter().any(|field| field.name == sf.name),
+                _ => false,
+            };
+
+            if !is_grouped {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Groups can only be ordered by grouped fields, '{}' is not contained in 'by'.",
+                    order_by.field.name()
+                )));
+            }
+        }
+
+        let mut selection_order = vec![];
+        let mut aggregators = vec![];
+
+        for field in nested_fields {
+            let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+
+            let aggregator = match field.name.as_str() {
+                "count" => Aggregator::Count,
+                "sum" => Aggregator::Sum(collect_aggregation_fields(&field, &model)?),
+                "avg" => Aggregator::Average(collect_aggregation_fields(&field, &model)?),
+                "min" => Aggregator::Min(collect_aggregation_fields(&field, &model)?),
+                "max" => Aggregator::Max(collect_aggregation_fields(&field, &model)?),
+                name => match group_by.iter().position(|field| field.name == name) {
+                    Some(index) => {
+                        selection_order.push(GroupBySelection::Field(key, index));
+                        continue;
+                    }
+                    None => {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "Only grouped fields can be selected, '{}' is not contained in 'by'.",
+                            name
+                        )))
+                    }
+                },
+            };
+
+            let nested = field
+                .nested_fields
+                .as_ref()
+                .map(|nested| collect_selection_order(&nested.fields));
+
+            selection_order.push(GroupBySelection::Aggregation(key, nested));
+            aggregators.push(aggregator);
+        }
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            args,
+            group_by,
+            aggregators,
+            having,
+            selection_order,
+        }))
+    }
+}
+
+/// Extracts the grouped fields (`by`) and the filter on the aggregated values of the groups (`having`).
+/// The remaining arguments are regular query arguments.
+fn extract_group_by_args(
+    arguments: &[ParsedArgument],
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<(Vec<ScalarFieldRef>, Option<HavingFilter>)> {
+    let mut group_by: Vec<ScalarFieldRef> = vec![];
+    let mut having = None;
+
+    for argument in arguments {
+        match argument.name.as_str() {
+            "by" => {
+                let values = match argument.value.clone() {
+                    ParsedInputValue::List(values) => values,
+                    single => vec![single],
+                };
+
+                for value in values {
+                    let field_name: Option<String> = value.try_into()?;
+
+                    if let Some(field_name) = field_name {
+                        let field = model.fields().find_from_scalar(&field_name)?;
+
+                        if group_by.iter().all(|grouped| grouped.name != field.name) {
+                            group_by.push(field);
+                        }
+                    }
+                }
+            }
+
+            "having" => {
+                let value: Option<ParsedInputMap> = argument.value.clone().try_into()?;
+                having = value.map(|map| utils::extract_having_filter(map, model)).transpose()?;
+            }
+
+            _ => (),
+        }
+    }
+
+    if group_by.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(
+            "At least one field is required to group records by.".to_owned(),
+        ));
+    }
+
+    Ok((group_by, having))
+}
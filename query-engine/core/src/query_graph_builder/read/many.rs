@@ -21,6 +21,7 @@ impl Builder<ReadQuery> for ReadManyRecordsBuilder {
         let nested_fields = self.field.nested_fields.unwrap().fields;
         let selection_order: Vec<String> = collect_selection_order(&nested_fields);
         let selected_fields = collect_selected_fields(&nested_fields, &self.model, None);
+        let relation_counts = collect_relation_counts(&nested_fields, &self.model)?;
         let nested = collect_nested_queries(nested_fields, &self.model)?;
         let model = self.model;
 
@@ -32,6 +33,7 @@ impl Builder<ReadQuery> for ReadManyRecordsBuilder {
             selected_fields,
             nested,
             selection_order,
+            relation_counts,
         }))
     }
 }
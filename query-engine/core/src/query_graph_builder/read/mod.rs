@@ -1,25 +1,29 @@
 mod aggregate;
+mod group_by;
 mod many;
 mod one;
 mod related;
 
 pub use aggregate::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
 
 use super::*;
-use crate::{query_document::ParsedField, ReadQuery};
+use crate::{query_document::ParsedField, ReadQuery, RelationCount, RELATION_COUNT_FIELD};
 use prisma_models::{
-    Field, ModelRef, RelationFieldRef, SelectedField, SelectedFields, SelectedRelationField, SelectedScalarField,
+    Field, ModelRef, RelationFieldRef, ScalarFieldRef, SelectedField, SelectedFields, SelectedRelationField,
+    SelectedScalarField,
 };
-use std::sync::Arc;
+use std::{convert::TryInto, sync::Arc};
 
 pub enum ReadQueryBuilder {
     ReadOneRecordBuilder(ReadOneRecordBuilder),
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -29,6 +33,7 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
         }
     }
 }
@@ -44,6 +49,23 @@ pub fn collect_selection_order(from: &[ParsedField]) -> Vec<String> {
         .collect()
 }
 
+/// Resolves the selected fields of an aggregation object (e.g. `sum { age }`) to the scalar fields of the model.
+pub fn collect_aggregation_fields(
+    field: &ParsedField,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+    let fields = field
+        .nested_fields
+        .as_ref()
+        .map(|nested| nested.fields.as_slice())
+        .unwrap_or(&[])
+        .iter()
+        .map(|selected_field| model.fields().find_from_scalar(&selected_field.name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(fields)
+}
+
 pub fn collect_selected_fields(
     from: &[ParsedField],
     model: &ModelRef,
@@ -51,6 +73,7 @@ pub fn collect_selected_fields(
 ) -> SelectedFields {
     let selected_fields = from
         .iter()
+        .filter(|selected_field| selected_field.name != RELATION_COUNT_FIELD)
         .map(|selected_field| {
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
@@ -68,6 +91,7 @@ pub fn collect_selected_fields(
 
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
+        .filter(|selected_field| selected_field.name != RELATION_COUNT_FIELD)
         .filter_map(|selected_field| {
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
@@ -87,3 +111,28 @@ pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> Query
         .map(|builder| builder.build())
         .collect::<QueryGraphBuilderResult<Vec<ReadQuery>>>()
 }
+
+/// Resolves the fields selected in the `_count` object of a record to counts of the related records.
+pub fn collect_relation_counts(from: &[ParsedField], model: &ModelRef) -> QueryGraphBuilderResult<Vec<RelationCount>> {
+    from.iter()
+        .filter(|selected_field| selected_field.name == RELATION_COUNT_FIELD)
+        .filter_map(|selected_field| selected_field.nested_fields.as_ref())
+        .flat_map(|nested| nested.fields.iter())
+        .map(|count_field| {
+            let field = model.fields().find_from_relation_fields(&count_field.name).unwrap();
+            let filter = match count_field.arguments.iter().find(|arg| arg.name == "where") {
+                Some(where_arg) => Some(utils::extract_filter(
+                    where_arg.value.clone().try_into()?,
+                    &field.related_model(),
+                )?),
+                None => None,
+            };
+
+            Ok(RelationCount {
+                name: count_field.alias.clone().unwrap_or_else(|| count_field.name.clone()),
+                field,
+                filter,
+            })
+        })
+        .collect()
+}
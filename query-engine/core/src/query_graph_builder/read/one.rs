@@ -30,6 +30,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
         let nested_fields = self.field.nested_fields.unwrap().fields;
         let selection_order: Vec<String> = collect_selection_order(&nested_fields);
         let selected_fields = collect_selected_fields(&nested_fields, &self.model, None);
+        let relation_counts = collect_relation_counts(&nested_fields, &self.model)?;
         let nested = collect_nested_queries(nested_fields, &self.model)?;
 
         Ok(ReadQuery::RecordQuery(RecordQuery {
@@ -39,6 +40,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
             selected_fields,
             nested,
             selection_order,
+            relation_counts,
         }))
     }
 }
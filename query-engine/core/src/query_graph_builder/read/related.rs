@@ -27,6 +27,7 @@ impl Builder<ReadQuery> for ReadRelatedRecordsBuilder {
         let sub_selections = self.field.nested_fields.unwrap().fields;
         let selection_order: Vec<String> = collect_selection_order(&sub_selections);
         let selected_fields = collect_selected_fields(&sub_selections, &self.model, Some(Arc::clone(&self.parent)));
+        let relation_counts = collect_relation_counts(&sub_selections, &self.model)?;
         let nested = collect_nested_queries(sub_selections, &self.model)?;
         let parent_field = self.parent;
 
@@ -38,6 +39,7 @@ impl Builder<ReadQuery> for ReadRelatedRecordsBuilder {
             selected_fields,
             nested,
             selection_order,
+            relation_counts,
             parent_ids: None,
         }))
     }
@@ -1,14 +1,18 @@
 use super::*;
 use crate::query_document::{ParsedArgument, ParsedInputMap, ParsedInputValue};
-use connector::{filter::RecordFinder, QueryArguments};
-use prisma_models::{ModelRef, PrismaValue};
-use std::convert::TryInto;
+use connector::{
+    filter::{Filter, RecordFinder, ScalarCondition, ScalarFilter},
+    QueryArguments,
+};
+use prisma_models::{GraphqlId, ModelRef, OrderByField, PrismaValue, ScalarFieldRef};
+use std::convert::{TryFrom, TryInto};
 
 /// Extracts a RecordFinder from the given parsed input.
 /// Expects that, assuming an extraction is possible, arguments are structurally valid, meaning that
 /// previous validation guarantees that unwraps are safe. Panics otherwise.
 ///
-/// Expects the parsed value to be transformable into a ParsedInputMap.
+/// Expects the parsed value to be transformable into a ParsedInputMap. The map holds either a single unique field,
+/// or a compound unique (e.g. `a_b`) mapping to an object with the values of all its fields.
 ///
 /// Errors occur if the arguments are structurally correct, but it's semantically impossible
 /// to extract a record finder, e.g. if too many fields are given.
@@ -25,21 +29,46 @@ pub fn extract_record_finder(
         )))
     } else {
         let field_selector: (String, ParsedInputValue) = values.into_iter().next().unwrap();
-        let model_field = model.fields().find_from_scalar(&field_selector.0).unwrap();
-
-        match field_selector.1.try_into()? {
-            PrismaValue::Null => Err(QueryGraphBuilderError::InputError(format!(
-                "You provided a null value for the where clause (or implicit nested selector) on {}. Please provide a non null value.",
-                &model.name
-            ))),
-            x => Ok(RecordFinder {
-                field: model_field,
-                value: x,
-            }),
+
+        match model.fields().find_from_scalar(&field_selector.0) {
+            Ok(model_field) => Ok(RecordFinder::new(model_field, non_null_value(field_selector.1, model)?)),
+            Err(_) => {
+                let fields = model
+                    .compound_uniques()
+                    .into_iter()
+                    .find(|fields| compound_field_name(fields) == field_selector.0)
+                    .unwrap();
+
+                let mut compound_values: ParsedInputMap = field_selector.1.try_into()?;
+                let values = fields
+                    .into_iter()
+                    .map(|field| {
+                        let value = compound_values.remove(&field.name).unwrap();
+                        Ok((field, non_null_value(value, model)?))
+                    })
+                    .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+                Ok(RecordFinder::compound(values))
+            }
         }
     }
 }
 
+/// The name of the selector of a compound unique, e.g. `a_b` for a unique spanning fields `a` and `b`.
+pub fn compound_field_name(fields: &[ScalarFieldRef]) -> String {
+    fields.iter().map(|f| f.name.as_str()).collect::<Vec<&str>>().join("_")
+}
+
+fn non_null_value(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<PrismaValue> {
+    match value.try_into()? {
+        PrismaValue::Null => Err(QueryGraphBuilderError::InputError(format!(
+            "You provided a null value for the where clause (or implicit nested selector) on {}. Please provide a non null value.",
+            &model.name
+        ))),
+        x => Ok(x),
+    }
+}
+
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail unexpectedly.
@@ -65,12 +94,12 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                     }),
 
                     "after" => Ok(QueryArguments {
-                        after: arg.value.try_into()?,
+                        after: extract_cursor(arg.value, model)?,
                         ..res
                     }),
 
                     "before" => Ok(QueryArguments {
-                        before: arg.value.try_into()?,
+                        before: extract_cursor(arg.value, model)?,
                         ..res
                     }),
 
@@ -79,6 +108,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
+                    "distinct" => Ok(QueryArguments {
+                        distinct: extract_distinct_fields(arg.value, model)?,
+                        ..res
+                    }),
+
                     "where" => {
                         let val: Option<ParsedInputMap> = arg.value.try_into()?;
                         match val {
@@ -96,4 +130,102 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                 result
             }
         })
+        .and_then(with_relevance_queries)
+}
+
+/// Extracts the id of the cursor record of `after` or `before`. Cursors of models with a compound id are objects
+/// with the values of all id fields, e.g. `{ a: 1, b: 2 }`, and become a compound id.
+fn extract_cursor(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Option<GraphqlId>> {
+    if !model.has_compound_id() {
+        return Ok(value.try_into()?);
+    }
+
+    let cursor: Option<ParsedInputMap> = value.try_into()?;
+
+    match cursor {
+        Some(mut cursor) => {
+            let ids = model
+                .id_fields()
+                .into_iter()
+                .map(|field| {
+                    let value: PrismaValue = cursor.remove(&field.name).unwrap().try_into()?;
+                    Ok(GraphqlId::try_from(value)?)
+                })
+                .collect::<QueryGraphBuilderResult<Vec<GraphqlId>>>()?;
+
+            Ok(Some(GraphqlId::from_parts(ids)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Resolves the fields of the `distinct` argument (e.g. `distinct: [name, age]`) to the scalar fields of the model.
+/// A single field is accepted as well, an empty list makes no records distinct.
+fn extract_distinct_fields(
+    value: ParsedInputValue,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Option<Vec<ScalarFieldRef>>> {
+    let values = match value {
+        ParsedInputValue::Single(PrismaValue::Null) => return Ok(None),
+        ParsedInputValue::List(values) => values,
+        single => vec![single],
+    };
+
+    let mut fields: Vec<ScalarFieldRef> = vec![];
+
+    for value in values {
+        let field_name: Option<String> = value.try_into()?;
+
+        if let Some(field_name) = field_name {
+            let field = model.fields().find_from_scalar(&field_name)?;
+
+            if fields.iter().all(|distinct| distinct.name != field.name) {
+                fields.push(field);
+            }
+        }
+    }
+
+    if fields.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(fields))
+    }
+}
+
+/// Orderings by relevance rank the records by the search query of the `_search` filter on the same field,
+/// which is only known once all arguments are extracted.
+fn with_relevance_queries(mut query_args: QueryArguments) -> QueryGraphBuilderResult<QueryArguments> {
+    for order_by in query_args.order_by.iter_mut() {
+        if let OrderByField::Relevance(ref field, ref mut query) = order_by.field {
+            let search = query_args
+                .filter
+                .as_ref()
+                .and_then(|filter| search_query(filter, field));
+
+            match search {
+                Some(search) => *query = search,
+                None => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Ordering by the relevance of '{}' requires a '{}_search' filter.",
+                        field.name, field.name
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(query_args)
+}
+
+/// The query of a `_search` filter on the field that all records have to match.
+fn search_query(filter: &Filter, field: &ScalarFieldRef) -> Option<String> {
+    match filter {
+        Filter::And(filters) => filters.iter().find_map(|filter| search_query(filter, field)),
+        Filter::Scalar(ScalarFilter {
+            field: filter_field,
+            condition: ScalarCondition::Search(query),
+            ..
+        }) if filter_field.name == field.name => Some(format!("{}", query)),
+        _ => None,
+    }
 }
@@ -1,7 +1,10 @@
 use super::*;
 use crate::query_document::ParsedInputValue;
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue};
+use connector::{
+    filter::{Filter, QueryMode, ScalarCondition, ScalarFilter},
+    AggregateValue, HavingFilter, JsonCompare, RelationCompare, ScalarCompare, ScalarListCompare,
+};
+use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue, TypeIdentifier};
 use std::{collections::BTreeMap, convert::TryFrom, convert::TryInto};
 
 lazy_static! {
@@ -17,19 +20,29 @@ lazy_static! {
         FilterOp::Lte,
         FilterOp::Gt,
         FilterOp::Gte,
+        FilterOp::ArrayContains,
+        FilterOp::HasEvery,
+        FilterOp::HasSome,
+        FilterOp::Has,
+        FilterOp::IsEmpty,
         FilterOp::Contains,
         FilterOp::StartsWith,
         FilterOp::EndsWith,
+        FilterOp::Search,
         FilterOp::Some,
         FilterOp::None,
         FilterOp::Every,
         FilterOp::NestedAnd,
         FilterOp::NestedOr,
         FilterOp::NestedNot,
+        FilterOp::Mode,
         FilterOp::Field, // Needs to be last
     ];
 }
 
+/// Suffix of the filter field setting the path inside of a Json field that the filters of the field compare.
+const JSON_PATH_SUFFIX: &str = "_path";
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum FilterOp {
     In,
@@ -39,18 +52,25 @@ enum FilterOp {
     Lte,
     Gt,
     Gte,
+    ArrayContains,
+    Has,
+    HasEvery,
+    HasSome,
+    IsEmpty,
     Contains,
     NotContains,
     StartsWith,
     NotStartsWith,
     EndsWith,
     NotEndsWith,
+    Search,
     Some,
     None,
     Every,
     NestedAnd,
     NestedOr,
     NestedNot,
+    Mode,
     Field,
 }
 
@@ -74,18 +94,25 @@ impl FilterOp {
             FilterOp::Lte => "_lte",
             FilterOp::Gt => "_gt",
             FilterOp::Gte => "_gte",
+            FilterOp::ArrayContains => "_array_contains",
+            FilterOp::Has => "_has",
+            FilterOp::HasEvery => "_has_every",
+            FilterOp::HasSome => "_has_some",
+            FilterOp::IsEmpty => "_is_empty",
             FilterOp::Contains => "_contains",
             FilterOp::NotContains => "_not_contains",
             FilterOp::StartsWith => "_starts_with",
             FilterOp::NotStartsWith => "_not_starts_with",
             FilterOp::EndsWith => "_ends_with",
             FilterOp::NotEndsWith => "_not_ends_with",
+            FilterOp::Search => "_search",
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
             FilterOp::NestedAnd => "AND",
             FilterOp::NestedOr => "OR",
             FilterOp::NestedNot => "NOT",
+            FilterOp::Mode => "_mode",
             FilterOp::Field => "",
         }
     }
@@ -95,6 +122,15 @@ pub fn extract_filter(
     value_map: BTreeMap<String, ParsedInputValue>,
     model: &ModelRef,
 ) -> QueryGraphBuilderResult<Filter> {
+    let (modes, value_map): (BTreeMap<String, ParsedInputValue>, BTreeMap<String, ParsedInputValue>) = value_map
+        .into_iter()
+        .partition(|(key, _)| FilterOp::find_op(key.as_str()) == Some(FilterOp::Mode));
+
+    let (paths, value_map): (BTreeMap<String, ParsedInputValue>, BTreeMap<String, ParsedInputValue>) =
+        value_map.into_iter().partition(|(key, _)| is_json_path_key(key, model));
+
+    let modes = extract_query_modes(modes)?;
+    let paths = extract_json_paths(paths)?;
     let filters = value_map
         .into_iter()
         .map(|(key, value): (String, ParsedInputValue)| {
@@ -125,9 +161,42 @@ pub fn extract_filter(
                     let field = model.fields().find_from_all(&field_name).unwrap();
 
                     match field {
-                        Field::Scalar(s) => {
+                        Field::Scalar(s) if s.is_embedded_list() => {
+                            let value: PrismaValue = value.try_into()?;
+
+                            Ok(match op {
+                                FilterOp::Has => s.contains_element(value),
+                                FilterOp::HasEvery => {
+                                    s.contains_every_element(PrismaListValue::try_from(value)?.unwrap_or_default())
+                                }
+                                FilterOp::HasSome => {
+                                    s.contains_some_element(PrismaListValue::try_from(value)?.unwrap_or_default())
+                                }
+                                FilterOp::IsEmpty => match value {
+                                    PrismaValue::Boolean(false) => Filter::not(vec![s.contains_none()]),
+                                    _ => s.contains_none(),
+                                },
+                                _ => unreachable!(), // Validated by the query schema.
+                            })
+                        }
+                        // Without a path, Json fields are compared as a whole like other scalars.
+                        Field::Scalar(s)
+                            if s.type_identifier == TypeIdentifier::Json
+                                && (paths.contains_key(field_name) || op == FilterOp::ArrayContains) =>
+                        {
                             let value: PrismaValue = value.try_into()?;
+                            let path = paths.get(field_name).cloned().unwrap_or_default();
+
                             Ok(match op {
+                                FilterOp::Not => s.json_not_equals(path, value),
+                                FilterOp::ArrayContains => s.json_array_contains(path, value),
+                                FilterOp::Field => s.json_equals(path, value),
+                                _ => unreachable!(), // Validated by the query schema.
+                            })
+                        }
+                        Field::Scalar(s) => {
+                            let value: PrismaValue = value.try_into()?;
+                            let filter = match op {
                                 FilterOp::In => s.is_in(PrismaListValue::try_from(value)?),
                                 FilterOp::NotIn => s.not_in(PrismaListValue::try_from(value)?),
                                 FilterOp::Not => s.not_equals(value),
@@ -141,8 +210,14 @@ pub fn extract_filter(
                                 FilterOp::NotStartsWith => s.not_starts_with(value),
                                 FilterOp::EndsWith => s.ends_with(value),
                                 FilterOp::NotEndsWith => s.not_ends_with(value),
+                                FilterOp::Search => s.search(value),
                                 FilterOp::Field => s.equals(value),
                                 _ => unreachable!(),
+                            };
+
+                            Ok(match modes.get(field_name) {
+                                Some(mode) => with_query_mode(filter, *mode),
+                                None => filter,
                             })
                         }
                         Field::Relation(r) => {
@@ -173,3 +248,150 @@ pub fn extract_filter(
 
     Ok(Filter::and(filters))
 }
+
+/// Extracts the query modes of the `<field>_mode` filter fields, keyed by field name.
+fn extract_query_modes(
+    value_map: BTreeMap<String, ParsedInputValue>,
+) -> QueryGraphBuilderResult<BTreeMap<String, QueryMode>> {
+    value_map
+        .into_iter()
+        .map(|(key, value)| {
+            let field_name = key.trim_end_matches(FilterOp::Mode.suffix()).to_owned();
+            let mode: Option<String> = value.try_into()?;
+
+            let mode = match mode.as_ref().map(String::as_str) {
+                Some("insensitive") => QueryMode::Insensitive,
+                _ => QueryMode::Default,
+            };
+
+            Ok((field_name, mode))
+        })
+        .collect()
+}
+
+/// Whether the key is the `<field>_path` filter field of a Json field, e.g. `meta_path`.
+fn is_json_path_key(key: &str, model: &ModelRef) -> bool {
+    key.ends_with(JSON_PATH_SUFFIX)
+        && model
+            .fields()
+            .find_from_scalar(key.trim_end_matches(JSON_PATH_SUFFIX))
+            .map(|field| field.type_identifier == TypeIdentifier::Json && !field.is_list)
+            .unwrap_or(false)
+}
+
+/// Extracts the paths of the `<field>_path` filter fields, keyed by field name.
+fn extract_json_paths(
+    value_map: BTreeMap<String, ParsedInputValue>,
+) -> QueryGraphBuilderResult<BTreeMap<String, Vec<String>>> {
+    value_map
+        .into_iter()
+        .map(|(key, value)| {
+            let field_name = key.trim_end_matches(JSON_PATH_SUFFIX).to_owned();
+            let path = match value {
+                ParsedInputValue::List(segments) => segments
+                    .into_iter()
+                    .map(|segment| {
+                        let segment: Option<String> = segment.try_into()?;
+                        Ok(segment.unwrap_or_default())
+                    })
+                    .collect::<QueryGraphBuilderResult<Vec<String>>>()?,
+                _ => vec![],
+            };
+
+            Ok((field_name, path))
+        })
+        .collect()
+}
+
+fn with_query_mode(filter: Filter, mode: QueryMode) -> Filter {
+    match filter {
+        Filter::Scalar(scalar_filter) => Filter::Scalar(ScalarFilter { mode, ..scalar_filter }),
+        filter => filter,
+    }
+}
+
+/// Extracts a filter on aggregated values of groups of records (`having`).
+/// Filter keys consist of the aggregation, the aggregated field and the filter operation,
+/// e.g. `sum_price_gt` or `count_lte`.
+pub fn extract_having_filter(
+    value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<HavingFilter> {
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = FilterOp::find_op(key.as_str())
+                .ok_or_else(|| QueryGraphBuilderError::InputError(format!("Unknown having filter '{}'.", key)))?;
+
+            match op {
+                op if (op == FilterOp::NestedAnd || op == FilterOp::NestedOr || op == FilterOp::NestedNot) => {
+                    let value: QueryGraphBuilderResult<Vec<HavingFilter>> = match value {
+                        ParsedInputValue::List(values) => values
+                            .into_iter()
+                            .map(|val| extract_having_filter(val.try_into()?, model))
+                            .collect(),
+
+                        ParsedInputValue::Map(map) => extract_having_filter(map, model).map(|res| vec![res]),
+                        _ => unreachable!(),
+                    };
+
+                    value.map(|value| match op {
+                        FilterOp::NestedAnd => HavingFilter::And(value),
+                        FilterOp::NestedOr => HavingFilter::Or(value),
+                        FilterOp::NestedNot => HavingFilter::Not(value),
+                        _ => unreachable!(),
+                    })
+                }
+                op => {
+                    let aggregate_value = extract_aggregate_value(key.trim_end_matches(op.suffix()), model)?;
+                    let value: PrismaValue = value.try_into()?;
+                    let condition = match op {
+                        FilterOp::In => ScalarCondition::In(PrismaListValue::try_from(value)?),
+                        FilterOp::NotIn => ScalarCondition::NotIn(PrismaListValue::try_from(value)?),
+                        FilterOp::Not => ScalarCondition::NotEquals(value),
+                        FilterOp::Lt => ScalarCondition::LessThan(value),
+                        FilterOp::Lte => ScalarCondition::LessThanOrEquals(value),
+                        FilterOp::Gt => ScalarCondition::GreaterThan(value),
+                        FilterOp::Gte => ScalarCondition::GreaterThanOrEquals(value),
+                        FilterOp::Field => ScalarCondition::Equals(value),
+                        _ => {
+                            return Err(QueryGraphBuilderError::InputError(format!(
+                                "Unknown having filter '{}'.",
+                                key
+                            )))
+                        }
+                    };
+
+                    Ok(HavingFilter::Condition(aggregate_value, condition))
+                }
+            }
+        })
+        .collect::<QueryGraphBuilderResult<Vec<HavingFilter>>>()?;
+
+    Ok(HavingFilter::And(filters))
+}
+
+/// Resolves an aggregated value name (e.g. `count` or `sum_price`) of a having filter.
+fn extract_aggregate_value(name: &str, model: &ModelRef) -> QueryGraphBuilderResult<AggregateValue> {
+    if name == "count" {
+        return Ok(AggregateValue::Count);
+    }
+
+    let mut parts = name.splitn(2, '_');
+    let aggregation = parts.next().unwrap_or_default();
+    let field_name = parts.next().unwrap_or_default();
+    let field = model.fields().find_from_scalar(field_name).map_err(|_| {
+        QueryGraphBuilderError::InputError(format!("Unknown aggregated field '{}' in having filter.", field_name))
+    })?;
+
+    match aggregation {
+        "sum" => Ok(AggregateValue::Sum(field)),
+        "avg" => Ok(AggregateValue::Average(field)),
+        "min" => Ok(AggregateValue::Min(field)),
+        "max" => Ok(AggregateValue::Max(field)),
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "Unknown aggregation '{}' in having filter.",
+            aggregation
+        ))),
+    }
+}
@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap, ReadOneRecordBuilder,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue, ReadOneRecordBuilder,
 };
 use connector::filter::RecordFinder;
 use prisma_models::ModelRef;
@@ -11,7 +11,7 @@ use write_arguments::*;
 
 /// Creates a create record query and adds it to the query graph, together with it's nested queries and companion read query.
 pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let id_field = model.fields().id();
+    let id_fields = model.id_fields();
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
     let create_node = create::create_record_node(graph, Arc::clone(&model), data_map)?;
@@ -33,10 +33,7 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
             }?;
 
             if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = node {
-                let finder = RecordFinder {
-                    field: id_field,
-                    value: parent_id,
-                };
+                let finder = RecordFinder::id(id_fields, parent_id);
 
                 rq.record_finder = Some(finder);
             };
@@ -48,6 +45,57 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
+/// Creates a create many records query and adds it to the query graph.
+/// Nested writes are not supported, all records are created with as few statements as possible.
+pub fn create_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    let data_argument = field.arguments.lookup("data").unwrap();
+    let data_maps: Vec<ParsedInputValue> = data_argument.value.try_into()?;
+
+    let skip_duplicates: Option<bool> = match field.arguments.lookup("skipDuplicates") {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    let skip_duplicates = skip_duplicates.unwrap_or(false);
+    let mut args = Vec::with_capacity(data_maps.len());
+
+    for data_map in data_maps {
+        let create_args = WriteArguments::from(&model, data_map.try_into()?)?;
+
+        if let Some((relation_field, _)) = create_args.nested.first() {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Nested writes are not supported when creating many records, found '{}'.",
+                relation_field.name
+            )));
+        }
+
+        if skip_duplicates && !create_args.list.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(
+                "Duplicates can't be skipped when creating records with scalar list values.".to_owned(),
+            ));
+        }
+
+        let mut non_list_args = create_args.non_list;
+        non_list_args.add_datetimes(Arc::clone(&model));
+
+        args.push((non_list_args, create_args.list));
+    }
+
+    let create_many = WriteQuery::CreateManyRecords(CreateManyRecords {
+        model,
+        args,
+        skip_duplicates,
+    });
+
+    graph.create_node(Query::Write(create_many));
+
+    Ok(())
+}
+
 pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
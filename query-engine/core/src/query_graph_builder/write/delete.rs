@@ -44,21 +44,26 @@ pub fn delete_many_records(
         None => Filter::empty(),
     };
 
-    let read_query = utils::read_ids_infallible(&model, filter.clone());
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: Arc::clone(&model),
-        filter,
+        filter: filter.clone(),
     });
 
-    let read_query_node = graph.create_node(read_query);
     let delete_many_node = graph.create_node(Query::Write(delete_many));
 
-    utils::insert_deletion_checks(graph, &model, &read_query_node, &delete_many_node)?;
-    graph.create_edge(
-        &read_query_node,
-        &delete_many_node,
-        QueryGraphDependency::ExecutionOrder,
-    )?;
+    // The ids of the records to delete are only read if relations require checks before deleting them,
+    // otherwise the records are deleted with a single statement.
+    if !model.internal_data_model().fields_requiring_model(&model).is_empty() {
+        let read_query = utils::read_ids_infallible(&model, filter);
+        let read_query_node = graph.create_node(read_query);
+
+        utils::insert_deletion_checks(graph, &model, &read_query_node, &delete_many_node)?;
+        graph.create_edge(
+            &read_query_node,
+            &delete_many_node,
+            QueryGraphDependency::ExecutionOrder,
+        )?;
+    }
 
     Ok(())
 }
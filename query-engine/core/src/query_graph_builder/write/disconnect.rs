@@ -154,30 +154,30 @@ fn handle_one_to_x(
     }
 
     // Depending on where the relation is inlined, we update the parent or the child and check the other one for ID presence.
-    let (node_to_attach, node_to_check, model_to_update, relation_field_name, id_field) =
+    let (node_to_attach, node_to_check, model_to_update, relation_field_name, id_fields) =
         if parent_relation_field.relation_is_inlined_in_parent() {
             let parent_model = parent_relation_field.model();
             let relation_field_name = parent_relation_field.name.clone();
-            let parent_model_id = parent_model.fields().id();
+            let parent_model_id_fields = parent_model.id_fields();
 
             (
                 parent_node,
                 child_node,
                 parent_model,
                 relation_field_name,
-                parent_model_id,
+                parent_model_id_fields,
             )
         } else {
             let child_model = child_relation_field.model();
             let relation_field_name = child_relation_field.name.clone();
-            let child_model_id = child_model.fields().id();
+            let child_model_id_fields = child_model.id_fields();
 
             (
                 child_node,
                 parent_node,
                 child_model,
                 relation_field_name,
-                child_model_id,
+                child_model_id_fields,
             )
         };
 
@@ -201,10 +201,7 @@ fn handle_one_to_x(
 
             if let Node::Query(Query::Write(ref mut wq)) = child_node {
                 wq.inject_non_list_arg(relation_field_name, PrismaValue::Null);
-                wq.inject_record_finder(RecordFinder {
-                    field: id_field,
-                    value: parent_id,
-                });
+                wq.inject_record_finder(RecordFinder::id(id_fields, parent_id));
             }
 
             Ok(child_node)
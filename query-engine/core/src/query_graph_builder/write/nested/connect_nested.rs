@@ -315,7 +315,7 @@ fn handle_one_to_one(
     if !relation_inlined_parent {
         let update_node = utils::update_record_node_placeholder(graph, None, Arc::clone(child_model));
         let relation_field_name = child_relation_field.name.clone();
-        let child_model_id = child_model.fields().id();
+        let child_model_id_fields = child_model.id_fields();
 
         graph.create_edge(
             &read_new_child_node,
@@ -327,10 +327,7 @@ fn handle_one_to_one(
                 }?;
 
                 if let Node::Query(Query::Write(ref mut wq)) = child_node {
-                    wq.inject_record_finder(RecordFinder {
-                        field: child_model_id,
-                        value: parent_id,
-                    });
+                    wq.inject_record_finder(RecordFinder::id(child_model_id_fields, parent_id));
                 }
 
                 Ok(child_node)
@@ -358,7 +355,7 @@ fn handle_one_to_one(
         // Create an update node for Parent to set the connection to the child.
         let parent_model = parent_relation_field.model();
         let relation_field_name = parent_relation_field.name.clone();
-        let parent_model_id = parent_model.fields().id();
+        let parent_model_id_fields = parent_model.id_fields();
         let update_node = utils::update_record_node_placeholder(graph, None, parent_model);
 
         graph.create_edge(
@@ -388,10 +385,7 @@ fn handle_one_to_one(
                 }?;
 
                 if let Node::Query(Query::Write(ref mut wq)) = child_node {
-                    wq.inject_record_finder(RecordFinder {
-                        field: parent_model_id,
-                        value: parent_id,
-                    });
+                    wq.inject_record_finder(RecordFinder::id(parent_model_id_fields, parent_id));
                 }
 
                 Ok(child_node)
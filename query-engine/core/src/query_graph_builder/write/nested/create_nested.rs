@@ -338,7 +338,7 @@ fn handle_one_to_one(
     // For explanation see doc comment.
     if relation_inlined_parent && !parent_is_create {
         let parent_model = parent_relation_field.model();
-        let parent_model_id = parent_model.fields().id();
+        let parent_model_id_fields = parent_model.id_fields();
         let update_node = utils::update_record_node_placeholder(graph, None, parent_model);
 
         graph.create_edge(
@@ -368,10 +368,7 @@ fn handle_one_to_one(
                 }?;
 
                 if let Node::Query(Query::Write(ref mut wq)) = child_node {
-                    wq.inject_record_finder(RecordFinder {
-                        field: parent_model_id,
-                        value: parent_id,
-                    });
+                    wq.inject_record_finder(RecordFinder::id(parent_model_id_fields, parent_id));
                 }
 
                 Ok(child_node)
@@ -4,7 +4,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue,
 };
-use connector::{Filter, ScalarCompare};
+use connector::Filter;
 use prisma_models::{ModelRef, PrismaValue, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
@@ -42,7 +42,7 @@ pub fn connect_nested_delete(
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
-        let id_field = child_model.fields().id();
+        let id_fields = child_model.id_fields();
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, or_filter)?;
 
@@ -65,7 +65,7 @@ pub fn connect_nested_delete(
                 }
 
                 if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut ur))) = node {
-                    let ids_filter = id_field.is_in(Some(parent_ids));
+                    let ids_filter = Filter::ids(id_fields, parent_ids);
                     let new_filter = Filter::and(vec![ur.filter.clone(), ids_filter]);
 
                     ur.filter = new_filter;
@@ -79,7 +79,7 @@ pub fn connect_nested_delete(
         let should_delete = if let PrismaValue::Boolean(b) = val { b } else { false };
 
         if should_delete {
-            let id_field = child_model.fields().id();
+            let id_fields = child_model.id_fields();
             let find_child_records_node =
                 utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, Filter::empty())?;
 
@@ -102,10 +102,7 @@ pub fn connect_nested_delete(
                     }?;
 
                     if let Node::Query(Query::Write(ref mut wq)) = node {
-                        wq.inject_record_finder(RecordFinder {
-                            field: id_field,
-                            value: parent_id,
-                        });
+                        wq.inject_record_finder(RecordFinder::id(id_fields, parent_id));
                     }
 
                     Ok(node)
@@ -137,7 +134,7 @@ pub fn connect_nested_delete_many(
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
-        let id_field = child_model.fields().id();
+        let id_fields = child_model.id_fields();
 
         utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
 
@@ -146,7 +143,7 @@ pub fn connect_nested_delete_many(
             &delete_many_node,
             QueryGraphDependency::ParentIds(Box::new(move |mut node, parent_ids| {
                 if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut ur))) = node {
-                    let ids_filter = id_field.is_in(Some(parent_ids));
+                    let ids_filter = Filter::ids(id_fields, parent_ids);
                     let new_filter = Filter::and(vec![ur.filter.clone(), ids_filter]);
 
                     ur.filter = new_filter;
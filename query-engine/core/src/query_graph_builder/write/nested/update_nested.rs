@@ -5,7 +5,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue,
 };
-use connector::{Filter, ScalarCompare};
+use connector::Filter;
 use prisma_models::{ModelRef, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
@@ -55,7 +55,7 @@ pub fn connect_nested_update(
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, finder)?;
 
         let update_node = update::update_record_node(graph, None, Arc::clone(child_model), data.try_into()?)?;
-        let id_field = child_model.fields().id();
+        let id_fields = child_model.id_fields();
 
         graph.create_edge(
             &find_child_records_node,
@@ -69,10 +69,7 @@ pub fn connect_nested_update(
                 }?;
 
                 if let Node::Query(Query::Write(WriteQuery::UpdateRecord(ref mut ur))) = node {
-                    ur.where_ = Some(RecordFinder {
-                        field: id_field,
-                        value: parent_id,
-                    });
+                    ur.where_ = Some(RecordFinder::id(id_fields, parent_id));
                 }
 
                 Ok(node)
@@ -109,17 +106,18 @@ pub fn connect_nested_update_many(
             filter,
             non_list_args: update_args.non_list,
             list_args: update_args.list,
+            json_updates: update_args.json,
         });
 
         let update_many_node = graph.create_node(Query::Write(update_many));
-        let id_field = child_model.fields().id();
+        let id_fields = child_model.id_fields();
 
         graph.create_edge(
             &find_child_records_node,
             &update_many_node,
             QueryGraphDependency::ParentIds(Box::new(move |mut node, parent_ids| {
                 if let Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut ur))) = node {
-                    let ids_filter = id_field.is_in(Some(parent_ids));
+                    let ids_filter = Filter::ids(id_fields, parent_ids);
                     let new_filter = Filter::and(vec![ur.filter.clone(), ids_filter]);
 
                     ur.filter = new_filter;
@@ -51,7 +51,7 @@ pub fn connect_nested_upsert(
             })),
         )?;
 
-        let id_field = model.fields().id();
+        let id_fields = model.id_fields();
         graph.create_edge(
             &initial_read_node,
             &update_node,
@@ -63,7 +63,7 @@ pub fn connect_nested_upsert(
                             "[Query Graph] Expected a valid parent ID to be present for a nested update in a nested upsert."
                         ))),
                     }?;
-                    let finder = RecordFinder::new(id_field, parent_id);
+                    let finder = RecordFinder::id(id_fields, parent_id);
                     x.where_ = Some(finder);
                 }
                 Ok(node)
@@ -0,0 +1,63 @@
+use super::*;
+use crate::{query_ast::*, query_graph::QueryGraph, ArgumentListLookup, ParsedField};
+use prisma_models::PrismaValue;
+use serde_json::Value;
+use std::convert::TryInto;
+
+/// Creates a raw query returning rows and adds it to the query graph.
+pub fn query_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
+    let raw_query = raw_query(field)?;
+
+    graph.create_node(Query::Write(WriteQuery::QueryRaw(raw_query)));
+    Ok(())
+}
+
+/// Creates a raw statement returning the number of affected rows and adds it to the query graph.
+pub fn execute_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
+    let raw_query = raw_query(field)?;
+
+    graph.create_node(Query::Write(WriteQuery::ExecuteRaw(raw_query)));
+    Ok(())
+}
+
+/// Reads the query and its parameters from the arguments. The parameters are a JSON array,
+/// with one value per placeholder in the query.
+fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
+    let query_arg = field.arguments.lookup("query").unwrap();
+    let query: Option<String> = query_arg.value.try_into()?;
+
+    let parameters: Option<Value> = match field.arguments.lookup("parameters") {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    let parameters = match parameters {
+        Some(Value::Array(values)) => values.into_iter().map(raw_parameter).collect(),
+        Some(value) => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Expected the parameters of a raw query to be a JSON array, got '{}'.",
+                value
+            )))
+        }
+        None => vec![],
+    };
+
+    Ok(RawQuery {
+        query: query.unwrap(),
+        parameters,
+    })
+}
+
+/// Converts a JSON parameter into the closest Prisma value. Nested arrays and objects are passed as JSON.
+fn raw_parameter(value: Value) -> PrismaValue {
+    match value {
+        Value::Null => PrismaValue::Null,
+        Value::Bool(b) => PrismaValue::Boolean(b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => PrismaValue::Int(i),
+            None => PrismaValue::Float(n.as_f64().unwrap()),
+        },
+        Value::String(s) => PrismaValue::String(s),
+        value => PrismaValue::Json(value),
+    }
+}
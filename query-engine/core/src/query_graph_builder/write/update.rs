@@ -11,7 +11,7 @@ use write_arguments::*;
 
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
 pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let id_field = model.fields().id();
+    let id_fields = model.id_fields();
 
     // "where"
     let where_arg = field.arguments.lookup("where").unwrap();
@@ -39,10 +39,7 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
             }?;
 
             if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = node {
-                let finder = RecordFinder {
-                    field: id_field,
-                    value: parent_id,
-                };
+                let finder = RecordFinder::id(id_fields, parent_id);
 
                 rq.record_finder = Some(finder);
             };
@@ -69,7 +66,7 @@ pub fn update_many_records(
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
     let update_args = WriteArguments::from(&model, data_map)?;
 
-    let list_causes_update = !update_args.list.is_empty();
+    let list_causes_update = !update_args.list.is_empty() || !update_args.json.is_empty();
     let mut non_list_args = update_args.non_list;
 
     non_list_args.update_datetimes(Arc::clone(&model), list_causes_update);
@@ -79,6 +76,7 @@ pub fn update_many_records(
         filter,
         non_list_args,
         list_args: update_args.list,
+        json_updates: update_args.json,
     });
 
     graph.create_node(Query::Write(update_many));
@@ -94,7 +92,7 @@ pub fn update_record_node(
     data_map: ParsedInputMap,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let update_args = WriteArguments::from(&model, data_map)?;
-    let list_causes_update = !update_args.list.is_empty();
+    let list_causes_update = !update_args.list.is_empty() || !update_args.json.is_empty();
     let mut non_list_args = update_args.non_list;
 
     non_list_args.update_datetimes(Arc::clone(&model), list_causes_update);
@@ -104,6 +102,7 @@ pub fn update_record_node(
         where_: record_finder,
         non_list_args,
         list_args: update_args.list,
+        json_updates: update_args.json,
     };
 
     let node = graph.create_node(Query::Write(WriteQuery::UpdateRecord(ur)));
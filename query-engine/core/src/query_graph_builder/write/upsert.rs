@@ -52,7 +52,7 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
 
-    let id_field = model.fields().id();
+    let id_fields = model.id_fields();
     graph.create_edge(
         &update_node,
         &read_node_update,
@@ -65,10 +65,7 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
             }?;
 
             if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = node {
-                let finder = RecordFinder {
-                    field: id_field,
-                    value: parent_id,
-                };
+                let finder = RecordFinder::id(id_fields, parent_id);
 
                 rq.record_finder = Some(finder);
             };
@@ -77,7 +74,7 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         })),
     )?;
 
-    let id_field = model.fields().id();
+    let id_fields = model.id_fields();
 
     graph.create_edge(
         &create_node,
@@ -91,10 +88,7 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
             }?;
 
             if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = node {
-                let finder = RecordFinder {
-                    field: id_field,
-                    value: parent_id,
-                };
+                let finder = RecordFinder::id(id_fields, parent_id);
 
                 rq.record_finder = Some(finder);
             };
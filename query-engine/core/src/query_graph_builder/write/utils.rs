@@ -5,7 +5,7 @@ use crate::{
 };
 use connector::{filter::RecordFinder, Filter, QueryArguments};
 use itertools::Itertools;
-use prisma_models::{ModelRef, PrismaArgs, PrismaValue, RelationFieldRef, SelectedFields};
+use prisma_models::{ModelRef, PrismaArgs, PrismaValue, RelationFieldRef, SelectedField, SelectedFields};
 use std::{convert::TryInto, sync::Arc};
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -30,7 +30,7 @@ pub fn read_ids_infallible<T>(model: &ModelRef, filter: T) -> Query
 where
     T: Into<Filter>,
 {
-    let selected_fields: SelectedFields = model.fields().id().into();
+    let selected_fields: SelectedFields = model.id_fields().into();
     let filter: Filter = filter.into();
 
     let read_query = ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
@@ -41,6 +41,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: vec![],
     });
 
     Query::Read(read_query)
@@ -71,7 +72,12 @@ where
     T: Into<QueryArguments>,
 {
     let selected_fields = SelectedFields::new(
-        vec![parent_relation_field.related_model().fields().id().into()],
+        parent_relation_field
+            .related_model()
+            .id_fields()
+            .into_iter()
+            .map(SelectedField::from)
+            .collect(),
         Some(Arc::clone(parent_relation_field)),
     );
 
@@ -84,6 +90,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: vec![],
     })));
 
     graph.create_edge(
@@ -128,6 +135,7 @@ pub fn update_record_node_placeholder(
         where_: record_finder,
         non_list_args: args,
         list_args: vec![],
+        json_updates: vec![],
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateRecord(ur)))
@@ -172,7 +180,7 @@ pub fn insert_existing_1to1_related_model_checks(
     parent_relation_field: &RelationFieldRef,
 ) -> QueryGraphBuilderResult<()> {
     let child_model = parent_relation_field.related_model();
-    let child_model_id_field = child_model.fields().id();
+    let child_model_id_fields = child_model.id_fields();
     let child_side_required = parent_relation_field.related_field().is_required;
     let relation_inlined_parent = parent_relation_field.relation_is_inlined_in_parent();
     let rf = Arc::clone(&parent_relation_field);
@@ -214,10 +222,7 @@ pub fn insert_existing_1to1_related_model_checks(
             }?;
 
             if let Node::Query(Query::Write(ref mut wq)) = child_node {
-                let finder = RecordFinder {
-                    field: child_model_id_field,
-                    value: child_id,
-                };
+                let finder = RecordFinder::id(child_model_id_fields, child_id);
 
                 wq.inject_record_finder(finder);
                 wq.inject_non_list_arg(relation_field_name, PrismaValue::Null);
@@ -1,12 +1,16 @@
 use super::*;
 use crate::query_document::{ParsedInputMap, ParsedInputValue};
-use prisma_models::{Field, ModelRef, PrismaArgs, PrismaListValue, PrismaValue, RelationFieldRef};
+use connector::{JsonUpdate, JsonUpdateOperation};
+use prisma_models::{
+    Field, ModelRef, PrismaArgs, PrismaListValue, PrismaValue, RelationFieldRef, ScalarFieldRef, TypeIdentifier,
+};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
 pub struct WriteArguments {
     pub non_list: PrismaArgs,
     pub list: Vec<(String, PrismaListValue)>,
+    pub json: Vec<JsonUpdate>,
     pub nested: Vec<(RelationFieldRef, ParsedInputMap)>,
 }
 
@@ -17,6 +21,11 @@ impl WriteArguments {
         data_map.into_iter().try_fold(
             WriteArguments::default(),
             |mut args, (k, v): (String, ParsedInputValue)| {
+                if let Some((field, suffix)) = json_update_field(model, &k) {
+                    args.json.extend(extract_json_updates(&field, suffix, v)?);
+                    return Ok(args);
+                }
+
                 let field = model.fields().find_from_all(&k).unwrap();
                 match field {
                     Field::Scalar(sf) if sf.is_list => {
@@ -25,7 +34,12 @@ impl WriteArguments {
                             vals.into_iter().find(|(k, _)| k == "set").unwrap().1.try_into()?;
                         let list_value: PrismaListValue = set_value.try_into()?;
 
-                        args.list.push((sf.name.clone(), list_value))
+                        // Embedded lists are written with the other columns of the record.
+                        if sf.is_embedded_list() {
+                            args.non_list.insert(sf.name.clone(), PrismaValue::List(list_value))
+                        } else {
+                            args.list.push((sf.name.clone(), list_value))
+                        }
                     }
 
                     Field::Scalar(sf) => {
@@ -43,3 +57,60 @@ impl WriteArguments {
         )
     }
 }
+
+/// The Json field and operation suffix of a key changing a value inside of a Json field, e.g. `meta_set`.
+fn json_update_field(model: &ModelRef, key: &str) -> Option<(ScalarFieldRef, &'static str)> {
+    ["_set", "_merge"].iter().find_map(|suffix| {
+        if !key.ends_with(suffix) {
+            return None;
+        }
+
+        let field_name = &key[..key.len() - suffix.len()];
+
+        model
+            .fields()
+            .find_from_scalar(field_name)
+            .ok()
+            .filter(|field| field.type_identifier == TypeIdentifier::Json && !field.is_list)
+            .map(|field| (field, *suffix))
+    })
+}
+
+/// Extracts the updates of a Json field, given as a list of `{ path, value }` objects.
+fn extract_json_updates(
+    field: &ScalarFieldRef,
+    suffix: &str,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<Vec<JsonUpdate>> {
+    let updates: Vec<ParsedInputValue> = value.try_into()?;
+
+    updates
+        .into_iter()
+        .map(|update| {
+            let mut update: ParsedInputMap = update.try_into()?;
+
+            let path = match update.remove("path") {
+                Some(ParsedInputValue::List(segments)) => segments
+                    .into_iter()
+                    .map(|segment| {
+                        let segment: Option<String> = segment.try_into()?;
+                        Ok(segment.unwrap_or_default())
+                    })
+                    .collect::<QueryGraphBuilderResult<Vec<String>>>()?,
+                _ => vec![],
+            };
+
+            let value: PrismaValue = update.remove("value").unwrap().try_into()?;
+            let operation = match suffix {
+                "_set" => JsonUpdateOperation::Set(value),
+                _ => JsonUpdateOperation::Merge(value),
+            };
+
+            Ok(JsonUpdate {
+                field: field.name.clone(),
+                path,
+                operation,
+            })
+        })
+        .collect()
+}
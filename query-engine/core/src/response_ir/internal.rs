@@ -1,9 +1,11 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, CoreResult, QueryResult, RecordSelection,
+    CoreError, CoreResult, GroupBySelection, QueryResult, RecordAggregation, RecordGroups, RecordSelection,
+    RELATION_COUNT_FIELD,
 };
-use connector::ScalarListValues;
+use bigdecimal::BigDecimal;
+use connector::{AggregationResult, RelatedRecordsCount, ScalarListValues};
 use indexmap::IndexMap;
 use prisma_models::{GraphqlId, PrismaValue};
 use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
@@ -40,6 +42,8 @@ pub fn serialize_internal(
 ) -> CoreResult<CheckedItemsWithParents> {
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, typ, is_list, is_optional),
+        QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordGroups(rg) => serialize_record_groups(rg),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation
@@ -52,11 +56,98 @@ pub fn serialize_internal(
             Ok(result)
         }
 
+        QueryResult::Json(json) => {
+            let mut result = CheckedItemsWithParents::new();
+            result.insert(None, Item::Value(PrismaValue::Json(json)));
+
+            Ok(result)
+        }
+
         QueryResult::Id(_) => unimplemented!(),
         QueryResult::Unit => unimplemented!(),
     }
 }
 
+/// Serializes an aggregation into a single object of the selected fields.
+/// The results are in the order of the selection, nested aggregation objects (e.g. `sum { a b }`)
+/// consume one result per selected field.
+fn serialize_aggregation(record_aggregation: RecordAggregation) -> CoreResult<CheckedItemsWithParents> {
+    let mut results = record_aggregation.results.into_iter();
+    let mut map = Map::new();
+
+    for (key, nested) in record_aggregation.selection_order {
+        let item = serialize_aggregation_item(&key, nested, &mut results)?;
+        map.insert(key, item);
+    }
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::Map(map));
+
+    Ok(result)
+}
+
+/// Serializes groups of records into a list of objects of the selected fields.
+/// Grouped fields are taken from the grouped values, aggregations are serialized like a single aggregation.
+fn serialize_record_groups(record_groups: RecordGroups) -> CoreResult<CheckedItemsWithParents> {
+    let selection_order = record_groups.selection_order;
+    let items = record_groups
+        .groups
+        .into_iter()
+        .map(|group| {
+            let mut results = group.aggregations.into_iter();
+            let mut map = Map::new();
+
+            for selection in selection_order.iter() {
+                match selection {
+                    GroupBySelection::Field(key, index) => {
+                        map.insert(key.clone(), Item::Value(group.values[*index].clone()));
+                    }
+                    GroupBySelection::Aggregation(key, nested) => {
+                        let item = serialize_aggregation_item(key, nested.clone(), &mut results)?;
+                        map.insert(key.clone(), item);
+                    }
+                }
+            }
+
+            Ok(Item::Map(map))
+        })
+        .collect::<CoreResult<Vec<Item>>>()?;
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::List(items));
+
+    Ok(result)
+}
+
+/// Serializes a single selected aggregation, consuming one result, or one result per selected field
+/// for nested aggregation objects (e.g. `sum { a b }`).
+fn serialize_aggregation_item(
+    key: &str,
+    nested: Option<Vec<String>>,
+    results: &mut impl Iterator<Item = AggregationResult>,
+) -> CoreResult<Item> {
+    let mut next_value = |key: &str| {
+        results
+            .next()
+            .map(|result| Item::Value(result.into_value()))
+            .ok_or_else(|| CoreError::SerializationError(format!("Missing aggregation result for selection '{}'", key)))
+    };
+
+    match nested {
+        Some(nested_keys) => {
+            let mut nested_map = Map::new();
+
+            for nested_key in nested_keys {
+                let item = next_value(&nested_key)?;
+                nested_map.insert(nested_key, item);
+            }
+
+            Ok(Item::Map(nested_map))
+        }
+        None => next_value(key),
+    }
+}
+
 fn serialize_record_selection(
     record_selection: RecordSelection,
     typ: &OutputTypeRef,
@@ -139,6 +230,7 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
     // Move lists and nested out of result for separate processing.
     let nested = std::mem::replace(&mut result.nested, vec![]);
     let lists = std::mem::replace(&mut result.lists, vec![]);
+    let relation_counts = std::mem::replace(&mut result.relation_counts, vec![]);
 
     // { <nested field name> -> { parent ID -> items } }
     let mut nested_mapping: HashMap<String, CheckedItemsWithParents> = process_nested_results(nested, &typ)?;
@@ -149,6 +241,9 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
     // { <list field name> -> { parent ID -> items } }
     let mut list_mapping = process_scalar_lists(lists, &typ)?;
 
+    // [ (<count field name>, { record ID -> count }) ]
+    let count_mapping = process_relation_counts(relation_counts);
+
     // Finally, serialize the objects based on the selected fields.
     let mut object_mapping = UncheckedItemsWithParents::new();
     let scalar_field_names = result.scalars.field_names;
@@ -156,7 +251,7 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records {
-        let record_id = Some(record.collect_id(&scalar_field_names, &result.id_field)?);
+        let record_id = Some(record.collect_id(&scalar_field_names, &result.id_fields)?);
 
         if !object_mapping.contains_key(&record.parent_id) {
             object_mapping.insert(record.parent_id.clone(), vec![]);
@@ -170,6 +265,19 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
             let field = typ.find_field(field_name).unwrap();
             if !field.field_type.is_object() && !field.field_type.is_list() {
                 object.insert(field_name.to_owned(), serialize_scalar(val, &field.field_type)?);
+            } else if let OutputType::List(list_type) = field.field_type.borrow() {
+                // Embedded scalar lists are read with the record instead of from their scalar list table.
+                let values = match val {
+                    PrismaValue::List(Some(values)) => values,
+                    _ => vec![],
+                };
+
+                let converted: Vec<Item> = values
+                    .into_iter()
+                    .map(|val| serialize_scalar(val, list_type))
+                    .collect::<CoreResult<Vec<_>>>()?;
+
+                object.insert(field_name.to_owned(), Item::Ref(ItemRef::new(Item::List(converted))));
             }
         }
 
@@ -177,6 +285,18 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
         write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ);
         write_nested_items(&record_id, &mut list_mapping, &mut object, &typ);
 
+        // Write the counts of related records, records without related records don't have a count result.
+        if !count_mapping.is_empty() {
+            let mut counts = Map::new();
+
+            for (count_name, record_counts) in count_mapping.iter() {
+                let count = record_counts.get(&record_id).copied().unwrap_or(0);
+                counts.insert(count_name.to_owned(), Item::Value(PrismaValue::Int(count as i64)));
+            }
+
+            object.insert(RELATION_COUNT_FIELD.to_owned(), Item::Map(counts));
+        }
+
         // Reorder into final shape.
         let mut map = Map::new();
         result.fields.iter().for_each(|field_name| {
@@ -303,6 +423,23 @@ fn process_scalar_lists(
     Ok(list_mapping)
 }
 
+/// Processes relation counts into a more ergonomic structure of [ (<count field name>, { record ID -> count }) ], in selection order.
+fn process_relation_counts(
+    relation_counts: Vec<(String, Vec<RelatedRecordsCount>)>,
+) -> Vec<(String, HashMap<Option<GraphqlId>, usize>)> {
+    relation_counts
+        .into_iter()
+        .map(|(name, counts)| {
+            let record_counts = counts
+                .into_iter()
+                .map(|count| (Some(count.record_id), count.count))
+                .collect();
+
+            (name, record_counts)
+        })
+        .collect()
+}
+
 fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item> {
     match (&value, typ.borrow()) {
         (PrismaValue::Null, OutputType::Opt(_)) => Ok(Item::Value(PrismaValue::Null)),
@@ -340,8 +477,15 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f as i64),
                 (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
 
+                (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+                (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
+
                 (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
                 (ScalarType::Float, PrismaValue::Int(i)) => PrismaValue::Float(i as f64),
+                (ScalarType::Float, PrismaValue::Decimal(d)) => PrismaValue::Float(d.to_string().parse().unwrap()),
+
+                (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+                (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(BigDecimal::from(i)),
 
                 (ScalarType::Enum(ref et), PrismaValue::Enum(ref ev)) => match et.value_for(&ev.name) {
                     Some(_) => PrismaValue::Enum(ev.clone()),
@@ -357,6 +501,7 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
                 (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
                 (ScalarType::Json, PrismaValue::Json(j)) => PrismaValue::Json(j),
+                (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
                 (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
 
                 (st, pv) => {
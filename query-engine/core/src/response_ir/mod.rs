@@ -11,7 +11,7 @@
 mod internal;
 mod utils;
 
-use crate::{ExpressionResult, OutputType, OutputTypeRef};
+use crate::{metrics::OperationLabels, ExpressionResult, OutputType, OutputTypeRef};
 use indexmap::IndexMap;
 use internal::*;
 use prisma_models::PrismaValue;
@@ -58,6 +58,9 @@ pub struct IrSerializer {
 
     /// Output type describing the possible shape of the result
     pub output_type: OutputTypeRef,
+
+    /// The operation the result belongs to, for metrics.
+    pub operation: OperationLabels,
 }
 
 impl IrSerializer {
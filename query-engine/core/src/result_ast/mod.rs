@@ -1,4 +1,5 @@
-use connector::{QueryArguments, ScalarListValues};
+use crate::query_ast::GroupBySelection;
+use connector::{AggregationResult, QueryArguments, RecordGroup, RelatedRecordsCount, ScalarListValues};
 use prisma_models::{GraphqlId, ManyRecords};
 
 #[derive(Debug, Clone)]
@@ -6,6 +7,9 @@ pub enum QueryResult {
     Id(GraphqlId),
     Count(usize),
     RecordSelection(RecordSelection),
+    RecordAggregation(RecordAggregation),
+    RecordGroups(RecordGroups),
+    Json(serde_json::Value),
     Unit,
 }
 
@@ -28,9 +32,30 @@ pub struct RecordSelection {
     /// Scalar list results, field names mapped to their results
     pub lists: Vec<(String, Vec<ScalarListValues>)>,
 
+    /// Counts of related records, selected field names (or aliases) of the `_count` object mapped to their results
+    pub relation_counts: Vec<(String, Vec<RelatedRecordsCount>)>,
+
     /// Required for result processing
     pub query_arguments: QueryArguments,
 
-    /// Name of the id field of the contained records.
-    pub id_field: String,
+    /// Names of the id fields of the contained records.
+    pub id_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordAggregation {
+    /// Ordered list of selected field names (or aliases), with the selected field names of nested aggregation objects.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// Aggregation results, in the order of the selection.
+    pub results: Vec<AggregationResult>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordGroups {
+    /// Ordered list of selected grouped fields and aggregations of each group.
+    pub selection_order: Vec<GroupBySelection>,
+
+    /// Grouped values and aggregation results of each group, in the order of the groups.
+    pub groups: Vec<RecordGroup>,
 }
@@ -43,6 +43,10 @@ pub enum ConnectorCapability {
     MongoJoinRelationLinks,
     RelationLinkList,
     RelationLinkTable,
+    InsensitiveFilters,
+    JsonFilters,
+    ScalarListFilters,
+    FullTextSearch,
 }
 
 #[derive(Debug, PartialEq)]
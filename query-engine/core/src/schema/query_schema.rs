@@ -86,6 +86,33 @@ impl QuerySchema {
         self.query().get_fields().into_iter().find(|f| f.name == name).cloned()
     }
 
+    /// Finds an input object type by name.
+    pub fn find_input_object_type(&self, name: &str) -> Option<InputObjectTypeStrongRef> {
+        self.input_object_types.iter().find(|t| t.name == name).cloned()
+    }
+
+    /// Finds an enum type by name that is used as input anywhere in the schema.
+    /// Enum types are not stored centrally, which requires a scan of all input positions.
+    pub fn find_input_enum_type(&self, name: &str) -> Option<EnumTypeRef> {
+        let field_args = self
+            .query()
+            .get_fields()
+            .iter()
+            .chain(self.mutation().get_fields().iter())
+            .flat_map(|field| field.arguments.iter().map(|arg| arg.argument_type.clone()))
+            .collect::<Vec<InputType>>();
+
+        let input_fields = self
+            .input_object_types
+            .iter()
+            .flat_map(|obj| obj.get_fields().iter().map(|field| field.field_type.clone()));
+
+        field_args
+            .into_iter()
+            .chain(input_fields)
+            .find_map(|typ| typ.as_enum_type().filter(|et| et.name == name))
+    }
+
     pub fn mutation(&self) -> ObjectTypeStrongRef {
         match self.mutation.borrow() {
             OutputType::Object(ref o) => o.into_arc(),
@@ -149,7 +176,7 @@ pub struct Field {
 ///
 /// - A `GenericQueryBuilder` is a query builder that requires no additional context but
 ///   the parsed query document data from the incoming query and is thus not associated to any particular
-///   model. The `ResetData` query is such an example.
+///   model. The raw queries (`queryRaw`, `executeRaw`) are such an example.
 #[derive(Debug)]
 pub enum SchemaQueryBuilder {
     ModelQueryBuilder(ModelQueryBuilder),
@@ -160,7 +187,7 @@ impl SchemaQueryBuilder {
     pub fn build(&self, parsed_field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
         match self {
             Self::ModelQueryBuilder(m) => m.build(parsed_field),
-            _ => unimplemented!(),
+            Self::GenericQueryBuilder(g) => g.build(parsed_field),
         }
     }
 }
@@ -196,12 +223,14 @@ pub enum QueryTag {
     FindOne,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
     DeleteMany,
     UpsertOne,
     Aggregate,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -210,21 +239,37 @@ impl fmt::Display for QueryTag {
             QueryTag::FindOne => "findOne",
             QueryTag::FindMany => "findMany",
             QueryTag::CreateOne => "createOne",
+            QueryTag::CreateMany => "createMany",
             QueryTag::UpdateOne => "updateOne",
             QueryTag::UpdateMany => "updateMany",
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::GroupBy => "groupBy",
         };
 
         s.fmt(f)
     }
 }
 
-#[derive(Debug, Clone)]
+pub type GenericQueryBuilderFn = dyn (Fn(ParsedField) -> QueryGraphBuilderResult<QueryGraph>) + Send + Sync;
+
+/// Designates a top-level operation that is not bound to a model.
+#[derive(DebugStub)]
 pub struct GenericQueryBuilder {
-    // WIP
+    #[debug_stub = "#BuilderFn#"]
+    pub builder_fn: Box<GenericQueryBuilderFn>,
+}
+
+impl GenericQueryBuilder {
+    pub fn new(builder_fn: Box<GenericQueryBuilderFn>) -> Self {
+        Self { builder_fn }
+    }
+
+    pub fn build(&self, parsed_field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
+        (self.builder_fn)(parsed_field)
+    }
 }
 
 #[derive(Debug)]
@@ -304,10 +349,18 @@ impl InputType {
         InputType::Scalar(ScalarType::Int)
     }
 
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
     pub fn float() -> InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -320,6 +373,10 @@ impl InputType {
         InputType::Scalar(ScalarType::Json)
     }
 
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
@@ -327,6 +384,17 @@ impl InputType {
     pub fn id() -> InputType {
         InputType::Scalar(ScalarType::ID)
     }
+
+    /// Attempts to recurse through the type until an enum type is found.
+    pub fn as_enum_type(&self) -> Option<EnumTypeRef> {
+        match self {
+            InputType::Enum(et) => Some(Arc::clone(et)),
+            InputType::Scalar(ScalarType::Enum(et)) => Some(Arc::clone(et)),
+            InputType::List(inner) => inner.as_enum_type(),
+            InputType::Opt(inner) => inner.as_enum_type(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -359,10 +427,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Int)
     }
 
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
     pub fn float() -> OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -375,6 +451,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Json)
     }
 
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn uuid() -> OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
@@ -416,11 +496,14 @@ impl OutputType {
 pub enum ScalarType {
     String,
     Int,
+    BigInt,
     Float,
+    Decimal,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
     Json,
+    Bytes,
     UUID,
     ID,
 }
@@ -39,6 +39,20 @@ impl<'a> ArgumentBuilder<'a> {
         }
     }
 
+    /// Builds "data" and "skipDuplicates" arguments intended for the create many field.
+    pub fn create_many_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
+        let input_object_type = self.input_type_builder.into_arc().create_input_type(model, None);
+
+        if input_object_type.into_arc().is_empty() {
+            None
+        } else {
+            Some(vec![
+                argument("data", InputType::list(InputType::object(input_object_type)), None),
+                argument("skipDuplicates", InputType::opt(InputType::boolean()), None),
+            ])
+        }
+    }
+
     /// Builds "where" (unique) argument intended for the delete field.
     pub fn delete_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
         self.where_unique_argument(model).map(|arg| vec![arg])
@@ -94,4 +108,12 @@ impl<'a> ArgumentBuilder<'a> {
 
         vec![where_arg]
     }
+
+    /// Builds "query" and "parameters" arguments intended for the raw query fields.
+    pub fn raw_arguments(&self) -> Vec<Argument> {
+        vec![
+            argument("query", InputType::string(), None),
+            argument("parameters", InputType::opt(InputType::json()), None),
+        ]
+    }
 }
@@ -11,6 +11,9 @@ struct StaticFilterArguments {
     pub inclusion: Vec<FilterArgument>,
     pub alphanumeric: Vec<FilterArgument>,
     pub string: Vec<FilterArgument>,
+    pub fulltext: Vec<FilterArgument>,
+    pub json: Vec<FilterArgument>,
+    pub scalar_list: Vec<FilterArgument>,
     pub multi_relation: Vec<FilterArgument>,
     pub one_relation: Vec<FilterArgument>,
 }
@@ -43,6 +46,20 @@ lazy_static! {
             FilterArgument { suffix: "_not_ends_with",is_list: false } // All values not ending with the given string.
         ],
 
+        fulltext: vec![
+            FilterArgument { suffix: "_search",is_list: false } // All values matching the given full-text search query.
+        ],
+
+        json: vec![
+            FilterArgument { suffix: "_array_contains",is_list: false } // All values that are arrays containing the given value.
+        ],
+
+        scalar_list: vec![
+            FilterArgument { suffix: "_has",is_list: false }, // All lists containing the given value.
+            FilterArgument { suffix: "_has_every",is_list: true }, // All lists containing all of the given values.
+            FilterArgument { suffix: "_has_some",is_list: true } // All lists containing at least one of the given values.
+        ],
+
         multi_relation: vec![
             FilterArgument { suffix: "_every",is_list: false }, // All records where all records in the relation satisfy the given condition.
             FilterArgument { suffix: "_some",is_list: false }, // All records that have at least one record in the relation satisfying the given condition.
@@ -53,28 +70,39 @@ lazy_static! {
     };
 }
 
-pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
+pub fn get_field_filters<'a>(field: &ModelField, capabilities: &SupportedCapabilities) -> Vec<&'a FilterArgument> {
     let args = &FILTER_ARGUMENTS;
 
     if field.is_list() {
         match field.type_identifier() {
             TypeIdentifier::Relation => args.multi_relation.iter().collect(),
+            _ if is_embedded_list(field) && capabilities.has(ConnectorCapability::ScalarListFilters) => {
+                args.scalar_list.iter().collect()
+            }
             _ => vec![],
         }
     } else {
-        let filters = match field.type_identifier() {
+        let mut filters = match field.type_identifier() {
             TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::GraphQLID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::BigInt => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![],
+            TypeIdentifier::Json if capabilities.has(ConnectorCapability::JsonFilters) => vec![&args.base, &args.json],
+            TypeIdentifier::Json => vec![&args.base],
+            TypeIdentifier::Bytes => vec![&args.base, &args.inclusion],
             TypeIdentifier::Relation => vec![&args.one_relation],
         };
 
+        if is_searchable(field) && capabilities.has(ConnectorCapability::FullTextSearch) {
+            filters.push(&args.fulltext);
+        }
+
         filters
             .into_iter()
             .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
@@ -82,3 +110,75 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             .collect()
     }
 }
+
+/// String fields of a `@@fulltext` index can be searched with `_search`.
+fn is_searchable(field: &ModelField) -> bool {
+    match field {
+        ModelField::Scalar(sf) => {
+            sf.type_identifier == TypeIdentifier::String && sf.model().fulltext_index(sf).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Embedded scalar lists are stored in native arrays, which can be filtered by their elements.
+fn is_embedded_list(field: &ModelField) -> bool {
+    match field {
+        ModelField::Scalar(sf) => sf.is_embedded_list(),
+        _ => false,
+    }
+}
+
+/// Suffix of the filter field matching the records with an empty (`true`) or non-empty (`false`) scalar list,
+/// e.g. `tags_is_empty: true`.
+pub const IS_EMPTY_FILTER_SUFFIX: &str = "_is_empty";
+
+/// Whether the field is a scalar list whose emptiness can be filtered on.
+pub fn has_is_empty_filter(field: &ModelField) -> bool {
+    is_embedded_list(field)
+}
+
+/// Suffix of the filter field setting the `QueryMode` of the string filters of a field, e.g. `name_mode`.
+pub const MODE_FILTER_SUFFIX: &str = "_mode";
+
+/// Whether the field has string filters (`_contains`, `_starts_with`, ...), which can be made case-insensitive.
+pub fn has_string_filters(field: &ModelField, capabilities: &SupportedCapabilities) -> bool {
+    let string_filters = &FILTER_ARGUMENTS.string;
+
+    get_field_filters(field, capabilities)
+        .into_iter()
+        .any(|arg| string_filters.iter().any(|string_arg| string_arg.suffix == arg.suffix))
+}
+
+/// Suffix of the filter field setting the path inside of a Json field that the filters of the field compare,
+/// e.g. `meta_path: ["status"]`.
+pub const JSON_PATH_FILTER_SUFFIX: &str = "_path";
+
+/// Whether the field is a Json field, whose filters can compare a value at a path inside of the field.
+pub fn has_json_path_filters(field: &ModelField, capabilities: &SupportedCapabilities) -> bool {
+    !field.is_list()
+        && field.type_identifier() == TypeIdentifier::Json
+        && capabilities.has(ConnectorCapability::JsonFilters)
+}
+
+/// The values of the `<field>_mode` filter fields.
+pub fn query_mode_enum() -> EnumType {
+    enum_type(
+        "QueryMode",
+        vec![
+            EnumValue::string("default", "default".to_owned()),
+            EnumValue::string("insensitive", "insensitive".to_owned()),
+        ],
+    )
+}
+
+/// Filters on aggregated values (e.g. the sum of a field in a group of records).
+pub fn get_aggregation_filters<'a>() -> Vec<&'a FilterArgument> {
+    let args = &FILTER_ARGUMENTS;
+
+    vec![&args.base, &args.inclusion, &args.alphanumeric]
+        .into_iter()
+        .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
+        .flatten()
+        .collect()
+}
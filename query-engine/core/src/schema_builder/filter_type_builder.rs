@@ -62,6 +62,23 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the cursor of a model with a compound id for `after` and `before`, holding the values of all id fields.
+    pub fn cursor_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}CursorInput", model.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_fields = model
+            .id_fields()
+            .into_iter()
+            .map(|f| input_field(f.name.clone(), self.map_required_input_type(f), None))
+            .collect();
+
+        let input_object = Arc::new(input_object_type(name.clone(), input_fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
     pub fn filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
         if self.capabilities.has(ConnectorCapability::MongoJoinRelationLinks) {
             self.build_mongo_filter_object(model)
@@ -120,16 +137,96 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the filter object on aggregated values of groups of records (e.g. UserGroupByHavingInput).
+    /// Fields are named after the aggregation and the aggregated field, e.g. `count_gt` or `sum_age_lte`.
+    pub fn having_filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}GroupByHavingInput", model.name);
+        return_cached!(self.input_object_cache, &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let weak_ref = Arc::downgrade(&input_object);
+        let mut fields = vec![
+            input_field(
+                "AND",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "OR",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "NOT",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+        ];
+
+        fields.append(&mut Self::map_aggregation_input_fields("count", InputType::int()));
+
+        let scalar_fields = model.fields().scalar_non_list();
+        let numeric_fields = scalar_fields.iter().filter(|f| match f.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+            _ => false,
+        });
+
+        let comparable_fields = scalar_fields.iter().filter(|f| match f.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+            TypeIdentifier::DateTime => true,
+            _ => false,
+        });
+
+        for field in numeric_fields {
+            let mapped = self.map_required_input_type(Arc::clone(field));
+
+            fields.append(&mut Self::map_aggregation_input_fields(
+                &format!("sum_{}", field.name),
+                mapped,
+            ));
+
+            fields.append(&mut Self::map_aggregation_input_fields(
+                &format!("avg_{}", field.name),
+                InputType::float(),
+            ));
+        }
+
+        for field in comparable_fields {
+            let mapped = self.map_required_input_type(Arc::clone(field));
+
+            fields.append(&mut Self::map_aggregation_input_fields(
+                &format!("min_{}", field.name),
+                mapped.clone(),
+            ));
+
+            fields.append(&mut Self::map_aggregation_input_fields(
+                &format!("max_{}", field.name),
+                mapped,
+            ));
+        }
+
+        input_object.set_fields(fields);
+        weak_ref
+    }
+
     fn build_mongo_filter_object(&self, _model: ModelRef) -> InputObjectTypeRef {
         unimplemented!()
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
-        get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
+        let model_field = ModelField::Scalar(Arc::clone(&field));
+        let mut input_fields: Vec<InputField> = get_field_filters(&model_field, self.capabilities)
             .into_iter()
             .map(|arg| {
                 let field_name = format!("{}{}", field.name, arg.suffix);
-                let mapped = self.map_required_input_type(Arc::clone(&field));
+
+                // The filters of scalar lists compare the elements of the lists.
+                let mapped = match self.map_required_input_type(Arc::clone(&field)) {
+                    InputType::List(element) => *element,
+                    mapped => mapped,
+                };
 
                 if arg.is_list {
                     input_field(field_name, InputType::opt(InputType::list(mapped)), None)
@@ -137,6 +234,46 @@ impl<'a> FilterObjectTypeBuilder<'a> {
                     input_field(field_name, InputType::opt(mapped), None)
                 }
             })
+            .collect();
+
+        // The mode applies to all string filters of the field, e.g. `{ name_contains: "a", name_mode: insensitive }`.
+        if has_string_filters(&model_field, self.capabilities)
+            && self.capabilities.has(ConnectorCapability::InsensitiveFilters)
+        {
+            let field_name = format!("{}{}", field.name, MODE_FILTER_SUFFIX);
+            input_fields.push(input_field(field_name, InputType::opt(query_mode_enum().into()), None));
+        }
+
+        // The path applies to all filters of the field, e.g. `{ meta: "\"active\"", meta_path: ["status"] }`.
+        if has_json_path_filters(&model_field, self.capabilities) {
+            let field_name = format!("{}{}", field.name, JSON_PATH_FILTER_SUFFIX);
+            input_fields.push(input_field(
+                field_name,
+                InputType::opt(InputType::list(InputType::string())),
+                None,
+            ));
+        }
+
+        if has_is_empty_filter(&model_field) {
+            let field_name = format!("{}{}", field.name, IS_EMPTY_FILTER_SUFFIX);
+            input_fields.push(input_field(field_name, InputType::opt(InputType::boolean()), None));
+        }
+
+        input_fields
+    }
+
+    fn map_aggregation_input_fields(name: &str, mapped: InputType) -> Vec<InputField> {
+        get_aggregation_filters()
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", name, arg.suffix);
+
+                if arg.is_list {
+                    input_field(field_name, InputType::opt(InputType::list(mapped.clone())), None)
+                } else {
+                    input_field(field_name, InputType::opt(mapped.clone()), None)
+                }
+            })
             .collect()
     }
 
@@ -152,7 +289,7 @@ impl<'a> FilterObjectTypeBuilder<'a> {
                 InputType::opt(InputType::object(Weak::clone(&related_input_type))),
                 None,
             )],
-            (_, true) => get_field_filters(&ModelField::Relation(Arc::clone(&field)))
+            (_, true) => get_field_filters(&ModelField::Relation(Arc::clone(&field)), self.capabilities)
                 .into_iter()
                 .map(|arg| {
                     let field_name = format!("{}{}", field.name, arg.suffix);
@@ -1,4 +1,5 @@
 use super::*;
+use crate::query_graph_builder::compound_field_name;
 
 mod create_input_type_extension;
 mod input_builder_extensions;
@@ -101,15 +102,44 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
             .map(|f| Arc::clone(f))
             .collect();
 
-        let fields: Vec<InputField> = unique_fields
+        let mut fields: Vec<InputField> = unique_fields
             .into_iter()
             .map(|f| input_field(f.name.clone(), self.map_optional_input_type(f), None))
             .collect();
 
+        let mut compound_fields: Vec<InputField> = model
+            .compound_uniques()
+            .into_iter()
+            .map(|unique_fields| {
+                let name = compound_field_name(&unique_fields);
+                let input_type = self.compound_unique_object_type(&model, unique_fields);
+
+                input_field(name, InputType::opt(InputType::object(input_type)), None)
+            })
+            .collect();
+
+        fields.append(&mut compound_fields);
         input_object.set_fields(fields);
         Arc::downgrade(&input_object)
     }
 
+    /// Builds the input object holding the values of all fields of a compound unique, e.g. `{ a, b }`.
+    fn compound_unique_object_type(&self, model: &ModelRef, fields: Vec<ScalarFieldRef>) -> InputObjectTypeRef {
+        let field_names: Vec<String> = fields.iter().map(|f| capitalize(f.name.clone())).collect();
+        let name = format!("{}{}CompoundUniqueInput", model.name, field_names.join(""));
+        return_cached!(self.get_cache(), &name);
+
+        let input_fields = fields
+            .into_iter()
+            .map(|f| input_field(f.name.clone(), self.map_required_input_type(f), None))
+            .collect();
+
+        let input_object = Arc::new(input_object_type(name.clone(), input_fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
     fn get_filter_object_builder(&self) -> Arc<FilterObjectTypeBuilder<'a>>;
 }
 
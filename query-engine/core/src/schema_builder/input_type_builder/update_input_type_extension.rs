@@ -1,5 +1,8 @@
 use super::*;
 
+/// Suffixes of the update input fields changing values inside of a Json field, e.g. `meta_set` or `meta_merge`.
+pub const JSON_UPDATE_SUFFIXES: &[&str] = &["_set", "_merge"];
+
 pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + CreateInputTypeBuilderExtension<'a> {
     /// Builds "<x>UpdateInput" input object type.
     fn update_input_type(&self, model: ModelRef) -> InputObjectTypeRef {
@@ -33,13 +36,47 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
     }
 
     fn scalar_input_fields_for_update(&self, model: ModelRef) -> Vec<InputField> {
-        self.scalar_input_fields(
+        let mut fields = self.scalar_input_fields(
             model.name.clone(),
             "Update",
             model.fields().scalar(),
             |f: ScalarFieldRef| self.map_optional_input_type(f),
             false,
-        )
+        );
+
+        // Json fields can also be changed inside of the document, e.g. `meta_set: { path: ["status"], value: "1" }`.
+        let json_fields = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| f.type_identifier == TypeIdentifier::Json);
+
+        for field in json_fields {
+            for suffix in JSON_UPDATE_SUFFIXES {
+                let name = format!("{}{}", field.name, suffix);
+                let input_type = InputType::list(InputType::object(self.json_path_update_input_type()));
+
+                fields.push(input_field(name, InputType::opt(input_type), None));
+            }
+        }
+
+        fields
+    }
+
+    /// Builds the "JsonPathUpdateInput" input object type, a Json value at a path inside of a Json field.
+    fn json_path_update_input_type(&self) -> InputObjectTypeRef {
+        let name = "JsonPathUpdateInput";
+        return_cached!(self.get_cache(), name);
+
+        let fields = vec![
+            input_field("path", InputType::opt(InputType::list(InputType::string())), None),
+            input_field("value", InputType::json(), None),
+        ];
+
+        let input_object = Arc::new(input_object_type(name, fields));
+        self.cache(name.into(), Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
     }
 
     /// For update input types only. Compute input fields for relational fields.
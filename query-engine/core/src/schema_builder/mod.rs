@@ -17,10 +17,10 @@ use object_type_builder::*;
 use utils::*;
 
 /// Common module imports shared accross submodules.
-use crate::schema::*;
+use crate::{query_ast::RELATION_COUNT_FIELD, schema::*};
 use prisma_models::{
-    EnumType, EnumValue, Field as ModelField, FieldBehaviour, IdStrategy, InternalDataModelRef, ModelRef,
-    RelationFieldRef, ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
+    EnumType, EnumValue, Field as ModelField, FieldBehaviour, IdStrategy, InternalDataModelRef, ModelRef, NullOrdering,
+    OrderBy, OrderByField, RelationFieldRef, ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
 };
 use std::sync::{Arc, Weak};
 
@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct ObjectTypeBuilder<'a> {
@@ -68,7 +69,7 @@ impl<'a> ObjectTypeBuilder<'a> {
 
     /// This assumes that the cache has already been initialized.
     fn compute_fields(&self, model: &ModelRef) -> Vec<Field> {
-        model
+        let mut fields: Vec<Field> = model
             .fields()
             .all
             .iter()
@@ -80,7 +81,53 @@ impl<'a> ObjectTypeBuilder<'a> {
                     }
             })
             .map(|f| self.map_field(f))
-            .collect()
+            .collect();
+
+        if self.with_relations {
+            fields.extend(self.relation_count_field(model));
+        }
+
+        fields
+    }
+
+    /// Builds the `_count` field of a model, returning an object with the number of related records
+    /// of each to-many relation field (e.g. UserCountOutputType). Each count can be narrowed down
+    /// with a `where` filter on the related records. Models without to-many relations have no `_count` field.
+    fn relation_count_field(&self, model: &ModelRef) -> Option<Field> {
+        let relation_fields: Vec<RelationFieldRef> = model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|rf| rf.is_list && !rf.is_hidden && !rf.related_model().is_embedded)
+            .collect();
+
+        if relation_fields.is_empty() {
+            return None;
+        }
+
+        let object_name = format!("{}CountOutputType", capitalize(&model.name));
+        let object = ObjectTypeStrongRef::new(init_object_type(&object_name, None));
+        let object_fields = relation_fields
+            .iter()
+            .map(|rf| {
+                field(
+                    rf.name.clone(),
+                    vec![self.where_argument(&rf.related_model())],
+                    OutputType::int(),
+                    None,
+                )
+            })
+            .collect();
+
+        object.set_fields(object_fields);
+        self.cache(object_name, ObjectTypeStrongRef::clone(&object));
+
+        Some(field(
+            RELATION_COUNT_FIELD,
+            vec![],
+            OutputType::object(ObjectTypeStrongRef::downgrade(&object)),
+            None,
+        ))
     }
 
     pub fn map_field(&self, model_field: &ModelField) -> Field {
@@ -106,13 +153,16 @@ impl<'a> ObjectTypeBuilder<'a> {
             ModelField::Scalar(sf) => match sf.type_identifier {
                 TypeIdentifier::String => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
+                TypeIdentifier::Decimal => OutputType::decimal(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
                 TypeIdentifier::Json => OutputType::json(),
+                TypeIdentifier::Bytes => OutputType::bytes(),
                 TypeIdentifier::DateTime => OutputType::date_time(),
                 TypeIdentifier::GraphQLID => OutputType::id(),
                 TypeIdentifier::UUID => OutputType::uuid(),
                 TypeIdentifier::Int => OutputType::int(),
+                TypeIdentifier::BigInt => OutputType::big_int(),
                 TypeIdentifier::Relation => unreachable!(), // Scalar fields can't have a Relation type identifier.
             },
         };
@@ -141,20 +191,47 @@ impl<'a> ObjectTypeBuilder<'a> {
     }
 
     /// Builds "many records where" arguments solely based on the given model.
+    /// The `after` and `before` cursors of models with a compound id are objects with the values of all id fields.
     pub fn many_records_arguments(&self, model: &ModelRef) -> Vec<Argument> {
-        let id_field = model.fields().id();
-        let id_input_type = self.map_optional_input_type(id_field);
+        let cursor_type = match model.fields().id() {
+            Some(id_field) => self.map_optional_input_type(id_field),
+            None => {
+                let cursor_object = self
+                    .filter_object_type_builder
+                    .into_arc()
+                    .cursor_object_type(Arc::clone(model));
+
+                InputType::opt(InputType::object(cursor_object))
+            }
+        };
+
         vec![
             self.where_argument(&model),
             self.order_by_argument(&model),
             argument("skip", InputType::opt(InputType::int()), None),
-            argument("after", id_input_type.clone(), None),
-            argument("before", id_input_type, None),
+            argument("after", cursor_type.clone(), None),
+            argument("before", cursor_type, None),
             argument("first", InputType::opt(InputType::int()), None),
             argument("last", InputType::opt(InputType::int()), None),
+            self.distinct_argument(&model),
         ]
     }
 
+    /// Builds "distinct" argument, a list of the scalar fields the records are made distinct by.
+    pub fn distinct_argument(&self, model: &ModelRef) -> Argument {
+        let enum_values: Vec<EnumValue> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| EnumValue::string(f.name.clone(), f.name.clone()))
+            .collect();
+
+        let enum_type = enum_type(format!("{}DistinctFieldEnum", capitalize(&model.name)), enum_values);
+
+        argument("distinct", InputType::opt(InputType::list(enum_type.into())), None)
+    }
+
     /// Builds "where" argument.
     pub fn where_argument(&self, model: &ModelRef) -> Argument {
         let where_object = self
@@ -165,33 +242,85 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("where", InputType::opt(InputType::object(where_object)), None)
     }
 
-    // Builds "orderBy" argument.
+    /// Builds "orderBy" argument.
+    /// The argument is a list of orderings, applied in the given order. Single values are accepted as well.
+    /// Optional fields additionally allow to specify the position of nulls, e.g. `name_ASC_NULLS_LAST`.
+    /// Besides its own scalar fields, a model can be ordered by scalar fields of records related via a
+    /// to-one relation (e.g. `author_name_ASC`) and by the number of records of a to-many relation
+    /// (e.g. `comments_COUNT_DESC`). If the connector supports full-text search, fields that can be searched are
+    /// also ordered by the relevance of the `_search` filter on them (e.g. `title_RELEVANCE_DESC`).
+    /// Panics if two orderings have the same name, as a related ordering would shadow another one.
     pub fn order_by_argument(&self, model: &ModelRef) -> Argument {
-        let enum_values: Vec<EnumValue> = model
+        let scalar_fields = model.fields().scalar_non_list();
+        let mut fields: Vec<OrderByField> = scalar_fields.iter().cloned().map(OrderByField::Scalar).collect();
+
+        // The search query is taken from the filter when the query arguments are extracted.
+        if self.capabilities.has(ConnectorCapability::FullTextSearch) {
+            let searchable_fields = scalar_fields
+                .into_iter()
+                .filter(|sf| sf.type_identifier == TypeIdentifier::String && model.fulltext_index(sf).is_some());
+            fields.extend(searchable_fields.map(|sf| OrderByField::Relevance(sf, String::new())));
+        }
+
+        let relation_fields = model
             .fields()
-            .scalar_non_list()
-            .iter()
-            .map(|f| {
-                vec![
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Ascending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Ascending,
-                    ),
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Descending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Descending,
-                    ),
-                ]
+            .relation()
+            .into_iter()
+            .filter(|rf| !rf.is_hidden && !rf.related_model().is_embedded);
+
+        for rf in relation_fields {
+            if rf.is_list {
+                fields.push(OrderByField::RelationCount(Arc::clone(&rf)));
+            } else {
+                let related_fields = rf.related_model().fields().scalar_non_list();
+                fields.extend(
+                    related_fields
+                        .into_iter()
+                        .map(|sf| OrderByField::RelatedScalar(Arc::clone(&rf), sf)),
+                );
+            }
+        }
+
+        let mut names = HashSet::new();
+        let enum_values: Vec<EnumValue> = fields
+            .into_iter()
+            .map(|field| {
+                let null_orderings = if field.is_nullable() {
+                    vec![None, Some(NullOrdering::First), Some(NullOrdering::Last)]
+                } else {
+                    vec![None]
+                };
+
+                vec![SortOrder::Ascending, SortOrder::Descending]
+                    .into_iter()
+                    .flat_map(|sort_order| null_orderings.clone().into_iter().map(move |nulls| (sort_order, nulls)))
+                    .map(|(sort_order, null_ordering)| OrderBy {
+                        field: field.clone(),
+                        sort_order,
+                        null_ordering,
+                    })
+                    .collect::<Vec<OrderBy>>()
             })
             .flatten()
+            .map(|order_by| {
+                // Related orderings can clash with the orderings of scalar fields, e.g. `author_name` of a field
+                // `author_name` and of the field `name` of the relation `author`.
+                if !names.insert(order_by.name()) {
+                    panic!(
+                        "The ordering {} of model {} is ambiguous, it refers to more than one field. Rename one of the fields.",
+                        order_by.name(),
+                        model.name
+                    );
+                }
+
+                EnumValue::from(order_by)
+            })
             .collect();
 
         let enum_name = format!("{}OrderByInput", model.name);
         let enum_type = enum_type(enum_name, enum_values);
 
-        argument("orderBy", InputType::opt(enum_type.into()), None)
+        argument("orderBy", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
@@ -221,16 +350,154 @@ impl<'a> ObjectTypeBuilder<'a> {
     }
 
     /// Builds aggregation object type for given model (e.g. AggregateUser).
+    /// Besides `count`, numeric fields can be aggregated with `sum` and `avg`,
+    /// numeric and DateTime fields with `min` and `max`.
     pub fn aggregation_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
         let name = format!("Aggregate{}", capitalize(&model.name));
         return_cached!(self.get_cache(), &name);
 
         let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
-        let fields = vec![field("count", vec![], OutputType::int(), None)];
+        let mut fields = vec![field("count", vec![], OutputType::int(), None)];
+
+        fields.append(&mut self.aggregation_fields(model));
 
         object.set_fields(fields);
         self.cache(name, ObjectTypeStrongRef::clone(&object));
 
         ObjectTypeStrongRef::downgrade(&object)
     }
+
+    /// Builds the object type of a group of records (e.g. UserGroupBy).
+    /// Holds the grouped scalar fields of the model as well as the aggregations of the aggregation object type.
+    pub fn group_by_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
+        let name = format!("{}GroupBy", capitalize(&model.name));
+        return_cached!(self.get_cache(), &name);
+
+        let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
+        let mut fields: Vec<Field> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| self.map_field(&ModelField::Scalar(f)))
+            .collect();
+
+        fields.push(field("count", vec![], OutputType::int(), None));
+        fields.append(&mut self.aggregation_fields(model));
+
+        object.set_fields(fields);
+        self.cache(name, ObjectTypeStrongRef::clone(&object));
+
+        ObjectTypeStrongRef::downgrade(&object)
+    }
+
+    /// Builds "groupBy" arguments. Groups can only be ordered by grouped fields, which is validated
+    /// when building the query.
+    pub fn group_by_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+        let enum_values: Vec<EnumValue> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| EnumValue::string(f.name.clone(), f.name.clone()))
+            .collect();
+
+        let enum_type = enum_type(format!("{}GroupByFieldEnum", capitalize(&model.name)), enum_values);
+        let having_object = self
+            .filter_object_type_builder
+            .into_arc()
+            .having_filter_object_type(Arc::clone(model));
+
+        vec![
+            argument("by", InputType::list(enum_type.into()), None),
+            self.where_argument(&model),
+            self.order_by_argument(&model),
+            argument("having", InputType::opt(InputType::object(having_object)), None),
+            argument("skip", InputType::opt(InputType::int()), None),
+            argument("first", InputType::opt(InputType::int()), None),
+        ]
+    }
+
+    /// Builds the `sum`, `avg`, `min` and `max` aggregation fields of a model.
+    /// Numeric fields can be aggregated with `sum` and `avg`, numeric and DateTime fields with `min` and `max`.
+    fn aggregation_fields(&self, model: &ModelRef) -> Vec<Field> {
+        let mut fields = vec![];
+        let numeric_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| match f.type_identifier {
+                TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+                _ => false,
+            })
+            .collect();
+
+        let comparable_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| match f.type_identifier {
+                TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+                TypeIdentifier::DateTime => true,
+                _ => false,
+            })
+            .collect();
+
+        if !numeric_fields.is_empty() {
+            fields.push(self.aggregation_object_field("sum", model, &numeric_fields, Self::map_aggregated_type));
+            fields.push(self.aggregation_object_field("avg", model, &numeric_fields, |_| OutputType::float()));
+        }
+
+        if !comparable_fields.is_empty() {
+            fields.push(self.aggregation_object_field("min", model, &comparable_fields, Self::map_aggregated_type));
+            fields.push(self.aggregation_object_field("max", model, &comparable_fields, Self::map_aggregated_type));
+        }
+
+        fields
+    }
+
+    /// Builds an aggregation field (e.g. `sum`) returning an object with a field per aggregated model field
+    /// (e.g. UserSumAggregateOutputType). The aggregated values are null if there are no records to aggregate.
+    fn aggregation_object_field<F>(
+        &self,
+        name: &str,
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        type_mapper: F,
+    ) -> Field
+    where
+        F: Fn(&ScalarFieldRef) -> OutputType,
+    {
+        let object_name = format!("{}{}AggregateOutputType", capitalize(&model.name), capitalize(name));
+
+        // The object types are shared by the aggregation and the group by object types of the model.
+        let object = match self.get_cache().get(&object_name) {
+            Some(object) => object,
+            None => {
+                let object = ObjectTypeStrongRef::new(init_object_type(&object_name, None));
+                let object_fields = fields
+                    .iter()
+                    .map(|f| field(f.name.clone(), vec![], OutputType::opt(type_mapper(f)), None))
+                    .collect();
+
+                object.set_fields(object_fields);
+                self.cache(object_name, ObjectTypeStrongRef::clone(&object));
+
+                ObjectTypeStrongRef::downgrade(&object)
+            }
+        };
+
+        field(name, vec![], OutputType::object(object), None)
+    }
+
+    fn map_aggregated_type(field: &ScalarFieldRef) -> OutputType {
+        match field.type_identifier {
+            TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::BigInt => OutputType::big_int(),
+            TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
+            TypeIdentifier::DateTime => OutputType::date_time(),
+            _ => unreachable!(), // Only numeric and DateTime fields are aggregated.
+        }
+    }
 }
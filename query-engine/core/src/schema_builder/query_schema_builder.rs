@@ -46,7 +46,7 @@ pub enum BuildMode {
 pub struct QuerySchemaBuilder<'a> {
     mode: BuildMode,
     internal_data_model: InternalDataModelRef,
-    _capabilities: &'a SupportedCapabilities,
+    capabilities: &'a SupportedCapabilities,
     object_type_builder: Arc<ObjectTypeBuilder<'a>>,
     input_type_builder: Arc<InputTypeBuilder<'a>>,
     argument_builder: ArgumentBuilder<'a>,
@@ -80,7 +80,7 @@ impl<'a> QuerySchemaBuilder<'a> {
         QuerySchemaBuilder {
             mode,
             internal_data_model: Arc::clone(internal_data_model),
-            _capabilities: capabilities,
+            capabilities,
             object_type_builder,
             input_type_builder,
             argument_builder,
@@ -135,6 +135,7 @@ impl<'a> QuerySchemaBuilder<'a> {
                 let mut vec = vec![
                     self.all_items_field(Arc::clone(&m)),
                     self.aggregation_field(Arc::clone(&m)),
+                    self.group_by_field(Arc::clone(&m)),
                 ];
 
                 append_opt(&mut vec, self.single_item_field(Arc::clone(&m)));
@@ -151,11 +152,12 @@ impl<'a> QuerySchemaBuilder<'a> {
     /// Builds the root mutation type.
     fn build_mutation_type(&self) -> (OutputType, ObjectTypeStrongRef) {
         let non_embedded_models = self.non_embedded_models();
-        let fields = non_embedded_models
+        let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
+                append_opt(&mut vec, self.create_many_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.delete_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.update_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.upsert_item_field(Arc::clone(&model)));
@@ -168,6 +170,11 @@ impl<'a> QuerySchemaBuilder<'a> {
             .flatten()
             .collect();
 
+        if self.capabilities.has(ConnectorCapability::RawAccess) {
+            fields.push(self.query_raw_field());
+            fields.push(self.execute_raw_field());
+        }
+
         let strong_ref = Arc::new(object_type("Mutation", fields, None));
 
         (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref)
@@ -243,6 +250,7 @@ impl<'a> QuerySchemaBuilder<'a> {
     }
 
     /// Builds an "aggregate" query field (e.g. "aggregateUser") for given model.
+    /// The aggregations are computed over the records selected by the usual filter and pagination arguments.
     fn aggregation_field(&self, model: ModelRef) -> Field {
         let field_name = self.pluralize_internal(
             format!("aggregate{}", model.name.clone()), // Has no legacy counterpart.
@@ -251,7 +259,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         field(
             field_name,
-            vec![],
+            self.object_type_builder.many_records_arguments(&model),
             OutputType::object(self.object_type_builder.aggregation_object_type(&model)),
             Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
                 Arc::clone(&model),
@@ -267,6 +275,34 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a "groupBy" query field (e.g. "groupByUser") for given model.
+    /// Returns a list of groups of the records matching the filter, with their grouped values and aggregations.
+    fn group_by_field(&self, model: ModelRef) -> Field {
+        let field_name = self.pluralize_internal(
+            format!("groupBy{}", model.name.clone()), // Has no legacy counterpart.
+            format!("groupBy{}", model.name.clone()),
+        );
+
+        field(
+            field_name,
+            self.object_type_builder.group_by_arguments(&model),
+            OutputType::list(OutputType::object(
+                self.object_type_builder.group_by_object_type(&model),
+            )),
+            Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                Arc::clone(&model),
+                QueryTag::GroupBy,
+                Box::new(|model, parsed_field| {
+                    let mut graph = QueryGraph::new();
+                    let query = GroupByRecordsBuilder::new(parsed_field, model).build()?;
+
+                    graph.create_node(Query::Read(query));
+                    Ok(graph)
+                }),
+            ))),
+        )
+    }
+
     /// Builds a create mutation field (e.g. createUser) for given model.
     fn create_item_field(&self, model: ModelRef) -> Field {
         let args = self
@@ -296,6 +332,34 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a create many mutation field (e.g. createManyUsers) for given model.
+    fn create_many_field(&self, model: ModelRef) -> Option<Field> {
+        self.argument_builder
+            .create_many_arguments(Arc::clone(&model))
+            .map(|args| {
+                let field_name = self.pluralize_internal(
+                    format!("createMany{}", pluralize(model.name.clone())),
+                    format!("createMany{}", model.name.clone()),
+                );
+
+                field(
+                    field_name,
+                    args,
+                    OutputType::object(self.object_type_builder.batch_payload_object_type()),
+                    Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                        Arc::clone(&model),
+                        QueryTag::CreateMany,
+                        Box::new(|model, parsed_field| {
+                            let mut graph = QueryGraph::new();
+
+                            write::create_many_records(&mut graph, model, parsed_field)?;
+                            Ok(graph)
+                        }),
+                    ))),
+                )
+            })
+    }
+
     /// Builds a delete mutation field (e.g. deleteUser) for given model.
     fn delete_item_field(&self, model: ModelRef) -> Option<Field> {
         self.argument_builder.delete_arguments(Arc::clone(&model)).map(|args| {
@@ -424,6 +488,40 @@ impl<'a> QuerySchemaBuilder<'a> {
         })
     }
 
+    /// Builds the "queryRaw" mutation field, which runs a raw query and returns the resulting rows as JSON.
+    fn query_raw_field(&self) -> Field {
+        field(
+            "queryRaw",
+            self.argument_builder.raw_arguments(),
+            OutputType::json(),
+            Some(SchemaQueryBuilder::GenericQueryBuilder(GenericQueryBuilder::new(
+                Box::new(|parsed_field| {
+                    let mut graph = QueryGraph::new();
+
+                    write::query_raw(&mut graph, parsed_field)?;
+                    Ok(graph)
+                }),
+            ))),
+        )
+    }
+
+    /// Builds the "executeRaw" mutation field, which runs a raw statement and returns the number of affected rows.
+    fn execute_raw_field(&self) -> Field {
+        field(
+            "executeRaw",
+            self.argument_builder.raw_arguments(),
+            OutputType::int(),
+            Some(SchemaQueryBuilder::GenericQueryBuilder(GenericQueryBuilder::new(
+                Box::new(|parsed_field| {
+                    let mut graph = QueryGraph::new();
+
+                    write::execute_raw(&mut graph, parsed_field)?;
+                    Ok(graph)
+                }),
+            ))),
+        )
+    }
+
     fn pluralize_internal(&self, legacy: String, modern: String) -> String {
         match self.mode {
             BuildMode::Legacy => legacy,
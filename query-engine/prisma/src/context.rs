@@ -1,8 +1,6 @@
 use crate::{data_model_loader::*, exec_loader, PrismaError, PrismaResult};
-use query_core::{
-    schema::{QuerySchemaRef, SupportedCapabilities},
-    BuildMode, QueryExecutor, QuerySchemaBuilder,
-};
+use connector::PoolMetrics;
+use query_core::{schema::QuerySchemaRef, BuildMode, QueryExecutor, QuerySchemaBuilder};
 // use prisma_models::InternalDataModelRef;
 use std::sync::Arc;
 
@@ -48,7 +46,7 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let capabilities = SupportedCapabilities::empty(); // todo connector capabilities.
+        let capabilities = exec_loader::capabilities(&**data_source);
         let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode);
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
 
@@ -71,4 +69,8 @@ impl PrismaContext {
     pub fn primary_connector(&self) -> &'static str {
         self.executor.primary_connector()
     }
+
+    pub fn pool_metrics(&self) -> Option<PoolMetrics> {
+        self.executor.pool_metrics()
+    }
 }
@@ -67,10 +67,13 @@ impl<'a> DMMFTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -140,10 +143,13 @@ impl<'a> DMMFTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -1,6 +1,9 @@
 use crate::{PrismaError, PrismaResult};
 use connector::Connector;
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    schema::{ConnectorCapability, SupportedCapabilities},
+};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Source,
@@ -29,6 +32,30 @@ pub fn load(source: &dyn Source) -> PrismaResult<(String, Box<dyn QueryExecutor
     }
 }
 
+/// Returns the capabilities of the connector for the given source, which decide the operations of the query schema.
+pub fn capabilities(source: &dyn Source) -> SupportedCapabilities {
+    match source.connector_type() {
+        #[cfg(feature = "sql")]
+        POSTGRES_SOURCE_NAME => SupportedCapabilities {
+            capabilities: vec![ConnectorCapability::RawAccess],
+        },
+
+        // The full-text indexes of SQLite are FTS5 tables, which can be queried like any other table.
+        #[cfg(feature = "sql")]
+        SQLITE_SOURCE_NAME => SupportedCapabilities {
+            capabilities: vec![ConnectorCapability::RawAccess, ConnectorCapability::FullTextSearch],
+        },
+
+        // The default collations of MySQL compare strings case-insensitively.
+        #[cfg(feature = "sql")]
+        MYSQL_SOURCE_NAME => SupportedCapabilities {
+            capabilities: vec![ConnectorCapability::RawAccess, ConnectorCapability::InsensitiveFilters],
+        },
+
+        _ => SupportedCapabilities::empty(),
+    }
+}
+
 #[cfg(feature = "sql")]
 fn sqlite(source: &dyn Source) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
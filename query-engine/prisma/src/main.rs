@@ -41,6 +41,35 @@ lazy_static! {
     };
 }
 
+/// Settings of the query log, which records the SQL statements of each request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryLogSettings {
+    /// Emits the statements of every request as log events. Set with `QUERY_LOG=true`.
+    pub enabled: bool,
+
+    /// Replaces the parameters of logged statements. Set with `QUERY_LOG_REDACT_PARAMS=true`.
+    pub redact_params: bool,
+
+    /// Returns the statements of requests with the `x-prisma-debug` header in the response, which exposes the SQL
+    /// to clients. Set with `QUERY_LOG_DEBUG_HEADER=true`.
+    pub debug_header: bool,
+}
+
+lazy_static! {
+    pub static ref QUERY_LOG: QueryLogSettings = {
+        let flag = |key: &str| match env::var(key).as_ref().map(|s| s.as_str()) {
+            Ok("true") | Ok("1") => true,
+            _ => false,
+        };
+
+        QueryLogSettings {
+            enabled: flag("QUERY_LOG"),
+            redact_params: flag("QUERY_LOG_REDACT_PARAMS"),
+            debug_header: flag("QUERY_LOG_DEBUG_HEADER"),
+        }
+    };
+}
+
 pub type PrismaResult<T> = Result<T, PrismaError>;
 type AnyError = Box<dyn Error + Send + Sync + 'static>;
 
@@ -1,19 +1,34 @@
-use super::protocol_adapter::GraphQLProtocolAdapter;
-use crate::{context::PrismaContext, serializers::json, PrismaRequest, PrismaResult, RequestHandler};
-use query_core::{response_ir, CoreError};
+use super::{protocol_adapter::GraphQLProtocolAdapter, RawVariables};
+use crate::{context::PrismaContext, serializers::json, PrismaRequest, PrismaResult, RequestHandler, QUERY_LOG};
+use connector::QueryLog;
+use query_core::{metrics, response_ir, CoreError, Explanation};
 use graphql_parser as gql;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, sync::Arc};
+use serde_json::json;
+use std::sync::Arc;
 use async_trait::async_trait;
+use uuid::Uuid;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GraphQlBody {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: RawVariables,
+
+    /// Runs all operations of the query in a single transaction.
+    #[serde(default)]
+    transactional: bool,
 }
 
+/// Requests with this header get the statements they executed returned in the `extensions` of the response,
+/// if the server allows it with `QUERY_LOG_DEBUG_HEADER`.
+const DEBUG_HEADER: &str = "x-prisma-debug";
+
+/// Correlates the query log with the request. A random id is used if the request doesn't have the header.
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
 pub struct GraphQlRequestHandler;
 
 #[allow(unused_variables)]
@@ -25,12 +40,67 @@ impl RequestHandler for GraphQlRequestHandler {
     where
         S: Into<PrismaRequest<Self::Body>> + Send + Sync + 'static,
     {
-        let responses = match handle_graphql_query(req.into(), ctx).await {
+        let req = req.into();
+        let debug = QUERY_LOG.debug_header && req.headers.contains_key(DEBUG_HEADER);
+
+        let log = if QUERY_LOG.enabled || debug {
+            let request_id = match req.headers.get(REQUEST_ID_HEADER) {
+                Some(id) => id.clone(),
+                None => Uuid::new_v4().to_hyphenated().to_string(),
+            };
+
+            Some(QueryLog::new(request_id, QUERY_LOG.redact_params))
+        } else {
+            None
+        };
+
+        let result = match log {
+            Some(ref log) => log.clone().scope(handle_graphql_query(req, ctx)).await,
+            None => handle_graphql_query(req, ctx).await,
+        };
+
+        let responses = match result {
             Ok(responses) => responses,
             Err(err) => vec![err.into()],
         };
 
-        json::serialize(responses)
+        let mut response = json::serialize(responses);
+
+        if let Some(log) = log {
+            if QUERY_LOG.enabled {
+                emit_query_log(&log);
+            }
+
+            if debug {
+                let extensions = json!({
+                    "queryLog": {
+                        "requestId": log.request_id(),
+                        "queries": log.entries(),
+                    }
+                });
+
+                response["extensions"] = extensions;
+            }
+        }
+
+        response
+    }
+}
+
+/// Emits every logged statement of the request as a log event.
+fn emit_query_log(log: &QueryLog) {
+    for entry in log.entries() {
+        let params = serde_json::to_string(&entry.params).unwrap();
+
+        tracing::event!(
+            tracing::Level::INFO,
+            message = "query",
+            request_id = log.request_id(),
+            query = entry.query.as_str(),
+            params = params.as_str(),
+            duration_ms = tracing::field::display(entry.duration_ms),
+            rows = tracing::field::debug(&entry.rows),
+        );
     }
 }
 
@@ -41,14 +111,45 @@ async fn handle_graphql_query(
     debug!("Incoming GQL query: {:?}", &req.body.query);
 
     let gql_doc = gql::parse_query(&req.body.query)?;
-    let query_doc = GraphQLProtocolAdapter::convert(gql_doc, req.body.operation_name)?;
+    let query_doc =
+        GraphQLProtocolAdapter::convert(gql_doc, req.body.operation_name, req.body.variables, ctx.query_schema())?;
+
+    let query_schema = Arc::clone(ctx.query_schema());
+
+    let result = match req.transaction_id {
+        Some(ref tx_id) => {
+            ctx.executor
+                .execute_in_transaction(tx_id, query_doc, query_schema)
+                .await
+        }
+        None if req.body.transactional => ctx.executor.execute_transaction(query_doc, query_schema).await,
+        None => ctx.executor.execute(query_doc, query_schema).await,
+    };
+
+    result.map_err(|err| {
+        debug!("{}", err);
+        let ce: CoreError = err.into();
+        metrics::record_error(&ce);
+        ce.into()
+    })
+}
+
+/// Explains the operations of the GraphQL request, see `QueryExecutor::explain`.
+pub async fn explain_graphql_query(
+    body: GraphQlBody,
+    ctx: &PrismaContext,
+    explain_database: bool,
+) -> PrismaResult<Vec<Explanation>> {
+    let gql_doc = gql::parse_query(&body.query)?;
+    let query_doc = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables, ctx.query_schema())?;
+    let query_schema = Arc::clone(ctx.query_schema());
 
     ctx.executor
-        .execute(query_doc, Arc::clone(ctx.query_schema()))
+        .explain(query_doc, query_schema, explain_database)
         .await
         .map_err(|err| {
-            debug!("{}", err);
             let ce: CoreError = err.into();
+            metrics::record_error(&ce);
             ce.into()
         })
 }
@@ -1,6 +1,8 @@
 mod handler;
 mod protocol_adapter;
 mod schema_renderer;
+mod variables;
 
 pub use handler::*;
 pub use schema_renderer::*;
+pub use variables::*;
@@ -1,9 +1,16 @@
+use super::{RawVariables, Variables};
 use crate::{error::PrismaError, PrismaResult};
-use query_core::query_document::*;
+use query_core::{
+    query_document::*,
+    schema::{ObjectTypeStrongRef, QuerySchemaRef},
+};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet,
+    TypeCondition, Value,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+
+type Fragments = HashMap<String, FragmentDefinition>;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -13,30 +20,61 @@ use std::collections::BTreeMap;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables (`$name`) are substituted with the coerced values of the JSON payload, or the defaults of the
+///   operation's variable definitions. See `Variables` for the coercion rules.
+/// - Named fragments and inline fragments are expanded in place. Their type conditions are checked against the
+///   object type of the enclosing selection set. Nested fields selected multiple times are merged by response key,
+///   root fields are never merged, as every root field is an operation of its own.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
 /// - Subscription queries.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
+/// State shared by all selection sets of a single operation.
+struct SelectionContext<'a> {
+    variables: &'a Variables,
+    fragments: &'a Fragments,
+}
+
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<QueryDocument> {
+    pub fn convert(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: RawVariables,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<QueryDocument> {
+        let mut fragments = Fragments::new();
+        let mut definitions = vec![];
+
+        for def in gql_doc.definitions {
+            match def {
+                Definition::Fragment(f) => {
+                    if fragments.contains_key(&f.name) {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Fragment '{}' is defined more than once.",
+                            f.name
+                        )));
+                    }
+
+                    fragments.insert(f.name.clone(), f);
+                }
+                Definition::Operation(op) => definitions.push(op),
+            }
+        }
+
         let operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
+            Some(ref op) => definitions
                 .into_iter()
                 .find(|def| Self::matches_operation(def, op))
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
                 })
-                .and_then(|def| Self::convert_definition(def)),
+                .and_then(|def| Self::convert_definition(def, variables, &fragments, query_schema)),
 
-            None => gql_doc
-                .definitions
+            None => definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(|def| Self::convert_definition(def, variables.clone(), &fragments, query_schema))
                 .collect::<PrismaResult<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -44,31 +82,62 @@ impl GraphQLProtocolAdapter {
         Ok(QueryDocument { operations })
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Vec<Operation>> {
+    fn convert_definition(
+        def: OperationDefinition,
+        variables: RawVariables,
+        fragments: &Fragments,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<Vec<Operation>> {
         match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
+            OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
+                "Subscription query",
+                format!("At position {}.", s.position),
             )),
-            Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
-                    "Subscription query",
-                    format!("At position {}.", s.position),
-                )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
-            },
+            OperationDefinition::SelectionSet(s) => {
+                let variables = Variables::default();
+                let ctx = SelectionContext {
+                    variables: &variables,
+                    fragments,
+                };
+
+                Self::convert_query(&s, &query_schema.query(), &ctx)
+            }
+            OperationDefinition::Query(q) => {
+                let variables = Variables::resolve(&q.variable_definitions, variables, query_schema)?;
+                let ctx = SelectionContext {
+                    variables: &variables,
+                    fragments,
+                };
+
+                Self::convert_query(&q.selection_set, &query_schema.query(), &ctx)
+            }
+            OperationDefinition::Mutation(m) => {
+                let variables = Variables::resolve(&m.variable_definitions, variables, query_schema)?;
+                let ctx = SelectionContext {
+                    variables: &variables,
+                    fragments,
+                };
+
+                Self::convert_mutation(&m.selection_set, &query_schema.mutation(), &ctx)
+            }
         }
     }
 
-    fn convert_query(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
+    fn convert_query(
+        selection_set: &SelectionSet,
+        root: &ObjectTypeStrongRef,
+        ctx: &SelectionContext,
+    ) -> PrismaResult<Vec<Operation>> {
+        Self::expand_selection_set(selection_set, Some(root), ctx, &mut vec![])
             .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
     }
 
-    fn convert_mutation(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
+    fn convert_mutation(
+        selection_set: &SelectionSet,
+        root: &ObjectTypeStrongRef,
+        ctx: &SelectionContext,
+    ) -> PrismaResult<Vec<Operation>> {
+        Self::expand_selection_set(selection_set, Some(root), ctx, &mut vec![]).map(|fields| {
             fields
                 .into_iter()
                 .map(|selection| Operation::Write(selection))
@@ -76,59 +145,164 @@ impl GraphQLProtocolAdapter {
         })
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
+    /// Converts a selection set into a list of selections, expanding all fragments on the way.
+    /// The selections are not merged, see `merge_selections`.
+    /// `parent` is the object type the selection set is evaluated against, if it is known.
+    /// Unknown fields are left to the query document validation, which is why `parent` is optional.
+    /// `spread_path` holds the names of the fragments currently being expanded, in order to detect cycles.
+    fn expand_selection_set(
+        selection_set: &SelectionSet,
+        parent: Option<&ObjectTypeStrongRef>,
+        ctx: &SelectionContext,
+        spread_path: &mut Vec<String>,
+    ) -> PrismaResult<Vec<Selection>> {
+        let mut selections = vec![];
+
+        for item in selection_set.items.iter() {
+            match item {
                 GqlSelection::Field(f) => {
                     let arguments: Vec<(String, QueryValue)> = f
                         .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                        .iter()
+                        .map(|(k, v)| Ok((k.clone(), Self::convert_value(v.clone(), ctx.variables)?)))
                         .collect::<PrismaResult<Vec<_>>>()?;
 
-                    Ok(Selection {
-                        name: f.name,
-                        alias: f.alias,
+                    let field_object = parent
+                        .and_then(|obj| obj.find_field(f.name.as_str()))
+                        .and_then(|field| field.field_type.as_object_type());
+
+                    let nested_selections =
+                        Self::expand_selection_set(&f.selection_set, field_object.as_ref(), ctx, spread_path)?;
+
+                    selections.push(Selection {
+                        name: f.name.clone(),
+                        alias: f.alias.clone(),
                         arguments,
-                        nested_selections: Self::convert_selection_set(f.selection_set)?,
-                    })
+                        nested_selections: Self::merge_selections(nested_selections)?,
+                    });
                 }
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                GqlSelection::FragmentSpread(fs) => {
+                    if spread_path.contains(&fs.fragment_name) {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Fragment cycle detected: {} -> {}.",
+                            spread_path.join(" -> "),
+                            fs.fragment_name
+                        )));
+                    }
+
+                    let fragment = ctx.fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
+                    Self::check_type_condition(&fragment.type_condition, parent, || {
+                        format!("Fragment '{}', at position {},", fs.fragment_name, fs.position)
+                    })?;
+
+                    spread_path.push(fs.fragment_name.clone());
+                    let expanded = Self::expand_selection_set(&fragment.selection_set, parent, ctx, spread_path)?;
+                    spread_path.pop();
+
+                    selections.extend(expanded);
+                }
+
+                GqlSelection::InlineFragment(i) => {
+                    if let Some(ref type_condition) = i.type_condition {
+                        Self::check_type_condition(type_condition, parent, || {
+                            format!("Inline fragment at position {}", i.position)
+                        })?;
+                    }
+
+                    let expanded = Self::expand_selection_set(&i.selection_set, parent, ctx, spread_path)?;
+                    selections.extend(expanded);
+                }
+            }
+        }
+
+        Ok(selections)
+    }
+
+    /// Object types map 1:1 to models (or the root types), there are no interfaces or unions
+    /// in the query schema. A type condition therefore has to name the enclosing object type exactly.
+    fn check_type_condition<F>(
+        type_condition: &TypeCondition,
+        parent: Option<&ObjectTypeStrongRef>,
+        location: F,
+    ) -> PrismaResult<()>
+    where
+        F: FnOnce() -> String,
+    {
+        let TypeCondition::On(type_name) = type_condition;
+
+        match parent {
+            Some(obj) if &obj.name != type_name => Err(PrismaError::QueryConversionError(format!(
+                "{} can't be spread on type '{}': Type condition '{}' does not match.",
+                location(),
+                obj.name,
+                type_name
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Merges selections with the same response key (alias or name), which can occur if a field is selected
+    /// both directly and through fragments. Merged selections must select the same field with the same arguments,
+    /// in any order.
+    fn merge_selections(selections: Vec<Selection>) -> PrismaResult<Vec<Selection>> {
+        let mut merged: Vec<Selection> = Vec::with_capacity(selections.len());
+
+        for selection in selections {
+            let key = selection.alias.clone().unwrap_or_else(|| selection.name.clone());
+            let existing = merged.iter_mut().find(|s| s.alias.as_ref().unwrap_or(&s.name) == &key);
+
+            match existing {
+                Some(existing) => {
+                    if existing.name != selection.name
+                        || !Self::same_arguments(&existing.arguments, &selection.arguments)
+                    {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Conflicting selections for '{}': Fields must have the same name and arguments to be merged.",
+                            key
+                        )));
+                    }
+
+                    let mut nested = std::mem::replace(&mut existing.nested_selections, vec![]);
+                    nested.extend(selection.nested_selections);
+                    existing.nested_selections = Self::merge_selections(nested)?;
+                }
+                None => merged.push(selection),
+            }
+        }
+
+        Ok(merged)
+    }
+
+    fn same_arguments(left: &[(String, QueryValue)], right: &[(String, QueryValue)]) -> bool {
+        left.len() == right.len()
+            && left.iter().all(|(name, value)| {
+                right
+                    .iter()
+                    .any(|(other_name, other)| other_name == name && other == value)
             })
-            .collect()
     }
 
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
-    fn matches_operation(def: &Definition, operation: &str) -> bool {
+    fn matches_operation(def: &OperationDefinition, operation: &str) -> bool {
         let check = |n: Option<&String>| n.filter(|name| name.as_str() == operation).is_some();
         match def {
-            Definition::Fragment(_) => false,
-            Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => check(s.name.as_ref()),
-                OperationDefinition::SelectionSet(_) => false,
-                OperationDefinition::Query(q) => check(q.name.as_ref()),
-                OperationDefinition::Mutation(m) => check(m.name.as_ref()),
-            },
+            OperationDefinition::Subscription(s) => check(s.name.as_ref()),
+            OperationDefinition::SelectionSet(_) => false,
+            OperationDefinition::Query(q) => check(q.name.as_ref()),
+            OperationDefinition::Mutation(m) => check(m.name.as_ref()),
         }
     }
 
-    fn convert_value(value: Value) -> PrismaResult<QueryValue> {
+    fn convert_value(value: Value, variables: &Variables) -> PrismaResult<QueryValue> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => variables.get(&name),
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(PrismaError::QueryConversionError(format!(
@@ -144,7 +318,7 @@ impl GraphQLProtocolAdapter {
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .map(|v| Self::convert_value(v, variables))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
                 Ok(QueryValue::List(values))
@@ -152,7 +326,7 @@ impl GraphQLProtocolAdapter {
             Value::Object(map) => {
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .map(|(k, v)| Self::convert_value(v, variables).map(|v| (k, v)))
                     .collect::<PrismaResult<BTreeMap<String, QueryValue>>>()?;
 
                 Ok(QueryValue::Object(values))
@@ -49,10 +49,13 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -90,10 +93,13 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -0,0 +1,247 @@
+use crate::{error::PrismaError, PrismaResult};
+use graphql_parser::query::{Type, Value, VariableDefinition};
+use query_core::{
+    query_document::QueryValue,
+    schema::{InputObjectTypeStrongRef, InputType, IntoArc, QuerySchemaRef, ScalarType},
+};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, HashMap};
+
+/// Raw, untyped variable values as they are received in the JSON payload of a request.
+pub type RawVariables = HashMap<String, JsonValue>;
+
+/// Variable values of a single GraphQL operation, coerced into query values.
+///
+/// Coercion is driven by the variable definitions of the operation:
+/// - Provided values are checked against the declared GraphQL type of the variable. Named types are resolved
+///   against the query schema, which means that input objects and enums are coerced recursively and unknown
+///   fields, enum values or type names are rejected before the query document is built.
+/// - Variables that are not provided fall back to the default value of the definition, if any. Defaults are
+///   checked against the declared type in the same way.
+/// - Variables with a non-null type that are neither provided nor have a default cause an error.
+///
+/// The query document parser still validates the resulting values against the concrete argument types they
+/// are used with, just like with inlined values.
+#[derive(Debug, Default)]
+pub struct Variables {
+    values: HashMap<String, QueryValue>,
+}
+
+impl Variables {
+    pub fn resolve(
+        definitions: &[VariableDefinition],
+        mut raw: RawVariables,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<Self> {
+        let coercer = VariableCoercer { query_schema };
+        let mut values = HashMap::new();
+
+        for definition in definitions {
+            let value = match raw.remove(&definition.name) {
+                Some(value) => coercer.coerce(value, &definition.var_type).map_err(|reason| {
+                    PrismaError::QueryConversionError(format!(
+                        "Variable '${}' of type '{}' got an invalid value: {}",
+                        definition.name, definition.var_type, reason
+                    ))
+                })?,
+
+                None => match definition.default_value {
+                    Some(ref default) => default_to_json(default)
+                        .and_then(|default| coercer.coerce(default, &definition.var_type))
+                        .map_err(|reason| {
+                            PrismaError::QueryConversionError(format!(
+                                "Variable '${}' of type '{}' has an invalid default value: {}",
+                                definition.name, definition.var_type, reason
+                            ))
+                        })?,
+                    None if is_non_null(&definition.var_type) => {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Variable '${}' of required type '{}' was not provided.",
+                            definition.name, definition.var_type
+                        )))
+                    }
+                    None => QueryValue::Null,
+                },
+            };
+
+            values.insert(definition.name.clone(), value);
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Returns the value for a variable referenced as `$name` in the document.
+    pub fn get(&self, name: &str) -> PrismaResult<QueryValue> {
+        self.values.get(name).cloned().ok_or_else(|| {
+            PrismaError::QueryConversionError(format!("Variable '${}' is not defined by the operation.", name))
+        })
+    }
+}
+
+fn is_non_null(typ: &Type) -> bool {
+    match typ {
+        Type::NonNullType(_) => true,
+        _ => false,
+    }
+}
+
+/// Converts the literal default value of a variable definition into JSON, to be coerced like provided values.
+/// Enum values become strings. Defaults are constant, so they can't reference other variables.
+fn default_to_json(value: &Value) -> Result<JsonValue, String> {
+    match value {
+        Value::Null => Ok(JsonValue::Null),
+        Value::Boolean(b) => Ok(JsonValue::Bool(*b)),
+        Value::String(s) | Value::Enum(s) => Ok(JsonValue::String(s.clone())),
+        Value::Int(i) => i
+            .as_i64()
+            .map(JsonValue::from)
+            .ok_or_else(|| "Integer value out of range.".to_owned()),
+        Value::Float(f) => serde_json::Number::from_f64(*f)
+            .map(JsonValue::Number)
+            .ok_or_else(|| format!("Invalid float value {}.", f)),
+        Value::List(values) => values
+            .iter()
+            .map(default_to_json)
+            .collect::<Result<Vec<_>, _>>()
+            .map(JsonValue::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| default_to_json(value).map(|value| (key.clone(), value)))
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(JsonValue::Object),
+        Value::Variable(name) => Err(format!("Default values can't reference variables, found '${}'.", name)),
+    }
+}
+
+struct VariableCoercer<'a> {
+    query_schema: &'a QuerySchemaRef,
+}
+
+impl<'a> VariableCoercer<'a> {
+    /// Coerces a JSON value into a query value based on a declared GraphQL type.
+    fn coerce(&self, value: JsonValue, typ: &Type) -> Result<QueryValue, String> {
+        match (value, typ) {
+            (JsonValue::Null, Type::NonNullType(_)) => Err("Expected a non-null value, got null.".into()),
+            (value, Type::NonNullType(inner)) => self.coerce(value, inner),
+            (JsonValue::Null, _) => Ok(QueryValue::Null),
+            (JsonValue::Array(values), Type::ListType(inner)) => values
+                .into_iter()
+                .map(|value| self.coerce(value, inner))
+                .collect::<Result<Vec<_>, _>>()
+                .map(QueryValue::List),
+
+            // Input coercion rules allow single values in list positions.
+            (value, Type::ListType(inner)) => self.coerce(value, inner).map(|value| QueryValue::List(vec![value])),
+            (value, Type::NamedType(name)) => self.coerce_named(value, name),
+        }
+    }
+
+    fn coerce_named(&self, value: JsonValue, name: &str) -> Result<QueryValue, String> {
+        let scalar = match name {
+            "String" => Some(ScalarType::String),
+            "Int" => Some(ScalarType::Int),
+            "BigInt" => Some(ScalarType::BigInt),
+            "Float" => Some(ScalarType::Float),
+            "Decimal" => Some(ScalarType::Decimal),
+            "Boolean" => Some(ScalarType::Boolean),
+            "DateTime" => Some(ScalarType::DateTime),
+            "Json" => Some(ScalarType::Json),
+            "Bytes" => Some(ScalarType::Bytes),
+            "UUID" => Some(ScalarType::UUID),
+            "ID" => Some(ScalarType::ID),
+            _ => None,
+        };
+
+        if let Some(scalar) = scalar {
+            return self.coerce_scalar(value, &scalar);
+        }
+
+        if let Some(obj) = self.query_schema.find_input_object_type(name) {
+            return self.coerce_object(value, obj);
+        }
+
+        match self.query_schema.find_input_enum_type(name) {
+            Some(et) => self.coerce_scalar(value, &ScalarType::Enum(et)),
+            None => Err(format!("Unknown input type '{}'.", name)),
+        }
+    }
+
+    /// Coerces a JSON value into a query value based on an input type of the query schema.
+    fn coerce_input(&self, value: JsonValue, input_type: &InputType) -> Result<QueryValue, String> {
+        match (value, input_type) {
+            (JsonValue::Null, _) => Ok(QueryValue::Null),
+            (value, InputType::Opt(inner)) => self.coerce_input(value, inner),
+            (JsonValue::Array(values), InputType::List(inner)) => values
+                .into_iter()
+                .map(|value| self.coerce_input(value, inner))
+                .collect::<Result<Vec<_>, _>>()
+                .map(QueryValue::List),
+
+            (value, InputType::List(inner)) => self
+                .coerce_input(value, inner)
+                .map(|value| QueryValue::List(vec![value])),
+            (value, InputType::Object(obj)) => self.coerce_object(value, obj.into_arc()),
+            (value, InputType::Enum(et)) => self.coerce_scalar(value, &ScalarType::Enum(et.clone())),
+            (value, InputType::Scalar(scalar)) => self.coerce_scalar(value, scalar),
+        }
+    }
+
+    fn coerce_object(&self, value: JsonValue, obj: InputObjectTypeStrongRef) -> Result<QueryValue, String> {
+        match value {
+            JsonValue::Object(map) => map
+                .into_iter()
+                .map(|(key, value)| match obj.find_field(key.as_str()) {
+                    Some(field) => self
+                        .coerce_input(value, &field.field_type)
+                        .map(|value| (key, value))
+                        .map_err(|reason| format!("Field '{}.{}': {}", obj.name, field.name, reason)),
+
+                    None => Err(format!("Field '{}' does not exist on input type '{}'.", key, obj.name)),
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()
+                .map(QueryValue::Object),
+
+            value => Err(format!(
+                "Expected an object of input type '{}', got {}.",
+                obj.name, value
+            )),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn coerce_scalar(&self, value: JsonValue, scalar: &ScalarType) -> Result<QueryValue, String> {
+        match (value, scalar) {
+            (JsonValue::String(s), ScalarType::String)   => Ok(QueryValue::String(s)),
+            (JsonValue::String(s), ScalarType::DateTime) => Ok(QueryValue::String(s)),
+            (JsonValue::String(s), ScalarType::UUID)     => Ok(QueryValue::String(s)),
+            (JsonValue::String(s), ScalarType::ID)       => Ok(QueryValue::String(s)),
+            (JsonValue::Number(n), ScalarType::ID)       => n.as_i64().map(QueryValue::Int).ok_or_else(|| format!("Expected an integer or string ID, got {}.", n)),
+            (JsonValue::Number(n), ScalarType::Int)      => n.as_i64().map(QueryValue::Int).ok_or_else(|| format!("Expected a 64 bit integer, got {}.", n)),
+            (JsonValue::Number(n), ScalarType::Float)    => n.as_f64().map(QueryValue::Float).ok_or_else(|| format!("Expected a float, got {}.", n)),
+            (JsonValue::Bool(b), ScalarType::Boolean)    => Ok(QueryValue::Boolean(b)),
+
+            // Decimals are accepted as strings and numbers, and parsed from their text to stay exact.
+            (JsonValue::String(s), ScalarType::Decimal)  => Ok(QueryValue::String(s)),
+            (JsonValue::Number(n), ScalarType::Decimal)  => Ok(QueryValue::String(n.to_string())),
+
+            // BigInts are accepted as strings for clients without 64 bit integers, and as numbers.
+            (JsonValue::String(s), ScalarType::BigInt)   => Ok(QueryValue::String(s)),
+            (JsonValue::Number(n), ScalarType::BigInt)   => n.as_i64().map(QueryValue::Int).ok_or_else(|| format!("Expected a 64 bit integer, got {}.", n)),
+
+            // Bytes are base64 encoded.
+            (JsonValue::String(s), ScalarType::Bytes)    => Ok(QueryValue::String(s)),
+
+            // Json values are accepted in both serialized and structured form.
+            (JsonValue::String(s), ScalarType::Json)     => Ok(QueryValue::String(s)),
+            (value, ScalarType::Json)                    => Ok(QueryValue::String(value.to_string())),
+
+            (JsonValue::String(s), ScalarType::Enum(et)) => match et.value_for(s.as_str()) {
+                                                                Some(_) => Ok(QueryValue::Enum(s)),
+                                                                None => Err(format!("Value '{}' is not a member of enum '{}'.", s, et.name)),
+                                                            },
+            (value, ScalarType::Enum(et))                => Err(format!("Expected a member of enum '{}', got {}.", et.name, value)),
+
+            (value, scalar)                             => Err(format!("Expected a value of type {:?}, got {}.", scalar, value)),
+        }
+    }
+}
@@ -21,4 +21,7 @@ pub struct PrismaRequest<T> {
     pub body: T,
     pub headers: HashMap<String, String>,
     pub path: String,
+
+    /// The interactive transaction to run the request in, if any.
+    pub transaction_id: Option<String>,
 }
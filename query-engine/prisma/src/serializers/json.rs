@@ -71,6 +71,7 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
             Some(num) => num,
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
+        PrismaValue::Decimal(x) => Value::String(serialize_decimal(x.to_string())),
         PrismaValue::Boolean(x) => Value::Bool(x),
         PrismaValue::DateTime(date) => Value::String(format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))),
         PrismaValue::Enum(x) => Value::String(x.as_string()),
@@ -79,6 +80,8 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
             Some(num) => num,
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
+        PrismaValue::BigInt(x) => Value::String(x.to_string()),
+        PrismaValue::Bytes(x) => Value::String(base64::encode(&x)),
         PrismaValue::Null => Value::Null,
         PrismaValue::Uuid(x) => Value::String(x.to_hyphenated().to_string()),
         PrismaValue::GraphqlId(x) => serialize_graphql_id(&x)?,
@@ -86,6 +89,16 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
     })
 }
 
+/// Decimal columns have a fixed scale, so the trailing zeros of the fraction the database pads values with are
+/// removed.
+fn serialize_decimal(s: String) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
 fn serialize_graphql_id(id: &GraphqlId) -> PrismaResult<Value> {
     Ok(match id {
         GraphqlId::String(x) => Value::String(x.clone()),
@@ -94,5 +107,6 @@ fn serialize_graphql_id(id: &GraphqlId) -> PrismaResult<Value> {
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
         GraphqlId::UUID(x) => Value::String(x.to_hyphenated().to_string()),
+        GraphqlId::Compound(ids) => Value::Array(ids.iter().map(serialize_graphql_id).collect::<PrismaResult<_>>()?),
     })
 }
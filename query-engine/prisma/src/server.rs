@@ -2,23 +2,44 @@ use super::dmmf;
 use crate::{
     context::PrismaContext,
     request_handlers::{
-        graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
+        graphql::{explain_graphql_query, GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
         PrismaRequest, RequestHandler,
     },
     PrismaResult,
 };
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{metrics, schema::QuerySchemaRenderer, Explanation, MAX_TRANSACTION_TIMEOUT};
 use futures::stream::TryStreamExt;
 use hyper::header;
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Error, Method, Request, Response, Server, StatusCode};
+use serde::Deserialize;
 use serde_json::json;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[derive(RustEmbed)]
 #[folder = "query-engine/prisma/static_files"]
 struct StaticFiles;
 
+/// Time after which an interactive transaction is rolled back, unless the client sets a different timeout.
+const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Default, Deserialize)]
+struct BeginTransactionBody {
+    /// Timeout of the transaction in milliseconds. Must be positive, and is capped at `MAX_TRANSACTION_TIMEOUT`.
+    timeout: Option<u64>,
+}
+
+/// Options of an explain request, read from the body next to the GraphQL request.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExplainOptions {
+    #[serde(default)]
+    explain_database: bool,
+}
+
 pub(crate) struct RequestContext {
     context: PrismaContext,
     graphql_request_handler: GraphQlRequestHandler,
@@ -54,30 +75,15 @@ impl HttpServer {
 
     async fn routes(ctx: Arc<RequestContext>, req: Request<Body>) -> std::result::Result<Response<Body>, Error> {
         let res = match (req.method(), req.uri().path()) {
-            (&Method::POST, "/") => {
-                let (parts, chunks) = req.into_parts();
-                let body_bytes = chunks.try_concat().await?;
-
-                match serde_json::from_slice(body_bytes.as_ref()) {
-                    Ok(body) => {
-                        let req = PrismaRequest {
-                            body,
-                            path: parts.uri.path().into(),
-                            headers: parts
-                                .headers
-                                .iter()
-                                .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
-                                .collect(),
-                        };
-
-                        Self::http_handler(req, ctx).await
-                    }
-                    Err(_) => {
-                        let mut bad_request = Response::default();
-                        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
-                        bad_request
-                    }
-                }
+            (&Method::POST, "/") => match Self::graphql_request(req, None).await? {
+                Some(req) => Self::http_handler(req, ctx).await,
+                None => Self::bad_request(),
+            },
+
+            (&Method::POST, "/explain") => Self::explain_handler(ctx, req).await?,
+            (&Method::POST, path) if path.starts_with("/transaction/") => {
+                let path = path.to_owned();
+                Self::transaction_handler(ctx, &path, req).await?
             }
 
             (&Method::GET, "/") => Self::playground_handler(),
@@ -86,6 +92,8 @@ impl HttpServer {
             (&Method::GET, "/sdl") => Self::sdl_handler(ctx),
             (&Method::GET, "/dmmf") => Self::dmmf_handler(ctx),
             (&Method::GET, "/server_info") => Self::server_info_handler(ctx),
+            (&Method::GET, "/pool_metrics") => Self::pool_metrics_handler(ctx),
+            (&Method::GET, "/metrics") => Self::metrics_handler(ctx),
 
             _ => {
                 let mut not_found = Response::default();
@@ -97,6 +105,166 @@ impl HttpServer {
         Ok(res)
     }
 
+    /// Reads a GraphQL request from the body. Returns `None` if the body is not a valid request.
+    async fn graphql_request(
+        req: Request<Body>,
+        transaction_id: Option<String>,
+    ) -> std::result::Result<Option<PrismaRequest<GraphQlBody>>, Error> {
+        let (parts, chunks) = req.into_parts();
+        let body_bytes = chunks.try_concat().await?;
+
+        let req = serde_json::from_slice(body_bytes.as_ref())
+            .ok()
+            .map(|body| PrismaRequest {
+                body,
+                path: parts.uri.path().into(),
+                headers: parts
+                    .headers
+                    .iter()
+                    .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
+                    .collect(),
+                transaction_id,
+            });
+
+        Ok(req)
+    }
+
+    /// Handler for interactive transactions, which stay open across requests:
+    /// - `POST /transaction/begin` starts a transaction and returns its id. The body optionally sets the
+    ///   timeout in milliseconds after which the transaction is rolled back: `{ "timeout": 10000 }`.
+    ///   A timeout of zero is a bad request, timeouts above `MAX_TRANSACTION_TIMEOUT` are capped.
+    /// - `POST /transaction/<id>` executes a GraphQL request inside the transaction.
+    /// - `POST /transaction/<id>/commit` and `POST /transaction/<id>/rollback` close the transaction.
+    ///
+    /// The timeout also cleans up transactions of clients that disconnect without closing them.
+    async fn transaction_handler(
+        ctx: Arc<RequestContext>,
+        path: &str,
+        req: Request<Body>,
+    ) -> std::result::Result<Response<Body>, Error> {
+        let segments: Vec<&str> = path.trim_start_matches("/transaction/").split('/').collect();
+        let executor = &ctx.context.executor;
+
+        let result = match segments.as_slice() {
+            ["begin"] => {
+                let body_bytes = req.into_body().try_concat().await?;
+
+                let body: BeginTransactionBody = if body_bytes.is_empty() {
+                    BeginTransactionBody::default()
+                } else {
+                    match serde_json::from_slice(body_bytes.as_ref()) {
+                        Ok(body) => body,
+                        Err(_) => return Ok(Self::bad_request()),
+                    }
+                };
+
+                let timeout = match body.timeout {
+                    Some(0) => return Ok(Self::bad_request()),
+                    Some(millis) => Duration::from_millis(millis).min(MAX_TRANSACTION_TIMEOUT),
+                    None => DEFAULT_TRANSACTION_TIMEOUT,
+                };
+
+                executor.begin_transaction(timeout).await.map(|id| json!({ "id": id }))
+            }
+
+            [id] => {
+                let tx_id = Some(id.to_string());
+
+                return match Self::graphql_request(req, tx_id).await? {
+                    Some(req) => Ok(Self::http_handler(req, ctx).await),
+                    None => Ok(Self::bad_request()),
+                };
+            }
+
+            [id, "commit"] => executor.commit_transaction(id).await.map(|_| json!({})),
+            [id, "rollback"] => executor.rollback_transaction(id).await.map(|_| json!({})),
+
+            _ => {
+                let mut not_found = Response::default();
+                *not_found.status_mut() = StatusCode::NOT_FOUND;
+
+                return Ok(not_found);
+            }
+        };
+
+        let body_data = match result {
+            Ok(data) => data,
+            Err(err) => {
+                metrics::record_error(&err);
+                json!({ "errors": [{ "error": format!("{}", err) }] })
+            }
+        };
+
+        let bytes = serde_json::to_vec(&body_data).unwrap();
+
+        Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap())
+    }
+
+    /// Explains how the operations of a GraphQL request are executed: the query graph, the expression plan and
+    /// the SQL statements of each operation. The operations run in a transaction that is always rolled back, so
+    /// writes are never committed. With `"explainDatabase": true` in the body, read statements are also
+    /// explained by the database.
+    async fn explain_handler(
+        ctx: Arc<RequestContext>,
+        req: Request<Body>,
+    ) -> std::result::Result<Response<Body>, Error> {
+        let body_bytes = req.into_body().try_concat().await?;
+
+        let body: GraphQlBody = match serde_json::from_slice(body_bytes.as_ref()) {
+            Ok(body) => body,
+            Err(_) => return Ok(Self::bad_request()),
+        };
+
+        let options: ExplainOptions = serde_json::from_slice(body_bytes.as_ref()).unwrap_or_default();
+
+        let body_data = match explain_graphql_query(body, &ctx.context, options.explain_database).await {
+            Ok(explanations) => {
+                let operations: Vec<_> = explanations.into_iter().map(Self::serialize_explanation).collect();
+                json!({ "operations": operations })
+            }
+            Err(err) => json!({ "errors": [{ "error": format!("{}", err) }] }),
+        };
+
+        let bytes = serde_json::to_vec(&body_data).unwrap();
+
+        Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap())
+    }
+
+    fn serialize_explanation(explanation: Explanation) -> serde_json::Value {
+        let queries: Vec<_> = explanation
+            .queries
+            .into_iter()
+            .map(|explained| {
+                let mut query = serde_json::to_value(&explained.query).unwrap();
+
+                match explained.plan {
+                    Some(Ok(plan)) => query["plan"] = plan,
+                    Some(Err(err)) => query["planError"] = json!(format!("{}", err)),
+                    None => (),
+                }
+
+                query
+            })
+            .collect();
+
+        json!({
+            "key": explanation.key,
+            "queryGraph": explanation.query_graph,
+            "expression": explanation.expression,
+            "interpretation": explanation.interpretation,
+            "queries": queries,
+            "error": explanation.error,
+        })
+    }
+
     async fn http_handler(req: PrismaRequest<GraphQlBody>, cx: Arc<RequestContext>) -> Response<Body> {
         let result = cx.graphql_request_handler.handle(req, &cx.context).await;
         let bytes = serde_json::to_vec(&result).unwrap();
@@ -108,6 +276,12 @@ impl HttpServer {
             .unwrap()
     }
 
+    fn bad_request() -> Response<Body> {
+        let mut bad_request = Response::default();
+        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+        bad_request
+    }
+
     fn status_handler() -> Response<Body> {
         let body_data = json!({"status": "ok"});
         let bytes = serde_json::to_vec(&body_data).unwrap();
@@ -171,4 +345,28 @@ impl HttpServer {
             .body(Body::from(bytes))
             .unwrap()
     }
+
+    /// Current usage of the connection pool: connections checked out, idle connections and waiting requests.
+    /// Responds with `null` if the connector doesn't pool its connections.
+    fn pool_metrics_handler(cx: Arc<RequestContext>) -> Response<Body> {
+        let bytes = serde_json::to_vec(&cx.context.pool_metrics()).unwrap();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap()
+    }
+
+    /// Metrics of the engine in the Prometheus text format: operations per operation type and model,
+    /// connector operations, transactions, errors, and connection pool usage.
+    fn metrics_handler(cx: Arc<RequestContext>) -> Response<Body> {
+        let body = metrics::render(cx.context.pool_metrics());
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "text/plain; version=0.0.4")
+            .body(Body::from(body))
+            .unwrap()
+    }
 }